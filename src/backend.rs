@@ -0,0 +1,368 @@
+//! Abstraction over window management functions.
+//!
+//! Automation logic written against [WindowBackend](trait.WindowBackend.html) can be run
+//! on real desktop via [User32](struct.User32.html) or tested with [FakeDesktop](struct.FakeDesktop.html),
+//! where windows and their responses to messages are defined by test itself.
+//!
+//! `FakeDesktop` is platform independent.
+
+use core::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use crate::sys::{
+    HWND,
+    UINT,
+    WPARAM,
+    LPARAM,
+    LRESULT,
+    c_int,
+    SW_HIDE,
+};
+use crate::utils::{ErrorCode, Result};
+
+///Window management functions.
+///
+///Methods correspond to functions of [raw::window](../raw/window/index.html).
+pub trait WindowBackend {
+    ///Enumerates windows.
+    ///
+    ///If `parent` is specified, returns all its descendants, otherwise top-level windows.
+    fn enum_windows(&self, parent: Option<HWND>) -> Result<Vec<HWND>>;
+    ///Retrieves window's class.
+    fn class(&self, window: HWND) -> Result<String>;
+    ///Retrieves window's title.
+    fn title(&self, window: HWND) -> Result<String>;
+    ///Retrieves window's text via `WM_GETTEXT`.
+    fn text(&self, window: HWND) -> Option<String>;
+    ///Sets window's text via `WM_SETTEXT`.
+    fn set_text(&self, window: HWND, text: &str) -> bool;
+    ///Retrieves tuple of process and thread ids.
+    fn thread_process_id(&self, window: HWND) -> (u32, u32);
+    ///Returns whether window is visible.
+    fn is_visible(&self, window: HWND) -> bool;
+    ///Shows window with specified command, returning whether it was previously visible.
+    fn show(&self, window: HWND, cmd: c_int) -> bool;
+    ///Sends message to window.
+    fn send_message(&self, window: HWND, msg_type: UINT, w_param: WPARAM, l_param: LPARAM, timeout: Option<UINT>) -> Result<LRESULT>;
+
+    ///Retrieves list of windows with specified class.
+    fn get_by_class(&self, class_name: &str, parent: Option<HWND>) -> Result<Vec<HWND>> {
+        let mut windows = self.enum_windows(parent)?;
+        windows.retain(|window| self.class(*window).map(|class| class == class_name).unwrap_or(false));
+        Ok(windows)
+    }
+
+    ///Retrieves list of windows with specified title.
+    fn get_by_title(&self, name: &str, parent: Option<HWND>) -> Result<Vec<HWND>> {
+        let mut windows = self.enum_windows(parent)?;
+        windows.retain(|window| self.title(*window).map(|title| title == name).unwrap_or(false));
+        Ok(windows)
+    }
+
+    ///Retrieves first top-level window of the process.
+    fn get_by_pid(&self, pid: u32) -> Result<Option<HWND>> {
+        let windows = self.enum_windows(None)?;
+        Ok(windows.into_iter().find(|window| self.thread_process_id(*window).0 == pid))
+    }
+}
+
+#[cfg(windows)]
+///Backend that uses WinAPI.
+pub struct User32;
+
+#[cfg(windows)]
+impl WindowBackend for User32 {
+    fn enum_windows(&self, parent: Option<HWND>) -> Result<Vec<HWND>> {
+        let mut windows = Vec::new();
+        crate::raw::window::enum_by(parent, |window| windows.push(window))?;
+        Ok(windows)
+    }
+
+    #[inline]
+    fn class(&self, window: HWND) -> Result<String> {
+        crate::raw::window::get_class(window)
+    }
+
+    #[inline]
+    fn title(&self, window: HWND) -> Result<String> {
+        crate::raw::window::get_text(window)
+    }
+
+    #[inline]
+    fn text(&self, window: HWND) -> Option<String> {
+        crate::raw::window::send_get_text(window)
+    }
+
+    #[inline]
+    fn set_text(&self, window: HWND, text: &str) -> bool {
+        crate::raw::window::send_set_text(window, text)
+    }
+
+    #[inline]
+    fn thread_process_id(&self, window: HWND) -> (u32, u32) {
+        crate::raw::window::get_thread_process_id(window)
+    }
+
+    #[inline]
+    fn is_visible(&self, window: HWND) -> bool {
+        crate::raw::window::is_visible(window)
+    }
+
+    #[inline]
+    fn show(&self, window: HWND, cmd: c_int) -> bool {
+        crate::raw::window::show(window, cmd)
+    }
+
+    #[inline]
+    fn send_message(&self, window: HWND, msg_type: UINT, w_param: WPARAM, l_param: LPARAM, timeout: Option<UINT>) -> Result<LRESULT> {
+        crate::raw::window::send_message(window, msg_type, w_param, l_param, timeout)
+    }
+}
+
+//ERROR_INVALID_WINDOW_HANDLE
+const INVALID_WINDOW_HANDLE: c_int = 1400;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+///Window of [FakeDesktop](struct.FakeDesktop.html).
+pub struct FakeWindow {
+    ///Window's class.
+    pub class: String,
+    ///Window's title, which is also its text.
+    pub title: String,
+    ///Pid of owning process.
+    pub pid: u32,
+    ///Id of owning thread.
+    pub tid: u32,
+    ///Whether window is visible.
+    pub visible: bool,
+}
+
+impl FakeWindow {
+    ///Creates new visible window with specified class.
+    pub fn new(class: &str) -> Self {
+        Self {
+            class: class.to_owned(),
+            title: String::new(),
+            pid: 0,
+            tid: 0,
+            visible: true,
+        }
+    }
+
+    ///Sets title.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_owned();
+        self
+    }
+
+    ///Sets pid of owning process.
+    pub fn pid(mut self, pid: u32) -> Self {
+        self.pid = pid;
+        self
+    }
+
+    ///Sets id of owning thread.
+    pub fn tid(mut self, tid: u32) -> Self {
+        self.tid = tid;
+        self
+    }
+
+    ///Sets visibility.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Message sent to window of [FakeDesktop](struct.FakeDesktop.html).
+pub struct SentMessage {
+    ///Destination window.
+    pub window: HWND,
+    ///Message identifier.
+    pub msg_type: UINT,
+    ///First parameter.
+    pub w_param: WPARAM,
+    ///Second parameter.
+    pub l_param: LPARAM,
+}
+
+type Handler = Box<dyn FnMut(&mut FakeWindow, WPARAM, LPARAM) -> Result<LRESULT>>;
+
+struct Entry {
+    handle: HWND,
+    parent: Option<HWND>,
+    window: FakeWindow,
+}
+
+///In-memory desktop.
+///
+///Windows are enumerated in order of creation.
+///Messages without handler are recorded and return `0`, like with default window procedure.
+///Operations on unknown windows fail with `ERROR_INVALID_WINDOW_HANDLE`.
+pub struct FakeDesktop {
+    windows: RefCell<Vec<Entry>>,
+    handlers: RefCell<HashMap<(usize, UINT), Handler>>,
+    sent: RefCell<Vec<SentMessage>>,
+    next_handle: Cell<usize>,
+}
+
+impl FakeDesktop {
+    ///Creates empty desktop.
+    pub fn new() -> Self {
+        Self {
+            windows: RefCell::new(Vec::new()),
+            handlers: RefCell::new(HashMap::new()),
+            sent: RefCell::new(Vec::new()),
+            next_handle: Cell::new(0x10000),
+        }
+    }
+
+    fn insert(&self, parent: Option<HWND>, window: FakeWindow) -> HWND {
+        let handle = self.next_handle.get();
+        self.next_handle.set(handle + 2);
+
+        let handle = handle as HWND;
+        self.windows.borrow_mut().push(Entry {
+            handle,
+            parent,
+            window,
+        });
+        handle
+    }
+
+    ///Adds top-level window, returning its handle.
+    pub fn add(&self, window: FakeWindow) -> HWND {
+        self.insert(None, window)
+    }
+
+    ///Adds child window, returning its handle.
+    pub fn add_child(&self, parent: HWND, window: FakeWindow) -> HWND {
+        self.insert(Some(parent), window)
+    }
+
+    ///Removes window with all its descendants.
+    ///
+    ///Returns `false` if window doesn't exist.
+    pub fn destroy(&self, window: HWND) -> bool {
+        let mut removed = Vec::new();
+        self.collect_descendants(window, &mut removed);
+
+        let mut windows = self.windows.borrow_mut();
+        let len = windows.len();
+        windows.retain(|entry| entry.handle != window && !removed.contains(&entry.handle));
+        let is_removed = windows.len() != len;
+
+        if is_removed {
+            removed.push(window);
+            self.handlers.borrow_mut().retain(|(handle, _), _| !removed.contains(&(*handle as HWND)));
+        }
+
+        is_removed
+    }
+
+    ///Sets handler of message, replacing previous one.
+    ///
+    ///Handler receives window's state, which it can modify, and message parameters.
+    pub fn on_message<F: FnMut(&mut FakeWindow, WPARAM, LPARAM) -> Result<LRESULT> + 'static>(&self, window: HWND, msg_type: UINT, handler: F) {
+        self.handlers.borrow_mut().insert((window as usize, msg_type), Box::new(handler));
+    }
+
+    ///Returns copy of window's state.
+    pub fn window(&self, window: HWND) -> Option<FakeWindow> {
+        self.with_window(window, |window| window.clone()).ok()
+    }
+
+    ///Returns all messages sent so far.
+    pub fn sent(&self) -> Vec<SentMessage> {
+        self.sent.borrow().clone()
+    }
+
+    fn with_window<R, F: FnOnce(&mut FakeWindow) -> R>(&self, window: HWND, cb: F) -> Result<R> {
+        let mut windows = self.windows.borrow_mut();
+        match windows.iter_mut().find(|entry| entry.handle == window) {
+            Some(entry) => Ok(cb(&mut entry.window)),
+            None => Err(ErrorCode::new_system(INVALID_WINDOW_HANDLE)),
+        }
+    }
+
+    fn collect_descendants(&self, parent: HWND, result: &mut Vec<HWND>) {
+        let children: Vec<HWND> = self.windows.borrow().iter().filter(|entry| entry.parent == Some(parent)).map(|entry| entry.handle).collect();
+
+        for child in children {
+            result.push(child);
+            self.collect_descendants(child, result);
+        }
+    }
+}
+
+impl Default for FakeDesktop {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowBackend for FakeDesktop {
+    fn enum_windows(&self, parent: Option<HWND>) -> Result<Vec<HWND>> {
+        match parent {
+            Some(parent) => {
+                self.with_window(parent, |_| ())?;
+
+                let mut result = Vec::new();
+                self.collect_descendants(parent, &mut result);
+                Ok(result)
+            },
+            None => Ok(self.windows.borrow().iter().filter(|entry| entry.parent.is_none()).map(|entry| entry.handle).collect()),
+        }
+    }
+
+    fn class(&self, window: HWND) -> Result<String> {
+        self.with_window(window, |window| window.class.clone())
+    }
+
+    fn title(&self, window: HWND) -> Result<String> {
+        self.with_window(window, |window| window.title.clone())
+    }
+
+    fn text(&self, window: HWND) -> Option<String> {
+        self.with_window(window, |window| window.title.clone()).ok()
+    }
+
+    fn set_text(&self, window: HWND, text: &str) -> bool {
+        self.with_window(window, |window| window.title = text.to_owned()).is_ok()
+    }
+
+    fn thread_process_id(&self, window: HWND) -> (u32, u32) {
+        self.with_window(window, |window| (window.pid, window.tid)).unwrap_or((0, 0))
+    }
+
+    fn is_visible(&self, window: HWND) -> bool {
+        self.with_window(window, |window| window.visible).unwrap_or(false)
+    }
+
+    fn show(&self, window: HWND, cmd: c_int) -> bool {
+        self.with_window(window, |window| {
+            let was_visible = window.visible;
+            window.visible = cmd != SW_HIDE;
+            was_visible
+        }).unwrap_or(false)
+    }
+
+    fn send_message(&self, window: HWND, msg_type: UINT, w_param: WPARAM, l_param: LPARAM, _timeout: Option<UINT>) -> Result<LRESULT> {
+        self.with_window(window, |_| ())?;
+
+        self.sent.borrow_mut().push(SentMessage {
+            window,
+            msg_type,
+            w_param,
+            l_param,
+        });
+
+        let mut handlers = self.handlers.borrow_mut();
+        match handlers.get_mut(&(window as usize, msg_type)) {
+            Some(handler) => self.with_window(window, |window| handler(window, w_param, l_param))?,
+            None => Ok(0),
+        }
+    }
+}
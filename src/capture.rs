@@ -0,0 +1,393 @@
+//! Capture of screen and windows.
+//!
+//! Captured pixels are stored in [Image](struct.Image.html), which can be saved as BMP.
+//!
+//! Image and its encoding are platform independent.
+
+use std::{io, fs, path};
+
+#[cfg(windows)]
+use crate::sys::*;
+#[cfg(windows)]
+use crate::utils::{self, ErrorCode, Result};
+#[cfg(windows)]
+use crate::raw;
+#[cfg(windows)]
+use core::{mem, ptr};
+
+const BYTES_PER_PIXEL: usize = 4;
+const BMP_FILE_HEADER_SIZE: usize = 14;
+const BMP_INFO_HEADER_SIZE: usize = 40;
+//72 DPI
+const BMP_PIXELS_PER_METER: i32 = 2835;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+///Image with pixels in BGRA format, top-down.
+pub struct Image {
+    width: u32,
+    height: u32,
+    stride: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    ///Creates new image filled with transparent black.
+    pub fn new(width: u32, height: u32) -> Self {
+        let stride = width as usize * BYTES_PER_PIXEL;
+
+        Self {
+            width,
+            height,
+            stride,
+            pixels: vec![0; stride * height as usize],
+        }
+    }
+
+    ///Creates image from existing pixels.
+    ///
+    ///# Parameters
+    ///
+    ///* ```width``` - Width in pixels.
+    ///* ```height``` - Height in pixels.
+    ///* ```stride``` - Number of bytes between starts of rows. At least `width * 4`.
+    ///* ```pixels``` - Pixels in BGRA format, top-down. At least `stride * height` bytes.
+    ///
+    ///# Return
+    ///
+    ///* ```Some``` - Image.
+    ///* ```None``` - If stride or size of pixels is invalid.
+    pub fn from_bgra(width: u32, height: u32, stride: usize, pixels: Vec<u8>) -> Option<Self> {
+        if stride < width as usize * BYTES_PER_PIXEL || pixels.len() < stride * height as usize {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            stride,
+            pixels,
+        })
+    }
+
+    #[inline]
+    ///Returns width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    ///Returns height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[inline]
+    ///Returns number of bytes between starts of rows.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    #[inline]
+    ///Returns pixels in BGRA format.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    #[inline]
+    ///Returns pixels in BGRA format and consumes self.
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    #[inline]
+    ///Returns pixels of row without padding.
+    ///
+    ///Panics if row is out of bounds.
+    pub fn row(&self, y: u32) -> &[u8] {
+        assert!(y < self.height);
+        let start = y as usize * self.stride;
+        &self.pixels[start..start + self.width as usize * BYTES_PER_PIXEL]
+    }
+
+    ///Returns pixel as `[blue, green, red, alpha]`.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let idx = y as usize * self.stride + x as usize * BYTES_PER_PIXEL;
+        Some([self.pixels[idx], self.pixels[idx + 1], self.pixels[idx + 2], self.pixels[idx + 3]])
+    }
+
+    ///Sets pixel as `[blue, green, red, alpha]`.
+    ///
+    ///Returns `false` if pixel is out of bounds.
+    pub fn set_pixel(&mut self, x: u32, y: u32, pixel: [u8; 4]) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+
+        let idx = y as usize * self.stride + x as usize * BYTES_PER_PIXEL;
+        self.pixels[idx..idx + BYTES_PER_PIXEL].copy_from_slice(&pixel);
+        true
+    }
+
+    ///Copies part of image.
+    ///
+    ///Returns `None` if area is out of bounds.
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Option<Self> {
+        if x.checked_add(width)? > self.width || y.checked_add(height)? > self.height {
+            return None;
+        }
+
+        let mut result = Self::new(width, height);
+        let start = x as usize * BYTES_PER_PIXEL;
+        for row in 0..height {
+            let src = &self.row(y + row)[start..start + result.stride];
+            let dst = (row as usize) * result.stride;
+            result.pixels[dst..dst + result.stride].copy_from_slice(src);
+        }
+
+        Some(result)
+    }
+
+    ///Writes image as 32-bit BMP.
+    pub fn write_bmp<W: io::Write>(&self, mut out: W) -> io::Result<()> {
+        let row_size = self.width as usize * BYTES_PER_PIXEL;
+        let data_size = row_size * self.height as usize;
+        let data_offset = BMP_FILE_HEADER_SIZE + BMP_INFO_HEADER_SIZE;
+        let file_size = data_offset + data_size;
+
+        if file_size > u32::max_value() as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Image is too large for BMP"));
+        }
+
+        let mut header = Vec::with_capacity(data_offset);
+        //BITMAPFILEHEADER
+        header.extend_from_slice(b"BM");
+        header.extend_from_slice(&(file_size as u32).to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&(data_offset as u32).to_le_bytes());
+        //BITMAPINFOHEADER
+        header.extend_from_slice(&(BMP_INFO_HEADER_SIZE as u32).to_le_bytes());
+        header.extend_from_slice(&(self.width as i32).to_le_bytes());
+        //Negative height for top-down rows
+        header.extend_from_slice(&(-(self.height as i32)).to_le_bytes());
+        header.extend_from_slice(&1u16.to_le_bytes());
+        header.extend_from_slice(&32u16.to_le_bytes());
+        //BI_RGB
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&(data_size as u32).to_le_bytes());
+        header.extend_from_slice(&BMP_PIXELS_PER_METER.to_le_bytes());
+        header.extend_from_slice(&BMP_PIXELS_PER_METER.to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+
+        out.write_all(&header)?;
+        for y in 0..self.height {
+            out.write_all(self.row(y))?;
+        }
+
+        Ok(())
+    }
+
+    ///Encodes image as 32-bit BMP.
+    pub fn to_bmp(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(BMP_FILE_HEADER_SIZE + BMP_INFO_HEADER_SIZE + self.pixels.len());
+        //Writing into vector cannot fail, unless image is too large for BMP.
+        let _ = self.write_bmp(&mut result);
+        result
+    }
+
+    ///Saves image as 32-bit BMP file.
+    pub fn save_bmp<P: AsRef<path::Path>>(&self, path: P) -> io::Result<()> {
+        let file = fs::File::create(path)?;
+        let mut file = io::BufWriter::new(file);
+        self.write_bmp(&mut file)?;
+        io::Write::flush(&mut file)
+    }
+}
+
+#[cfg(windows)]
+//ERROR_INVALID_PARAMETER
+const INVALID_PARAMETER: c_int = 87;
+
+#[cfg(windows)]
+fn gdi_error() -> ErrorCode {
+    //GDI functions do not always set last error.
+    match utils::get_last_error() {
+        error if error.raw_code() == 0 => ErrorCode::new_system(INVALID_PARAMETER),
+        error => error,
+    }
+}
+
+#[cfg(windows)]
+struct WindowDc {
+    window: HWND,
+    dc: HDC,
+}
+
+#[cfg(windows)]
+impl WindowDc {
+    fn screen() -> Result<Self> {
+        match unsafe { GetDC(ptr::null_mut()) } {
+            dc if dc.is_null() => Err(gdi_error()),
+            dc => Ok(Self {
+                window: ptr::null_mut(),
+                dc,
+            }),
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for WindowDc {
+    fn drop(&mut self) {
+        unsafe {
+            ReleaseDC(self.window, self.dc);
+        }
+    }
+}
+
+#[cfg(windows)]
+//Bitmap selected into memory DC.
+struct MemoryBitmap {
+    dc: HDC,
+    bitmap: HBITMAP,
+    old: HGDIOBJ,
+    width: c_int,
+    height: c_int,
+}
+
+#[cfg(windows)]
+impl MemoryBitmap {
+    fn new(screen: &WindowDc, width: c_int, height: c_int) -> Result<Self> {
+        if width <= 0 || height <= 0 {
+            return Err(ErrorCode::new_system(INVALID_PARAMETER));
+        }
+
+        unsafe {
+            let dc = CreateCompatibleDC(screen.dc);
+            if dc.is_null() {
+                return Err(gdi_error());
+            }
+
+            let bitmap = CreateCompatibleBitmap(screen.dc, width, height);
+            if bitmap.is_null() {
+                let error = gdi_error();
+                DeleteDC(dc);
+                return Err(error);
+            }
+
+            Ok(Self {
+                dc,
+                bitmap,
+                old: SelectObject(dc, bitmap),
+                width,
+                height,
+            })
+        }
+    }
+
+    fn to_image(&self) -> Result<Image> {
+        let mut info: BITMAPINFO = unsafe { mem::zeroed() };
+        info.bmiHeader.biSize = mem::size_of::<BITMAPINFOHEADER>() as DWORD;
+        info.bmiHeader.biWidth = self.width;
+        //Top-down
+        info.bmiHeader.biHeight = -self.height;
+        info.bmiHeader.biPlanes = 1;
+        info.bmiHeader.biBitCount = 32;
+        info.bmiHeader.biCompression = BI_RGB;
+
+        let mut image = Image::new(self.width as u32, self.height as u32);
+        //Bitmap must not be selected into DC when retrieving its bits.
+        let lines = unsafe {
+            SelectObject(self.dc, self.old);
+            let lines = GetDIBits(self.dc, self.bitmap, 0, self.height as UINT, image.pixels.as_mut_ptr() as LPVOID, &mut info, DIB_RGB_COLORS);
+            SelectObject(self.dc, self.bitmap);
+            lines
+        };
+
+        if lines == 0 {
+            return Err(gdi_error());
+        }
+
+        //Alpha is not preserved by GDI.
+        for pixel in image.pixels.chunks_exact_mut(BYTES_PER_PIXEL) {
+            pixel[3] = 0xFF;
+        }
+
+        Ok(image)
+    }
+}
+
+#[cfg(windows)]
+impl Drop for MemoryBitmap {
+    fn drop(&mut self) {
+        unsafe {
+            SelectObject(self.dc, self.old);
+            DeleteObject(self.bitmap);
+            DeleteDC(self.dc);
+        }
+    }
+}
+
+#[cfg(windows)]
+///Captures area of screen, including layered windows.
+///
+///# Parameters
+///
+///* ```x``` - Left coordinate in virtual screen.
+///* ```y``` - Top coordinate in virtual screen.
+///* ```width``` - Width of area.
+///* ```height``` - Height of area.
+pub fn screen(x: c_int, y: c_int, width: c_int, height: c_int) -> Result<Image> {
+    let screen = WindowDc::screen()?;
+    let bitmap = MemoryBitmap::new(&screen, width, height)?;
+
+    match unsafe { BitBlt(bitmap.dc, 0, 0, width, height, screen.dc, x, y, SRCCOPY | CAPTUREBLT) } {
+        0 => Err(gdi_error()),
+        _ => bitmap.to_image(),
+    }
+}
+
+#[cfg(windows)]
+///Captures primary monitor.
+pub fn primary_screen() -> Result<Image> {
+    let (width, height) = unsafe {
+        (GetSystemMetrics(SM_CXSCREEN), GetSystemMetrics(SM_CYSCREEN))
+    };
+
+    screen(0, 0, width, height)
+}
+
+#[cfg(windows)]
+///Captures virtual screen, i.e. all monitors.
+pub fn virtual_screen() -> Result<Image> {
+    let area = crate::input::VirtualScreen::current();
+    screen(area.x, area.y, area.width, area.height)
+}
+
+#[cfg(windows)]
+///Captures window, including its frame.
+///
+///Window is asked to draw itself with `PrintWindow`, so it can be captured even if it is covered by other windows.
+///If window fails to draw itself, its area on screen is captured instead.
+///
+///Minimized windows cannot be captured.
+pub fn window(window: HWND) -> Result<Image> {
+    let rect = raw::window::get_rect(window)?;
+    let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+
+    let screen = WindowDc::screen()?;
+    let bitmap = MemoryBitmap::new(&screen, width, height)?;
+
+    if unsafe { PrintWindow(window, bitmap.dc, PW_RENDERFULLCONTENT) } != 0 {
+        return bitmap.to_image();
+    }
+
+    match unsafe { BitBlt(bitmap.dc, 0, 0, width, height, screen.dc, rect.left, rect.top, SRCCOPY | CAPTUREBLT) } {
+        0 => Err(gdi_error()),
+        _ => bitmap.to_image(),
+    }
+}
@@ -0,0 +1,137 @@
+//! Clipboard notifications.
+
+use crate::sys::{
+    HWND,
+    DWORD,
+    MSG,
+    WM_CLIPBOARDUPDATE,
+    AddClipboardFormatListener,
+    RemoveClipboardFormatListener,
+    GetClipboardSequenceNumber,
+};
+use crate::raw::window::Builder;
+use crate::message_loop::MessageThread;
+use crate::utils::{self, Result};
+use crate::{Messages, Window};
+
+#[inline]
+///Retrieves clipboard sequence number for the current window station.
+///
+///The system keeps serial number for the clipboard, which is incremented whenever content changes.
+pub fn sequence_number() -> DWORD {
+    unsafe {
+        GetClipboardSequenceNumber()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Clipboard content update.
+pub struct ClipboardUpdate {
+    ///Clipboard sequence number at the time notification is handled.
+    pub sequence: DWORD,
+}
+
+///Clipboard format listener.
+///
+///Creates message-only window and registers it with `AddClipboardFormatListener`.
+///Window receives `WM_CLIPBOARDUPDATE` whenever clipboard content changes.
+///
+///On drop listener is removed and window is destroyed.
+///
+///# Note:
+///
+///Messages are delivered to the thread that created listener,
+///so events can only be retrieved on this thread.
+///Use [spawn()](#method.spawn) to handle them on dedicated thread.
+pub struct ClipboardListener {
+    window: Window,
+}
+
+impl ClipboardListener {
+    ///Creates new listener on the current thread.
+    pub fn new() -> Result<Self> {
+        let window = Window::from_builder(Builder::new().class_name("STATIC").parent_message())?;
+
+        match unsafe { AddClipboardFormatListener(window.inner()) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(Self {
+                window
+            })
+        }
+    }
+
+    ///Spawns message loop thread with listener, which invokes callback on each update.
+    ///
+    ///Listener is removed once returned thread is stopped.
+    pub fn spawn<F: FnMut(ClipboardUpdate) + Send + 'static>(mut cb: F) -> Result<MessageThread> {
+        MessageThread::spawn(move || {
+            let listener = Self::new()?;
+
+            Ok(move |msg: &MSG| {
+                if msg.message == WM_CLIPBOARDUPDATE && msg.hwnd == listener.window() {
+                    cb(ClipboardUpdate {
+                        sequence: sequence_number()
+                    });
+                }
+            })
+        })
+    }
+
+    #[inline]
+    ///Returns underlying message-only window.
+    pub fn window(&self) -> HWND {
+        self.window.inner()
+    }
+
+    ///Returns blocking iterator over clipboard updates.
+    pub fn events(&self) -> ClipboardEvents {
+        ClipboardEvents::new(self.window(), true)
+    }
+
+    ///Returns non-blocking iterator over clipboard updates.
+    ///
+    ///Iteration stops once there are no more updates in queue.
+    pub fn try_events(&self) -> ClipboardEvents {
+        ClipboardEvents::new(self.window(), false)
+    }
+}
+
+impl Drop for ClipboardListener {
+    fn drop(&mut self) {
+        unsafe {
+            RemoveClipboardFormatListener(self.window.inner());
+        }
+    }
+}
+
+///Iterator over clipboard updates of [ClipboardListener](struct.ClipboardListener.html).
+pub struct ClipboardEvents {
+    messages: Messages,
+}
+
+impl ClipboardEvents {
+    fn new(window: HWND, is_block: bool) -> Self {
+        let mut messages = Messages::new();
+        messages.window(Some(window)).low(Some(WM_CLIPBOARDUPDATE)).high(Some(WM_CLIPBOARDUPDATE));
+        if !is_block {
+            messages.non_blocking();
+        }
+
+        Self {
+            messages
+        }
+    }
+}
+
+impl Iterator for ClipboardEvents {
+    type Item = Result<ClipboardUpdate>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.messages.next().map(|msg| msg.map(|msg| {
+            msg.dispatch();
+            ClipboardUpdate {
+                sequence: sequence_number()
+            }
+        }))
+    }
+}
@@ -0,0 +1,830 @@
+//! Console APIs.
+//!
+//! [Console](struct.Console.html) wraps standard handle of the console attached to the process,
+//! while free functions allocate, attach and free console itself.
+//!
+//! Text attributes and decoding of input records are platform independent.
+
+use crate::sys::*;
+#[cfg(windows)]
+use crate::utils::{self, ErrorCode, Result};
+
+#[cfg(windows)]
+use std::os::windows::ffi::OsStrExt;
+#[cfg(windows)]
+use std::sync::{Arc, Mutex};
+#[cfg(windows)]
+use std::{ffi, mem, panic};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Color of console's default palette.
+pub enum Color {
+    ///Black
+    Black = 0,
+    ///Dark blue
+    DarkBlue = 1,
+    ///Dark green
+    DarkGreen = 2,
+    ///Dark cyan
+    DarkCyan = 3,
+    ///Dark red
+    DarkRed = 4,
+    ///Dark magenta
+    DarkMagenta = 5,
+    ///Dark yellow
+    DarkYellow = 6,
+    ///Gray
+    Gray = 7,
+    ///Dark gray
+    DarkGray = 8,
+    ///Blue
+    Blue = 9,
+    ///Green
+    Green = 10,
+    ///Cyan
+    Cyan = 11,
+    ///Red
+    Red = 12,
+    ///Magenta
+    Magenta = 13,
+    ///Yellow
+    Yellow = 14,
+    ///White
+    White = 15,
+}
+
+impl Color {
+    ///Creates color from its palette's index, which consists of `FOREGROUND_*` bits.
+    pub fn from_index(index: u8) -> Option<Self> {
+        const COLORS: [Color; 16] = [
+            Color::Black, Color::DarkBlue, Color::DarkGreen, Color::DarkCyan,
+            Color::DarkRed, Color::DarkMagenta, Color::DarkYellow, Color::Gray,
+            Color::DarkGray, Color::Blue, Color::Green, Color::Cyan,
+            Color::Red, Color::Magenta, Color::Yellow, Color::White,
+        ];
+
+        COLORS.get(index as usize).copied()
+    }
+
+    #[inline]
+    ///Returns palette's index.
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Console text attributes.
+pub struct TextAttributes {
+    inner: WORD,
+}
+
+impl TextAttributes {
+    const FOREGROUND_MASK: WORD = FOREGROUND_BLUE | FOREGROUND_GREEN | FOREGROUND_RED | FOREGROUND_INTENSITY;
+    const BACKGROUND_MASK: WORD = BACKGROUND_BLUE | BACKGROUND_GREEN | BACKGROUND_RED | BACKGROUND_INTENSITY;
+
+    #[inline]
+    ///Creates new instance with gray text on black background.
+    pub const fn new() -> Self {
+        Self {
+            inner: FOREGROUND_BLUE | FOREGROUND_GREEN | FOREGROUND_RED
+        }
+    }
+
+    #[inline]
+    ///Creates instance from raw `FOREGROUND_*`, `BACKGROUND_*` and `COMMON_LVB_*` flags.
+    pub const fn from_raw(inner: WORD) -> Self {
+        Self {
+            inner
+        }
+    }
+
+    #[inline]
+    ///Returns raw flags.
+    pub const fn raw(&self) -> WORD {
+        self.inner
+    }
+
+    ///Sets text color.
+    pub fn foreground(mut self, color: Color) -> Self {
+        self.inner = (self.inner & !Self::FOREGROUND_MASK) | WORD::from(color.index());
+        self
+    }
+
+    ///Sets background color.
+    pub fn background(mut self, color: Color) -> Self {
+        self.inner = (self.inner & !Self::BACKGROUND_MASK) | (WORD::from(color.index()) << 4);
+        self
+    }
+
+    ///Underlines text.
+    pub fn underline(mut self) -> Self {
+        self.inner |= COMMON_LVB_UNDERSCORE;
+        self
+    }
+
+    ///Swaps text and background colors.
+    pub fn reverse(mut self) -> Self {
+        self.inner |= COMMON_LVB_REVERSE_VIDEO;
+        self
+    }
+
+    #[inline]
+    ///Returns text color.
+    pub fn get_foreground(&self) -> Color {
+        //Mask guarantees valid index
+        Color::from_index((self.inner & Self::FOREGROUND_MASK) as u8).unwrap_or(Color::Gray)
+    }
+
+    #[inline]
+    ///Returns background color.
+    pub fn get_background(&self) -> Color {
+        Color::from_index(((self.inner & Self::BACKGROUND_MASK) >> 4) as u8).unwrap_or(Color::Black)
+    }
+
+    #[inline]
+    ///Returns whether text is underlined.
+    pub fn is_underline(&self) -> bool {
+        self.inner & COMMON_LVB_UNDERSCORE != 0
+    }
+
+    #[inline]
+    ///Returns whether colors are swapped.
+    pub fn is_reverse(&self) -> bool {
+        self.inner & COMMON_LVB_REVERSE_VIDEO != 0
+    }
+}
+
+impl Default for TextAttributes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Rectangle of character cells, with inclusive right and bottom.
+pub struct CellRect {
+    ///Left column.
+    pub left: i16,
+    ///Top row.
+    pub top: i16,
+    ///Right column, which is included.
+    pub right: i16,
+    ///Bottom row, which is included.
+    pub bottom: i16,
+}
+
+impl CellRect {
+    #[inline]
+    ///Creates instance from raw rectangle.
+    pub fn from_raw(rect: &SMALL_RECT) -> Self {
+        Self {
+            left: rect.Left,
+            top: rect.Top,
+            right: rect.Right,
+            bottom: rect.Bottom,
+        }
+    }
+
+    #[inline]
+    ///Returns number of columns.
+    pub fn width(&self) -> i16 {
+        self.right - self.left + 1
+    }
+
+    #[inline]
+    ///Returns number of rows.
+    pub fn height(&self) -> i16 {
+        self.bottom - self.top + 1
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Information about console screen buffer.
+pub struct ScreenBufferInfo {
+    ///Size of buffer in character cells, as `(columns, rows)`.
+    pub size: (i16, i16),
+    ///Cursor position, as `(column, row)`.
+    pub cursor: (i16, i16),
+    ///Attributes of written text.
+    pub attributes: TextAttributes,
+    ///Visible part of buffer.
+    pub window: CellRect,
+    ///Maximum size of window, as `(columns, rows)`.
+    pub max_window_size: (i16, i16),
+}
+
+impl ScreenBufferInfo {
+    ///Creates instance from raw information.
+    pub fn from_raw(info: &CONSOLE_SCREEN_BUFFER_INFO) -> Self {
+        Self {
+            size: (info.dwSize.X, info.dwSize.Y),
+            cursor: (info.dwCursorPosition.X, info.dwCursorPosition.Y),
+            attributes: TextAttributes::from_raw(info.wAttributes),
+            window: CellRect::from_raw(&info.srWindow),
+            max_window_size: (info.dwMaximumWindowSize.X, info.dwMaximumWindowSize.Y),
+        }
+    }
+
+    #[inline]
+    ///Returns number of visible columns.
+    pub fn window_width(&self) -> i16 {
+        self.window.width()
+    }
+
+    #[inline]
+    ///Returns number of visible rows.
+    pub fn window_height(&self) -> i16 {
+        self.window.height()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///State of control keys during input event.
+pub struct ControlKeys {
+    inner: DWORD,
+}
+
+impl ControlKeys {
+    #[inline]
+    ///Creates instance from raw `dwControlKeyState`.
+    pub const fn from_raw(inner: DWORD) -> Self {
+        Self {
+            inner
+        }
+    }
+
+    #[inline]
+    ///Returns raw flags.
+    pub const fn raw(&self) -> DWORD {
+        self.inner
+    }
+
+    #[inline]
+    ///Returns whether either ALT key is pressed.
+    pub fn is_alt(&self) -> bool {
+        self.inner & (LEFT_ALT_PRESSED | RIGHT_ALT_PRESSED) != 0
+    }
+
+    #[inline]
+    ///Returns whether either CTRL key is pressed.
+    pub fn is_ctrl(&self) -> bool {
+        self.inner & (LEFT_CTRL_PRESSED | RIGHT_CTRL_PRESSED) != 0
+    }
+
+    #[inline]
+    ///Returns whether SHIFT key is pressed.
+    pub fn is_shift(&self) -> bool {
+        self.inner & SHIFT_PRESSED != 0
+    }
+
+    #[inline]
+    ///Returns whether key is extended one, e.g. arrow keys.
+    pub fn is_enhanced(&self) -> bool {
+        self.inner & ENHANCED_KEY != 0
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Keyboard input event.
+pub struct KeyEvent {
+    ///Whether key is pressed, rather than released.
+    pub is_down: bool,
+    ///Number of times key is repeated by holding it.
+    pub repeat_count: u16,
+    ///Virtual key code.
+    pub vk: u16,
+    ///Hardware scan code.
+    pub scan_code: u16,
+    ///UTF-16 code unit of translated character, or zero.
+    ///
+    ///Characters outside of BMP are reported by two events.
+    pub unit: u16,
+    ///State of control keys.
+    pub control_keys: ControlKeys,
+}
+
+impl KeyEvent {
+    ///Returns translated character, if it is not part of surrogate pair.
+    pub fn char(&self) -> Option<char> {
+        match self.unit {
+            0 => None,
+            unit => char::from_u32(u32::from(unit)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Mouse input event.
+///
+///Requires `ENABLE_MOUSE_INPUT` and disabled `ENABLE_QUICK_EDIT_MODE` on input handle.
+pub struct MouseEvent {
+    ///Position in character cells, as `(column, row)` of screen buffer.
+    pub position: (i16, i16),
+    ///Raw state of buttons.
+    ///
+    ///On wheel event high word contains signed wheel's delta.
+    pub buttons: DWORD,
+    ///State of control keys.
+    pub control_keys: ControlKeys,
+    ///Raw `MOUSE_MOVED`, `DOUBLE_CLICK` and wheel flags, zero on button press or release.
+    pub flags: DWORD,
+}
+
+impl MouseEvent {
+    #[inline]
+    ///Returns whether left button is pressed.
+    pub fn is_left(&self) -> bool {
+        self.buttons & FROM_LEFT_1ST_BUTTON_PRESSED != 0
+    }
+
+    #[inline]
+    ///Returns whether right button is pressed.
+    pub fn is_right(&self) -> bool {
+        self.buttons & RIGHTMOST_BUTTON_PRESSED != 0
+    }
+
+    #[inline]
+    ///Returns whether middle button is pressed.
+    pub fn is_middle(&self) -> bool {
+        self.buttons & FROM_LEFT_2ND_BUTTON_PRESSED != 0
+    }
+
+    #[inline]
+    ///Returns whether event is caused by movement.
+    pub fn is_moved(&self) -> bool {
+        self.flags & MOUSE_MOVED != 0
+    }
+
+    #[inline]
+    ///Returns whether event is second click of double click.
+    pub fn is_double_click(&self) -> bool {
+        self.flags & DOUBLE_CLICK != 0
+    }
+
+    ///Returns wheel's delta, positive if wheel is rotated forward or to the right.
+    pub fn wheel_delta(&self) -> Option<i16> {
+        match self.flags & (MOUSE_WHEELED | MOUSE_HWHEELED) {
+            0 => None,
+            _ => Some(((self.buttons >> 16) & 0xFFFF) as u16 as i16),
+        }
+    }
+
+    #[inline]
+    ///Returns whether wheel is horizontal.
+    pub fn is_horizontal_wheel(&self) -> bool {
+        self.flags & MOUSE_HWHEELED != 0
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Console input event.
+pub enum ConsoleEvent {
+    ///Keyboard event.
+    Key(KeyEvent),
+    ///Mouse event.
+    Mouse(MouseEvent),
+    ///Screen buffer is resized, as `(columns, rows)`.
+    ///
+    ///Requires `ENABLE_WINDOW_INPUT` on input handle.
+    Resize(i16, i16),
+    ///Console window gains or loses focus.
+    Focus(bool),
+    ///Menu command, used internally by console.
+    Menu(UINT),
+}
+
+impl ConsoleEvent {
+    ///Decodes input record, returning `None` for unknown type of event.
+    pub fn from_raw(record: &INPUT_RECORD) -> Option<Self> {
+        unsafe {
+            match record.EventType {
+                KEY_EVENT => {
+                    let event = &record.Event.KeyEvent;
+                    Some(ConsoleEvent::Key(KeyEvent {
+                        is_down: event.bKeyDown != 0,
+                        repeat_count: event.wRepeatCount,
+                        vk: event.wVirtualKeyCode,
+                        scan_code: event.wVirtualScanCode,
+                        unit: event.UnicodeChar,
+                        control_keys: ControlKeys::from_raw(event.dwControlKeyState),
+                    }))
+                },
+                MOUSE_EVENT => {
+                    let event = &record.Event.MouseEvent;
+                    Some(ConsoleEvent::Mouse(MouseEvent {
+                        position: (event.dwMousePosition.X, event.dwMousePosition.Y),
+                        buttons: event.dwButtonState,
+                        control_keys: ControlKeys::from_raw(event.dwControlKeyState),
+                        flags: event.dwEventFlags,
+                    }))
+                },
+                WINDOW_BUFFER_SIZE_EVENT => {
+                    let size = record.Event.WindowBufferSizeEvent.dwSize;
+                    Some(ConsoleEvent::Resize(size.X, size.Y))
+                },
+                FOCUS_EVENT => Some(ConsoleEvent::Focus(record.Event.FocusEvent.bSetFocus != 0)),
+                MENU_EVENT => Some(ConsoleEvent::Menu(record.Event.MenuEvent.dwCommandId)),
+                _ => None,
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Console control event.
+pub enum CtrlEvent {
+    ///CTRL+C is pressed.
+    CtrlC,
+    ///CTRL+BREAK is pressed.
+    CtrlBreak,
+    ///Console is closed.
+    Close,
+    ///User logs off. Received only by services.
+    Logoff,
+    ///System shuts down. Received only by services.
+    Shutdown,
+}
+
+impl CtrlEvent {
+    ///Creates instance from raw `CTRL_*_EVENT`.
+    pub fn from_raw(event: DWORD) -> Option<Self> {
+        match event {
+            CTRL_C_EVENT => Some(CtrlEvent::CtrlC),
+            CTRL_BREAK_EVENT => Some(CtrlEvent::CtrlBreak),
+            CTRL_CLOSE_EVENT => Some(CtrlEvent::Close),
+            CTRL_LOGOFF_EVENT => Some(CtrlEvent::Logoff),
+            CTRL_SHUTDOWN_EVENT => Some(CtrlEvent::Shutdown),
+            _ => None,
+        }
+    }
+
+    ///Returns raw `CTRL_*_EVENT`.
+    pub fn raw(self) -> DWORD {
+        match self {
+            CtrlEvent::CtrlC => CTRL_C_EVENT,
+            CtrlEvent::CtrlBreak => CTRL_BREAK_EVENT,
+            CtrlEvent::Close => CTRL_CLOSE_EVENT,
+            CtrlEvent::Logoff => CTRL_LOGOFF_EVENT,
+            CtrlEvent::Shutdown => CTRL_SHUTDOWN_EVENT,
+        }
+    }
+}
+
+#[cfg(windows)]
+///Allocates new console for the process.
+///
+///Fails if process already has console.
+pub fn alloc() -> Result<()> {
+    match unsafe { AllocConsole() } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+///Attaches to console of the specified process.
+///
+///Fails if process already has console.
+pub fn attach(pid: u32) -> Result<()> {
+    match unsafe { AttachConsole(pid as DWORD) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+///Attaches to console of the parent process, e.g. GUI application started from terminal.
+///
+///Fails if process already has console or parent has none.
+pub fn attach_parent() -> Result<()> {
+    match unsafe { AttachConsole(ATTACH_PARENT_PROCESS) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+///Detaches process from its console.
+pub fn free() -> Result<()> {
+    match unsafe { FreeConsole() } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+///Sets title of console window.
+pub fn set_title<T: AsRef<ffi::OsStr>>(title: T) -> Result<()> {
+    let mut title: Vec<u16> = title.as_ref().encode_wide().collect();
+    title.push(0);
+
+    match unsafe { SetConsoleTitleW(title.as_ptr()) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+///Console's standard handle.
+///
+///Handle is not closed on drop, as it is owned by process.
+pub struct Console {
+    handle: HANDLE,
+}
+
+#[cfg(windows)]
+impl Console {
+    fn std_handle(id: DWORD) -> Result<Self> {
+        let handle = unsafe { GetStdHandle(id) };
+
+        if handle == INVALID_HANDLE_VALUE {
+            Err(utils::get_last_error())
+        } else if handle.is_null() {
+            //Process has no console
+            Err(ErrorCode::new_system(ERROR_INVALID_HANDLE as c_int))
+        } else {
+            Ok(Self {
+                handle
+            })
+        }
+    }
+
+    #[inline]
+    ///Returns standard input handle.
+    pub fn stdin() -> Result<Self> {
+        Self::std_handle(STD_INPUT_HANDLE)
+    }
+
+    #[inline]
+    ///Returns standard output handle.
+    pub fn stdout() -> Result<Self> {
+        Self::std_handle(STD_OUTPUT_HANDLE)
+    }
+
+    #[inline]
+    ///Returns standard error handle.
+    pub fn stderr() -> Result<Self> {
+        Self::std_handle(STD_ERROR_HANDLE)
+    }
+
+    #[inline]
+    ///Creates instance from raw console handle, which is not closed on drop.
+    pub fn from_raw(handle: HANDLE) -> Self {
+        Self {
+            handle
+        }
+    }
+
+    #[inline]
+    ///Returns raw handle.
+    pub fn inner(&self) -> HANDLE {
+        self.handle
+    }
+
+    ///Returns mode of handle, i.e. `ENABLE_*` flags.
+    ///
+    ///Fails if handle is redirected, rather than console.
+    pub fn mode(&self) -> Result<DWORD> {
+        let mut mode = 0;
+
+        match unsafe { GetConsoleMode(self.handle, &mut mode) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(mode),
+        }
+    }
+
+    ///Sets mode of handle.
+    pub fn set_mode(&self, mode: DWORD) -> Result<()> {
+        match unsafe { SetConsoleMode(self.handle, mode) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(()),
+        }
+    }
+
+    ///Enables processing of virtual terminal sequences on output handle.
+    ///
+    ///Fails on Windows versions prior to Windows 10.
+    pub fn enable_virtual_terminal(&self) -> Result<()> {
+        let mode = self.mode()?;
+        self.set_mode(mode | ENABLE_PROCESSED_OUTPUT | ENABLE_VIRTUAL_TERMINAL_PROCESSING)
+    }
+
+    ///Enables translation of input into virtual terminal sequences on input handle.
+    pub fn enable_virtual_terminal_input(&self) -> Result<()> {
+        let mode = self.mode()?;
+        self.set_mode(mode | ENABLE_VIRTUAL_TERMINAL_INPUT)
+    }
+
+    ///Enables mouse and resize events on input handle.
+    ///
+    ///Quick edit mode is disabled, as it consumes mouse input.
+    pub fn enable_mouse_input(&self) -> Result<()> {
+        let mode = self.mode()?;
+        self.set_mode((mode & !ENABLE_QUICK_EDIT_MODE) | ENABLE_EXTENDED_FLAGS | ENABLE_MOUSE_INPUT | ENABLE_WINDOW_INPUT)
+    }
+
+    ///Sets attributes of subsequently written text.
+    pub fn set_text_attributes(&self, attributes: TextAttributes) -> Result<()> {
+        match unsafe { SetConsoleTextAttribute(self.handle, attributes.raw()) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(()),
+        }
+    }
+
+    ///Retrieves information about screen buffer.
+    pub fn screen_buffer_info(&self) -> Result<ScreenBufferInfo> {
+        let mut info: CONSOLE_SCREEN_BUFFER_INFO = unsafe { mem::zeroed() };
+
+        match unsafe { GetConsoleScreenBufferInfo(self.handle, &mut info) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(ScreenBufferInfo::from_raw(&info)),
+        }
+    }
+
+    ///Moves cursor to `(column, row)` of screen buffer.
+    pub fn set_cursor_position(&self, column: i16, row: i16) -> Result<()> {
+        let position = COORD {
+            X: column,
+            Y: row,
+        };
+
+        match unsafe { SetConsoleCursorPosition(self.handle, position) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(()),
+        }
+    }
+
+    ///Sets size of screen buffer in character cells.
+    ///
+    ///Size cannot be less than size of window.
+    pub fn set_buffer_size(&self, columns: i16, rows: i16) -> Result<()> {
+        let size = COORD {
+            X: columns,
+            Y: rows,
+        };
+
+        match unsafe { SetConsoleScreenBufferSize(self.handle, size) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(()),
+        }
+    }
+
+    ///Sets size of window in character cells, keeping its top-left corner.
+    ///
+    ///Window cannot exceed size of screen buffer.
+    pub fn set_window_size(&self, columns: i16, rows: i16) -> Result<()> {
+        //Zero sized window is invalid.
+        if columns < 1 || rows < 1 {
+            return Err(ErrorCode::new_system(ERROR_INVALID_PARAMETER as c_int));
+        }
+
+        let info = self.screen_buffer_info()?;
+        let left = info.window.left as SHORT;
+        let top = info.window.top as SHORT;
+        let rect = SMALL_RECT {
+            Left: left,
+            Top: top,
+            Right: left.saturating_add(columns - 1),
+            Bottom: top.saturating_add(rows - 1),
+        };
+
+        match unsafe { SetConsoleWindowInfo(self.handle, 1, &rect) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(()),
+        }
+    }
+
+    ///Returns number of unread input events.
+    pub fn pending_input(&self) -> Result<usize> {
+        let mut count = 0;
+
+        match unsafe { GetNumberOfConsoleInputEvents(self.handle, &mut count) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(count as usize),
+        }
+    }
+
+    ///Reads up to `max` input events, blocking until at least one is available.
+    ///
+    ///Events of unknown type are skipped.
+    pub fn read_input(&self, max: usize) -> Result<Vec<ConsoleEvent>> {
+        let mut records: Vec<INPUT_RECORD> = Vec::with_capacity(core::cmp::max(max, 1));
+        let mut count = 0;
+
+        match unsafe { ReadConsoleInputW(self.handle, records.as_mut_ptr(), records.capacity() as DWORD, &mut count) } {
+            0 => Err(utils::get_last_error()),
+            _ => {
+                unsafe {
+                    records.set_len(count as usize);
+                }
+                Ok(records.iter().filter_map(ConsoleEvent::from_raw).collect())
+            }
+        }
+    }
+
+    ///Discards unread input events.
+    pub fn flush_input(&self) -> Result<()> {
+        match unsafe { FlushConsoleInputBuffer(self.handle) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(windows)]
+//Callback is shared with running handlers, so that it can be removed from within itself.
+type CtrlCallback = Arc<Mutex<Box<dyn FnMut(CtrlEvent) -> bool + Send>>>;
+
+#[cfg(windows)]
+static CTRL_CB: Mutex<Option<CtrlCallback>> = Mutex::new(None);
+
+#[cfg(windows)]
+unsafe extern "system" fn ctrl_handler(event: DWORD) -> BOOL {
+    let event = match CtrlEvent::from_raw(event) {
+        Some(event) => event,
+        None => return 0,
+    };
+
+    //Slot is not locked while callback runs, as it can drop its handler.
+    let cb = match CTRL_CB.lock() {
+        Ok(slot) => slot.clone(),
+        Err(error) => error.into_inner().clone(),
+    };
+    let cb = match cb {
+        Some(cb) => cb,
+        None => return 0,
+    };
+
+    //Concurrent events are handled one by one.
+    let mut cb = match cb.lock() {
+        Ok(cb) => cb,
+        Err(error) => error.into_inner(),
+    };
+    //Panic must not cross FFI boundary.
+    panic::catch_unwind(panic::AssertUnwindSafe(|| cb(event))).unwrap_or(false) as BOOL
+}
+
+#[cfg(windows)]
+///Console control handler.
+///
+///Callback is invoked on separate thread, created by system for each event.
+///It returns whether event is handled, otherwise next handler is invoked, which by default terminates process.
+///
+///On `Close`, `Logoff` and `Shutdown` process is terminated once callback returns,
+///but system waits for limited amount of time only.
+///
+///Only one handler can be set at a time. Handler is removed on drop, which is allowed within callback too.
+pub struct CtrlHandler {
+    _priv: (),
+}
+
+#[cfg(windows)]
+impl CtrlHandler {
+    ///Sets handler.
+    ///
+    ///Fails with `ERROR_ALREADY_EXISTS` if another handler is set.
+    pub fn new<F: FnMut(CtrlEvent) -> bool + Send + 'static>(cb: F) -> Result<Self> {
+        {
+            let mut slot = match CTRL_CB.lock() {
+                Ok(slot) => slot,
+                Err(error) => error.into_inner(),
+            };
+
+            if slot.is_some() {
+                return Err(ErrorCode::new_system(ERROR_ALREADY_EXISTS as c_int));
+            }
+            *slot = Some(Arc::new(Mutex::new(Box::new(cb))));
+        }
+
+        match unsafe { SetConsoleCtrlHandler(Some(ctrl_handler), 1) } {
+            0 => {
+                let error = utils::get_last_error();
+                Self::clear();
+                Err(error)
+            },
+            _ => Ok(Self {
+                _priv: ()
+            })
+        }
+    }
+
+    fn clear() {
+        let mut slot = match CTRL_CB.lock() {
+            Ok(slot) => slot,
+            Err(error) => error.into_inner(),
+        };
+        *slot = None;
+    }
+}
+
+#[cfg(windows)]
+impl Drop for CtrlHandler {
+    fn drop(&mut self) {
+        unsafe {
+            SetConsoleCtrlHandler(Some(ctrl_handler), 0);
+        }
+        Self::clear();
+    }
+}
@@ -0,0 +1,549 @@
+//! Readers of common controls, including controls of other processes.
+//!
+//! `ListBox` and `ComboBox` messages are marshaled by the system, while `ListView` and `TreeView`
+//! require structures to be placed into memory of the target process.
+//! Layout of these structures depends on bitness of the target process, rather than own.
+//!
+//! Encoding of structures is platform independent.
+
+use crate::sys::{
+    c_int,
+    LVIF_TEXT,
+    LVCF_TEXT,
+    LVCF_WIDTH,
+    TVIF_TEXT,
+    TVIF_HANDLE,
+    TVIF_CHILDREN,
+};
+
+#[cfg(windows)]
+use crate::sys::*;
+#[cfg(windows)]
+use crate::raw;
+#[cfg(windows)]
+use crate::raw::memory::RemoteBuffer;
+#[cfg(windows)]
+use crate::utils::{ErrorCode, Result};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Bitness of process.
+pub enum Bitness {
+    ///32-bit process.
+    Bits32,
+    ///64-bit process.
+    Bits64,
+}
+
+impl Bitness {
+    #[inline]
+    ///Returns bitness of the current process.
+    pub fn current() -> Self {
+        if cfg!(target_pointer_width = "64") {
+            Bitness::Bits64
+        } else {
+            Bitness::Bits32
+        }
+    }
+
+    #[inline]
+    ///Returns size of pointer.
+    pub fn pointer_size(self) -> usize {
+        match self {
+            Bitness::Bits32 => 4,
+            Bitness::Bits64 => 8,
+        }
+    }
+
+    #[inline]
+    fn select(self, bits32: usize, bits64: usize) -> usize {
+        match self {
+            Bitness::Bits32 => bits32,
+            Bitness::Bits64 => bits64,
+        }
+    }
+}
+
+struct Encoder {
+    bitness: Bitness,
+    data: Vec<u8>,
+}
+
+impl Encoder {
+    fn new(bitness: Bitness, size32: usize, size64: usize) -> Self {
+        Self {
+            bitness,
+            data: vec![0; bitness.select(size32, size64)],
+        }
+    }
+
+    fn uint(mut self, offset: usize, value: u32) -> Self {
+        self.data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn int(self, offset: usize, value: c_int) -> Self {
+        self.uint(offset, value as u32)
+    }
+
+    fn ptr(mut self, offset32: usize, offset64: usize, value: u64) -> Self {
+        match self.bitness {
+            Bitness::Bits32 => self.data[offset32..offset32 + 4].copy_from_slice(&(value as u32).to_le_bytes()),
+            Bitness::Bits64 => self.data[offset64..offset64 + 8].copy_from_slice(&value.to_le_bytes()),
+        }
+        self
+    }
+}
+
+fn decode_int(data: &[u8], offset: usize) -> c_int {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&data[offset..offset + 4]);
+    i32::from_le_bytes(bytes)
+}
+
+///Encodes `LVITEMW` to retrieve text of item with `LVM_GETITEMTEXTW`.
+///
+///# Parameters
+///
+///* ```bitness``` - Bitness of target process.
+///* ```sub_item``` - Index of sub item, i.e. column.
+///* ```text``` - Address of text buffer within target process.
+///* ```text_max``` - Size of text buffer in characters.
+pub fn encode_list_view_item(bitness: Bitness, sub_item: c_int, text: u64, text_max: c_int) -> Vec<u8> {
+    Encoder::new(bitness, 60, 88).uint(0, LVIF_TEXT)
+                                 .int(8, sub_item)
+                                 .ptr(20, 24, text)
+                                 .int(bitness.select(24, 32), text_max)
+                                 .data
+}
+
+///Encodes `LVCOLUMNW` to retrieve text and width of column with `LVM_GETCOLUMNW`.
+///
+///# Parameters
+///
+///* ```bitness``` - Bitness of target process.
+///* ```text``` - Address of text buffer within target process.
+///* ```text_max``` - Size of text buffer in characters.
+pub fn encode_list_view_column(bitness: Bitness, text: u64, text_max: c_int) -> Vec<u8> {
+    Encoder::new(bitness, 44, 56).uint(0, LVCF_TEXT | LVCF_WIDTH)
+                                 .ptr(12, 16, text)
+                                 .int(bitness.select(16, 24), text_max)
+                                 .data
+}
+
+///Decodes width of column from `LVCOLUMNW`.
+pub fn decode_list_view_column_width(_bitness: Bitness, data: &[u8]) -> c_int {
+    decode_int(data, 8)
+}
+
+///Encodes `TVITEMW` to retrieve text and children of item with `TVM_GETITEMW`.
+///
+///# Parameters
+///
+///* ```bitness``` - Bitness of target process.
+///* ```item``` - Handle of item.
+///* ```text``` - Address of text buffer within target process.
+///* ```text_max``` - Size of text buffer in characters.
+pub fn encode_tree_view_item(bitness: Bitness, item: u64, text: u64, text_max: c_int) -> Vec<u8> {
+    Encoder::new(bitness, 40, 56).uint(0, TVIF_TEXT | TVIF_HANDLE | TVIF_CHILDREN)
+                                 .ptr(4, 8, item)
+                                 .ptr(16, 24, text)
+                                 .int(bitness.select(20, 32), text_max)
+                                 .data
+}
+
+///Decodes whether item has children from `TVITEMW`.
+pub fn decode_tree_view_item_children(bitness: Bitness, data: &[u8]) -> bool {
+    decode_int(data, bitness.select(32, 44)) != 0
+}
+
+///Decodes UTF-16 text, which ends with first null character.
+pub fn decode_text(data: &[u8]) -> String {
+    let text: Vec<u16> = data.chunks_exact(2).map(|ch| u16::from_le_bytes([ch[0], ch[1]])).take_while(|ch| *ch != 0).collect();
+    String::from_utf16_lossy(&text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+///Column of list view.
+pub struct Column {
+    ///Header's text.
+    pub text: String,
+    ///Width in pixels.
+    pub width: c_int,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+///Item of tree view.
+pub struct TreeItem {
+    ///Handle of item within target process.
+    pub handle: usize,
+    ///Depth, starting with `0` for root items.
+    pub depth: usize,
+    ///Text.
+    pub text: String,
+    ///Whether item has children, even if they are not yet inserted.
+    pub has_children: bool,
+}
+
+#[cfg(windows)]
+//Timeout of each message, in milliseconds.
+const TIMEOUT: Option<UINT> = Some(5000);
+#[cfg(windows)]
+const INITIAL_TEXT_LEN: usize = 256;
+#[cfg(windows)]
+const MAX_TEXT_LEN: usize = 32 * 1024;
+
+#[cfg(windows)]
+//ERROR_NOT_SUPPORTED
+const NOT_SUPPORTED: c_int = 50;
+#[cfg(windows)]
+//ERROR_INVALID_INDEX
+const INVALID_INDEX: c_int = 1413;
+
+#[cfg(windows)]
+impl Bitness {
+    ///Determines bitness of the process.
+    ///
+    ///Fails with `ERROR_NOT_SUPPORTED` if 32-bit process inspects 64-bit process.
+    pub fn of_process(process: HANDLE) -> Result<Self> {
+        let current = Self::current();
+
+        match raw::process::is_wow64(process)? {
+            true => Ok(Bitness::Bits32),
+            false => match current {
+                Bitness::Bits64 => Ok(Bitness::Bits64),
+                //Own process is WOW64, while target is not, so it is 64-bit.
+                Bitness::Bits32 => match raw::process::is_wow64(raw::process::get_current_handle())? {
+                    true => Err(ErrorCode::new_system(NOT_SUPPORTED)),
+                    false => Ok(Bitness::Bits32),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+//Process that owns control.
+struct Target {
+    window: HWND,
+    process: HANDLE,
+    bitness: Bitness,
+}
+
+#[cfg(windows)]
+impl Target {
+    fn new(window: HWND) -> Result<Self> {
+        let (pid, _) = raw::window::get_thread_process_id(window);
+        let process = raw::process::open(pid, PROCESS_VM_OPERATION | PROCESS_VM_READ | PROCESS_VM_WRITE | PROCESS_QUERY_LIMITED_INFORMATION)?;
+
+        match Bitness::of_process(process) {
+            Ok(bitness) => Ok(Self {
+                window,
+                process,
+                bitness,
+            }),
+            Err(error) => {
+                let _ = raw::process::close(process);
+                Err(error)
+            }
+        }
+    }
+
+    #[inline]
+    fn send(&self, msg_type: UINT, w_param: WPARAM, l_param: LPARAM) -> Result<LRESULT> {
+        raw::window::send_message(self.window, msg_type, w_param, l_param, TIMEOUT)
+    }
+
+    //Places structure, produced by `encode` with address and size of text buffer, into target process
+    //and sends message with it. Buffer is grown while text seems to be truncated.
+    //
+    //Returns text and structure after message is processed.
+    fn query<E, F>(&self, mut encode: E, mut send: F) -> Result<(String, Vec<u8>)>
+        where E: FnMut(u64, c_int) -> Vec<u8>, F: FnMut(LPARAM) -> Result<LRESULT>
+    {
+        let mut text_len = INITIAL_TEXT_LEN;
+
+        loop {
+            let struct_size = encode(0, 0).len();
+            let buffer = RemoteBuffer::alloc(self.process, struct_size + text_len * 2)?;
+            let text_addr = buffer.addr() + struct_size;
+
+            buffer.write(0, &encode(text_addr as u64, text_len as c_int))?;
+            send(buffer.addr() as LPARAM)?;
+
+            let mut data = vec![0; buffer.size()];
+            buffer.read(0, &mut data)?;
+
+            let text = decode_text(&data[struct_size..]);
+            if text.encode_utf16().count() < text_len - 1 || text_len >= MAX_TEXT_LEN {
+                data.truncate(struct_size);
+                return Ok((text, data));
+            }
+
+            text_len *= 2;
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for Target {
+    fn drop(&mut self) {
+        let _ = raw::process::close(self.process);
+    }
+}
+
+#[cfg(windows)]
+///Reader of list view, i.e. `SysListView32`.
+pub struct ListView {
+    target: Target,
+}
+
+#[cfg(windows)]
+impl ListView {
+    ///Opens process that owns list view.
+    pub fn new(window: HWND) -> Result<Self> {
+        Target::new(window).map(|target| Self {
+            target
+        })
+    }
+
+    ///Retrieves number of items.
+    pub fn len(&self) -> Result<usize> {
+        self.target.send(LVM_GETITEMCOUNT, 0, 0).map(|len| len as usize)
+    }
+
+    ///Retrieves text of item's column.
+    ///
+    ///Column `0` corresponds to item itself.
+    pub fn item_text(&self, item: usize, sub_item: usize) -> Result<String> {
+        let bitness = self.target.bitness;
+
+        self.target.query(|text, text_max| encode_list_view_item(bitness, sub_item as c_int, text, text_max),
+                          |item_ptr| self.target.send(LVM_GETITEMTEXTW, item as WPARAM, item_ptr))
+                   .map(|(text, _)| text)
+    }
+
+    ///Retrieves columns.
+    ///
+    ///List view without header, e.g. not in report mode, has no columns.
+    pub fn columns(&self) -> Result<Vec<Column>> {
+        let bitness = self.target.bitness;
+        let mut result = Vec::new();
+
+        loop {
+            let column = self.target.query(|text, text_max| encode_list_view_column(bitness, text, text_max),
+                                           |column_ptr| self.target.send(LVM_GETCOLUMNW, result.len() as WPARAM, column_ptr).and_then(|res| match res {
+                                               0 => Err(ErrorCode::new_system(INVALID_INDEX)),
+                                               res => Ok(res),
+                                           }));
+
+            match column {
+                Ok((text, data)) => result.push(Column {
+                    text,
+                    width: decode_list_view_column_width(bitness, &data),
+                }),
+                Err(error) if error.raw_code() == INVALID_INDEX => break Ok(result),
+                Err(error) => break Err(error),
+            }
+        }
+    }
+
+    ///Retrieves text of all items, each with text of all columns.
+    pub fn items(&self) -> Result<Vec<Vec<String>>> {
+        let columns = core::cmp::max(self.columns()?.len(), 1);
+        let len = self.len()?;
+        let mut result = Vec::with_capacity(len);
+
+        for item in 0..len {
+            let mut row = Vec::with_capacity(columns);
+            for sub_item in 0..columns {
+                row.push(self.item_text(item, sub_item)?);
+            }
+            result.push(row);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(windows)]
+///Reader of tree view, i.e. `SysTreeView32`.
+pub struct TreeView {
+    target: Target,
+}
+
+#[cfg(windows)]
+impl TreeView {
+    ///Opens process that owns tree view.
+    pub fn new(window: HWND) -> Result<Self> {
+        Target::new(window).map(|target| Self {
+            target
+        })
+    }
+
+    ///Retrieves number of items.
+    pub fn len(&self) -> Result<usize> {
+        self.target.send(TVM_GETCOUNT, 0, 0).map(|len| len as usize)
+    }
+
+    fn next_item(&self, relation: WPARAM, item: LRESULT) -> Result<LRESULT> {
+        self.target.send(TVM_GETNEXTITEM, relation, item as LPARAM)
+    }
+
+    fn item(&self, handle: LRESULT, depth: usize) -> Result<TreeItem> {
+        let bitness = self.target.bitness;
+
+        let (text, data) = self.target.query(|text, text_max| encode_tree_view_item(bitness, handle as u64, text, text_max),
+                                             |item_ptr| self.target.send(TVM_GETITEMW, 0, item_ptr))?;
+
+        Ok(TreeItem {
+            handle: handle as usize,
+            depth,
+            text,
+            has_children: decode_tree_view_item_children(bitness, &data),
+        })
+    }
+
+    ///Retrieves all items in depth-first order.
+    ///
+    ///Items of collapsed nodes are included only if they are already inserted,
+    ///as some applications insert them on expansion.
+    pub fn walk(&self) -> Result<Vec<TreeItem>> {
+        let mut result = Vec::new();
+        let mut stack = Vec::new();
+
+        let root = self.next_item(TVGN_ROOT, 0)?;
+        if root != 0 {
+            stack.push((root, 0));
+        }
+
+        while let Some((handle, depth)) = stack.pop() {
+            result.push(self.item(handle, depth)?);
+
+            //Next sibling is visited after children.
+            let next = self.next_item(TVGN_NEXT, handle)?;
+            if next != 0 {
+                stack.push((next, depth));
+            }
+            let child = self.next_item(TVGN_CHILD, handle)?;
+            if child != 0 {
+                stack.push((child, depth + 1));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(windows)]
+fn get_list_text(window: HWND, get_len: UINT, get_text: UINT, idx: usize) -> Result<String> {
+    let len = raw::window::send_message(window, get_len, idx as WPARAM, 0, TIMEOUT)?;
+    if len == LB_ERR {
+        return Err(ErrorCode::new_system(INVALID_INDEX));
+    }
+
+    let mut text: Vec<u16> = vec![0; len as usize + 1];
+    let len = raw::window::send_message(window, get_text, idx as WPARAM, text.as_mut_ptr() as LPARAM, TIMEOUT)?;
+    if len == LB_ERR {
+        return Err(ErrorCode::new_system(INVALID_INDEX));
+    }
+
+    Ok(String::from_utf16_lossy(&text[..core::cmp::min(len as usize, text.len() - 1)]))
+}
+
+#[cfg(windows)]
+fn get_list_selected(window: HWND, get_sel: UINT) -> Result<Option<usize>> {
+    match raw::window::send_message(window, get_sel, 0, 0, TIMEOUT)? {
+        LB_ERR => Ok(None),
+        idx => Ok(Some(idx as usize)),
+    }
+}
+
+#[cfg(windows)]
+///Reader of list box.
+///
+///Only list boxes with strings, i.e. `LBS_HASSTRINGS`, are supported.
+pub struct ListBox {
+    window: HWND,
+}
+
+#[cfg(windows)]
+impl ListBox {
+    #[inline]
+    ///Creates new instance.
+    pub fn new(window: HWND) -> Self {
+        Self {
+            window
+        }
+    }
+
+    ///Retrieves number of items.
+    pub fn len(&self) -> Result<usize> {
+        raw::window::send_message(self.window, LB_GETCOUNT, 0, 0, TIMEOUT).map(|len| len as usize)
+    }
+
+    #[inline]
+    ///Retrieves text of item.
+    pub fn item_text(&self, idx: usize) -> Result<String> {
+        get_list_text(self.window, LB_GETTEXTLEN, LB_GETTEXT, idx)
+    }
+
+    ///Retrieves text of all items.
+    pub fn items(&self) -> Result<Vec<String>> {
+        (0..self.len()?).map(|idx| self.item_text(idx)).collect()
+    }
+
+    #[inline]
+    ///Retrieves index of selected item in single-selection list box.
+    pub fn selected_index(&self) -> Result<Option<usize>> {
+        get_list_selected(self.window, LB_GETCURSEL)
+    }
+}
+
+#[cfg(windows)]
+///Reader of combo box.
+///
+///Only combo boxes with strings, i.e. `CBS_HASSTRINGS`, are supported.
+pub struct ComboBox {
+    window: HWND,
+}
+
+#[cfg(windows)]
+impl ComboBox {
+    #[inline]
+    ///Creates new instance.
+    pub fn new(window: HWND) -> Self {
+        Self {
+            window
+        }
+    }
+
+    ///Retrieves number of items.
+    pub fn len(&self) -> Result<usize> {
+        raw::window::send_message(self.window, CB_GETCOUNT, 0, 0, TIMEOUT).map(|len| len as usize)
+    }
+
+    #[inline]
+    ///Retrieves text of item.
+    pub fn item_text(&self, idx: usize) -> Result<String> {
+        get_list_text(self.window, CB_GETLBTEXTLEN, CB_GETLBTEXT, idx)
+    }
+
+    ///Retrieves text of all items.
+    pub fn items(&self) -> Result<Vec<String>> {
+        (0..self.len()?).map(|idx| self.item_text(idx)).collect()
+    }
+
+    #[inline]
+    ///Retrieves index of selected item.
+    pub fn selected_index(&self) -> Result<Option<usize>> {
+        get_list_selected(self.window, CB_GETCURSEL)
+    }
+
+    ///Retrieves text of selected item.
+    pub fn selected(&self) -> Result<Option<String>> {
+        match self.selected_index()? {
+            Some(idx) => self.item_text(idx).map(Some),
+            None => Ok(None),
+        }
+    }
+}
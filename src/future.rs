@@ -0,0 +1,282 @@
+//! Asynchronous access to Windows messages.
+//!
+//! Blocking WinAPI calls are performed on dedicated threads, so that async executor is never blocked.
+//!
+//! With feature `futures` [Messages](struct.Messages.html) implements `futures_core::Stream`.
+
+use std::thread;
+use std::future::Future;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use crate::sys::{HWND, MSG, UINT, WPARAM, LPARAM, LRESULT};
+use crate::message_loop::MessageThread;
+use crate::raw;
+use crate::utils::Result;
+
+///Message retrieved by message loop thread.
+///
+///It is only copy of message, which is dispatched on its own thread.
+#[derive(Copy, Clone)]
+pub struct PostedMsg {
+    inner: MSG
+}
+
+unsafe impl Send for PostedMsg {}
+
+impl PostedMsg {
+    #[inline]
+    ///Message identifier.
+    pub fn id(&self) -> UINT {
+        self.inner.message
+    }
+
+    #[inline]
+    ///Window to which message is addressed.
+    ///
+    ///Null for thread messages.
+    pub fn window(&self) -> HWND {
+        self.inner.hwnd
+    }
+
+    #[inline]
+    ///Message specific parameter.
+    pub fn w_param(&self) -> WPARAM {
+        self.inner.wParam
+    }
+
+    #[inline]
+    ///Message specific parameter.
+    pub fn l_param(&self) -> LPARAM {
+        self.inner.lParam
+    }
+
+    #[inline]
+    ///Retrieves raw Windows Message.
+    pub fn inner(&self) -> MSG {
+        self.inner
+    }
+}
+
+struct MessagesState {
+    queue: VecDeque<PostedMsg>,
+    waker: Option<Waker>,
+    is_closed: bool,
+}
+
+type SharedMessages = Arc<Mutex<MessagesState>>;
+
+fn wake(waker: Option<Waker>) {
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+//Lives on loop thread and notifies stream when loop exits.
+struct MessagesSender {
+    state: SharedMessages,
+}
+
+impl MessagesSender {
+    fn send(&self, msg: &MSG) {
+        let waker = {
+            let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+            state.queue.push_back(PostedMsg { inner: *msg });
+            state.waker.take()
+        };
+
+        wake(waker);
+    }
+}
+
+impl Drop for MessagesSender {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+            state.is_closed = true;
+            state.waker.take()
+        };
+
+        wake(waker);
+    }
+}
+
+///Stream of messages retrieved by dedicated message loop thread.
+///
+///Messages are queued to be consumed by async code, while thread itself
+///continues to dispatch them to window procedures.
+///
+///Stream ends once message loop is stopped, e.g. by `WM_QUIT`.
+///On drop message loop thread is stopped.
+///
+///## Usage
+///
+///Windows, which messages you want to receive, must be created within `init` of [spawn()](#method.spawn),
+///as messages are delivered to the thread that created window.
+pub struct Messages {
+    state: SharedMessages,
+    thread: MessageThread,
+}
+
+impl Messages {
+    ///Spawns message loop thread.
+    ///
+    ///# Parameters:
+    ///
+    ///* ```init``` - Runs on loop thread and creates resources that live as long as loop.
+    pub fn spawn<I, T>(init: I) -> Result<Self> where I: FnOnce() -> Result<T> + Send + 'static, T: 'static {
+        let state = Arc::new(Mutex::new(MessagesState {
+            queue: VecDeque::new(),
+            waker: None,
+            is_closed: false,
+        }));
+
+        let sender = MessagesSender {
+            state: state.clone()
+        };
+        let thread = MessageThread::spawn(move || {
+            let resources = init()?;
+
+            Ok(move |msg: &MSG| {
+                //Keeps resources alive as long as loop runs
+                let _ = &resources;
+                sender.send(msg);
+            })
+        })?;
+
+        Ok(Self {
+            state,
+            thread
+        })
+    }
+
+    #[inline]
+    ///Returns message loop thread.
+    ///
+    ///It can be used to post thread messages into stream.
+    pub fn thread(&self) -> &MessageThread {
+        &self.thread
+    }
+
+    ///Attempts to retrieve next message.
+    ///
+    ///Returns `None` once message loop is stopped and all messages are consumed.
+    pub fn poll_recv(&mut self, ctx: &mut Context<'_>) -> Poll<Option<PostedMsg>> {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+
+        match state.queue.pop_front() {
+            Some(msg) => Poll::Ready(Some(msg)),
+            None if state.is_closed => Poll::Ready(None),
+            None => {
+                state.waker = Some(ctx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    #[inline]
+    ///Returns future that resolves into next message.
+    pub fn recv(&mut self) -> Recv<'_> {
+        Recv {
+            messages: self
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl futures_core::Stream for Messages {
+    type Item = PostedMsg;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_recv(ctx)
+    }
+}
+
+///Future returned by [Messages::recv()](struct.Messages.html#method.recv).
+pub struct Recv<'a> {
+    messages: &'a mut Messages,
+}
+
+impl Future for Recv<'_> {
+    type Output = Option<PostedMsg>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.messages.poll_recv(ctx)
+    }
+}
+
+enum SendState {
+    Pending(Option<Waker>),
+    Done(Result<LRESULT>),
+    Taken,
+}
+
+///Future of [send_message()](fn.send_message.html).
+pub struct SendMessage {
+    state: Arc<Mutex<SendState>>,
+}
+
+impl Future for SendMessage {
+    type Output = Result<LRESULT>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+
+        match &mut *state {
+            SendState::Pending(waker) => {
+                *waker = Some(ctx.waker().clone());
+                Poll::Pending
+            },
+            SendState::Done(_) => match core::mem::replace(&mut *state, SendState::Taken) {
+                SendState::Done(result) => Poll::Ready(result),
+                _ => unreachable!(),
+            },
+            SendState::Taken => panic!("SendMessage is polled after completion"),
+        }
+    }
+}
+
+///Sends message to a window using `SendMessageTimeoutW` on separate thread.
+///
+///Refer to [raw::window::send_message()](../raw/window/fn.send_message.html) for details.
+///
+///# Note:
+///
+///Dropping future does not cancel message, so any memory referenced by `l_param`
+///must remain valid until either future completes or `timeout` is expired.
+///
+///# Parameters
+///
+///* ```window``` - Handle to the window for which to send.
+///* ```msg_type``` - Type of message. See WinAPI docs.
+///* ```w_param``` - Additional message specific parameter.
+///* ```l_param``` - Additional message specific parameter.
+///* ```timeout``` - Timeout in milliseconds.
+pub fn send_message(window: HWND, msg_type: UINT, w_param: WPARAM, l_param: LPARAM, timeout: UINT) -> SendMessage {
+    let state = Arc::new(Mutex::new(SendState::Pending(None)));
+    let result_state = state.clone();
+    let window = window as usize;
+
+    let task = move || {
+        let result = raw::window::send_message(window as HWND, msg_type, w_param, l_param, Some(timeout));
+
+        let mut state = result_state.lock().unwrap_or_else(|error| error.into_inner());
+        if let SendState::Pending(waker) = core::mem::replace(&mut *state, SendState::Done(result)) {
+            drop(state);
+            wake(waker);
+        }
+    };
+
+    if let Err(error) = thread::Builder::new().name("windows-win-send-message".to_owned()).spawn(task) {
+        *state.lock().unwrap_or_else(|error| error.into_inner()) = SendState::Done(Err(error.into()));
+    }
+
+    SendMessage {
+        state
+    }
+}
+
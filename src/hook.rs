@@ -0,0 +1,402 @@
+//! Low-level keyboard and mouse hooks.
+//!
+//! Low-level hooks are called in context of the thread that installed it,
+//! by sending message to this thread.
+//! Therefore the installing thread must run message loop, otherwise input of whole system is stalled
+//! until hook times out.
+//! Use `spawn` methods to install hook on dedicated [MessageThread](../message_loop/struct.MessageThread.html).
+//!
+//! Conversion of raw hook structures into events is platform independent.
+
+use crate::sys::{
+    UINT,
+    DWORD,
+    LONG,
+    WPARAM,
+    KBDLLHOOKSTRUCT,
+    MSLLHOOKSTRUCT,
+    LLKHF_EXTENDED,
+    LLKHF_INJECTED,
+    LLKHF_ALTDOWN,
+    LLKHF_UP,
+    LLMHF_INJECTED,
+    WM_SYSKEYDOWN,
+    WM_SYSKEYUP,
+    WM_MOUSEMOVE,
+    WM_LBUTTONDOWN,
+    WM_LBUTTONUP,
+    WM_RBUTTONDOWN,
+    WM_RBUTTONUP,
+    WM_MBUTTONDOWN,
+    WM_MBUTTONUP,
+    WM_MOUSEWHEEL,
+    WM_MOUSEHWHEEL,
+    WM_XBUTTONDOWN,
+    WM_XBUTTONUP,
+    XBUTTON1,
+};
+use crate::input::MouseButton;
+
+#[cfg(windows)]
+use crate::sys::{
+    c_int,
+    MSG,
+    HHOOK,
+    HOOKPROC,
+    LPARAM,
+    LRESULT,
+    HC_ACTION,
+    WH_KEYBOARD_LL,
+    WH_MOUSE_LL,
+    SetWindowsHookExW,
+    UnhookWindowsHookEx,
+    CallNextHookEx,
+    GetModuleHandleW,
+};
+#[cfg(windows)]
+use crate::message_loop::MessageThread;
+#[cfg(windows)]
+use crate::utils::{self, Result};
+#[cfg(windows)]
+use crate::ErrorCode;
+#[cfg(windows)]
+use std::{panic, thread};
+#[cfg(windows)]
+use core::{ptr, cell::{Cell, RefCell}};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Decision of hook callback.
+pub enum HookAction {
+    ///Passes event to the next hook and eventually to the target.
+    Pass,
+    ///Prevents event from reaching the rest of the hook chain and the target.
+    Block,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Low-level keyboard event.
+pub struct KeyboardEvent {
+    ///Keyboard message: `WM_KEYDOWN`, `WM_KEYUP`, `WM_SYSKEYDOWN` or `WM_SYSKEYUP`.
+    pub message: UINT,
+    ///Virtual key code.
+    pub vk: DWORD,
+    ///Hardware scan code.
+    pub scan_code: DWORD,
+    ///`LLKHF_*` flags.
+    pub flags: DWORD,
+    ///Time stamp in milliseconds.
+    pub time: DWORD,
+}
+
+impl KeyboardEvent {
+    #[inline]
+    ///Creates event from raw hook parameters.
+    pub fn from_raw(message: WPARAM, raw: &KBDLLHOOKSTRUCT) -> Self {
+        Self {
+            message: message as UINT,
+            vk: raw.vkCode,
+            scan_code: raw.scanCode,
+            flags: raw.flags,
+            time: raw.time,
+        }
+    }
+
+    #[inline]
+    ///Returns whether key is pressed.
+    pub fn is_down(&self) -> bool {
+        self.flags & LLKHF_UP == 0
+    }
+
+    #[inline]
+    ///Returns whether key is released.
+    pub fn is_up(&self) -> bool {
+        !self.is_down()
+    }
+
+    #[inline]
+    ///Returns whether it is system key, i.e. F10 or key pressed while ALT is held down.
+    pub fn is_system(&self) -> bool {
+        self.message == WM_SYSKEYDOWN || self.message == WM_SYSKEYUP
+    }
+
+    #[inline]
+    ///Returns whether ALT key is held down.
+    pub fn is_alt_down(&self) -> bool {
+        self.flags & LLKHF_ALTDOWN != 0
+    }
+
+    #[inline]
+    ///Returns whether key is extended key, like right CTRL.
+    pub fn is_extended(&self) -> bool {
+        self.flags & LLKHF_EXTENDED != 0
+    }
+
+    #[inline]
+    ///Returns whether event is injected, e.g. by `SendInput`.
+    pub fn is_injected(&self) -> bool {
+        self.flags & LLKHF_INJECTED != 0
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Kind of low-level mouse event.
+pub enum MouseEventKind {
+    ///Cursor is moved.
+    Move,
+    ///Button is pressed.
+    ButtonDown(MouseButton),
+    ///Button is released.
+    ButtonUp(MouseButton),
+    ///Vertical wheel is rotated by delta. Positive is forward.
+    Wheel(i16),
+    ///Horizontal wheel is rotated by delta. Positive is to the right.
+    HWheel(i16),
+    ///Unknown message.
+    Other(UINT),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Low-level mouse event.
+pub struct MouseEvent {
+    ///Event kind.
+    pub kind: MouseEventKind,
+    ///Horizontal position in per-monitor aware screen coordinates.
+    pub x: LONG,
+    ///Vertical position in per-monitor aware screen coordinates.
+    pub y: LONG,
+    ///`LLMHF_*` flags.
+    pub flags: DWORD,
+    ///Time stamp in milliseconds.
+    pub time: DWORD,
+}
+
+impl MouseEvent {
+    ///Creates event from raw hook parameters.
+    pub fn from_raw(message: WPARAM, raw: &MSLLHOOKSTRUCT) -> Self {
+        let data = (raw.mouseData >> 16) as u16;
+        let x_button = match data == XBUTTON1 {
+            true => MouseButton::X1,
+            false => MouseButton::X2,
+        };
+
+        let kind = match message as UINT {
+            WM_MOUSEMOVE => MouseEventKind::Move,
+            WM_LBUTTONDOWN => MouseEventKind::ButtonDown(MouseButton::Left),
+            WM_LBUTTONUP => MouseEventKind::ButtonUp(MouseButton::Left),
+            WM_RBUTTONDOWN => MouseEventKind::ButtonDown(MouseButton::Right),
+            WM_RBUTTONUP => MouseEventKind::ButtonUp(MouseButton::Right),
+            WM_MBUTTONDOWN => MouseEventKind::ButtonDown(MouseButton::Middle),
+            WM_MBUTTONUP => MouseEventKind::ButtonUp(MouseButton::Middle),
+            WM_XBUTTONDOWN => MouseEventKind::ButtonDown(x_button),
+            WM_XBUTTONUP => MouseEventKind::ButtonUp(x_button),
+            WM_MOUSEWHEEL => MouseEventKind::Wheel(data as i16),
+            WM_MOUSEHWHEEL => MouseEventKind::HWheel(data as i16),
+            message => MouseEventKind::Other(message),
+        };
+
+        Self {
+            kind,
+            x: raw.pt.x,
+            y: raw.pt.y,
+            flags: raw.flags,
+            time: raw.time,
+        }
+    }
+
+    #[inline]
+    ///Returns whether event is injected, e.g. by `SendInput`.
+    pub fn is_injected(&self) -> bool {
+        self.flags & LLMHF_INJECTED != 0
+    }
+}
+
+#[cfg(windows)]
+type Callback<E> = Box<dyn FnMut(&E) -> HookAction>;
+#[cfg(windows)]
+type Slot<E> = thread::LocalKey<HookSlot<E>>;
+
+#[cfg(windows)]
+struct HookSlot<E> {
+    cb: RefCell<Option<Callback<E>>>,
+    //Set when hook is removed from within its own callback.
+    is_pending_clear: Cell<bool>,
+}
+
+#[cfg(windows)]
+impl<E> HookSlot<E> {
+    const fn new() -> Self {
+        Self {
+            cb: RefCell::new(None),
+            is_pending_clear: Cell::new(false),
+        }
+    }
+}
+
+#[cfg(windows)]
+thread_local! {
+    static KEYBOARD_CB: HookSlot<KeyboardEvent> = const { HookSlot::new() };
+    static MOUSE_CB: HookSlot<MouseEvent> = const { HookSlot::new() };
+}
+
+#[cfg(windows)]
+fn invoke<E>(slot: &'static Slot<E>, event: &E) -> HookAction {
+    slot.with(|slot| {
+        let result = match slot.cb.try_borrow_mut() {
+            Ok(mut cb) => match cb.as_mut() {
+                //Panic must not cross FFI boundary.
+                Some(cb) => panic::catch_unwind(panic::AssertUnwindSafe(|| cb(event))).unwrap_or(HookAction::Pass),
+                None => HookAction::Pass,
+            },
+            //Callback is re-entered by pumping messages within it.
+            Err(_) => return HookAction::Pass,
+        };
+
+        //Callback removed its own hook, so it can be dropped only now.
+        if slot.is_pending_clear.replace(false) {
+            let cb = slot.cb.borrow_mut().take();
+            let _ = panic::catch_unwind(panic::AssertUnwindSafe(move || drop(cb)));
+        }
+
+        result
+    })
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn keyboard_proc(code: c_int, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    if code == HC_ACTION {
+        let event = KeyboardEvent::from_raw(w_param, &*(l_param as *const KBDLLHOOKSTRUCT));
+        if invoke(&KEYBOARD_CB, &event) == HookAction::Block {
+            return 1;
+        }
+    }
+
+    CallNextHookEx(ptr::null_mut(), code, w_param, l_param)
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn mouse_proc(code: c_int, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    if code == HC_ACTION {
+        let event = MouseEvent::from_raw(w_param, &*(l_param as *const MSLLHOOKSTRUCT));
+        if invoke(&MOUSE_CB, &event) == HookAction::Block {
+            return 1;
+        }
+    }
+
+    CallNextHookEx(ptr::null_mut(), code, w_param, l_param)
+}
+
+#[cfg(windows)]
+fn install<E>(slot: &'static Slot<E>, id: c_int, proc: HOOKPROC, cb: Callback<E>) -> Result<HHOOK> {
+    //ERROR_ALREADY_EXISTS
+    const ALREADY_EXISTS: c_int = 183;
+
+    //Slot is busy while callback runs, even if its hook is already removed.
+    let is_installed = slot.with(|slot| slot.cb.try_borrow().map(|cb| cb.is_some()).unwrap_or(true));
+    if is_installed {
+        return Err(ErrorCode::new_system(ALREADY_EXISTS));
+    }
+
+    let hook = unsafe { SetWindowsHookExW(id, proc, GetModuleHandleW(ptr::null()), 0) };
+    if hook.is_null() {
+        return Err(utils::get_last_error());
+    }
+
+    slot.with(|slot| *slot.cb.borrow_mut() = Some(cb));
+    Ok(hook)
+}
+
+#[cfg(windows)]
+fn uninstall<E>(slot: &'static Slot<E>, hook: HHOOK) {
+    unsafe {
+        UnhookWindowsHookEx(hook);
+    }
+    slot.with(|slot| match slot.cb.try_borrow_mut() {
+        Ok(mut cb) => *cb = None,
+        //Callback is dropped from within itself, so clear slot once it returns.
+        Err(_) => slot.is_pending_clear.set(true),
+    });
+}
+
+#[cfg(windows)]
+///Low-level keyboard hook `WH_KEYBOARD_LL`.
+///
+///Only single keyboard hook can be installed per thread.
+///Hook is removed on drop, which must happen on the installing thread.
+///
+///# Note:
+///
+///Callback must return quickly, otherwise system skips it after `LowLevelHooksTimeout`.
+pub struct KeyboardHook {
+    hook: HHOOK,
+}
+
+#[cfg(windows)]
+impl KeyboardHook {
+    ///Installs hook on the current thread, which must run message loop.
+    pub fn new<F: FnMut(&KeyboardEvent) -> HookAction + 'static>(cb: F) -> Result<Self> {
+        install(&KEYBOARD_CB, WH_KEYBOARD_LL, Some(keyboard_proc), Box::new(cb)).map(|hook| Self { hook })
+    }
+
+    ///Spawns message loop thread with installed hook.
+    ///
+    ///Hook is removed once returned thread is stopped.
+    pub fn spawn<F: FnMut(&KeyboardEvent) -> HookAction + Send + 'static>(cb: F) -> Result<MessageThread> {
+        MessageThread::spawn(move || {
+            let hook = Self::new(cb)?;
+
+            Ok(move |_: &MSG| {
+                //Keeps hook alive as long as loop runs
+                let _ = &hook;
+            })
+        })
+    }
+}
+
+#[cfg(windows)]
+impl Drop for KeyboardHook {
+    fn drop(&mut self) {
+        uninstall(&KEYBOARD_CB, self.hook);
+    }
+}
+
+#[cfg(windows)]
+///Low-level mouse hook `WH_MOUSE_LL`.
+///
+///Only single mouse hook can be installed per thread.
+///Hook is removed on drop, which must happen on the installing thread.
+///
+///# Note:
+///
+///Callback must return quickly, otherwise system skips it after `LowLevelHooksTimeout`.
+pub struct MouseHook {
+    hook: HHOOK,
+}
+
+#[cfg(windows)]
+impl MouseHook {
+    ///Installs hook on the current thread, which must run message loop.
+    pub fn new<F: FnMut(&MouseEvent) -> HookAction + 'static>(cb: F) -> Result<Self> {
+        install(&MOUSE_CB, WH_MOUSE_LL, Some(mouse_proc), Box::new(cb)).map(|hook| Self { hook })
+    }
+
+    ///Spawns message loop thread with installed hook.
+    ///
+    ///Hook is removed once returned thread is stopped.
+    pub fn spawn<F: FnMut(&MouseEvent) -> HookAction + Send + 'static>(cb: F) -> Result<MessageThread> {
+        MessageThread::spawn(move || {
+            let hook = Self::new(cb)?;
+
+            Ok(move |_: &MSG| {
+                //Keeps hook alive as long as loop runs
+                let _ = &hook;
+            })
+        })
+    }
+}
+
+#[cfg(windows)]
+impl Drop for MouseHook {
+    fn drop(&mut self) {
+        uninstall(&MOUSE_CB, self.hook);
+    }
+}
@@ -0,0 +1,504 @@
+//! Global hotkeys.
+//!
+//! Hotkey is registered by [Hotkey](struct.Hotkey.html) with `RegisterHotKey`
+//! and `WM_HOTKEY` is delivered through message loop of the registering thread.
+//!
+//! Parsing of key combinations is platform independent.
+
+use core::{fmt, str};
+
+use crate::sys::{
+    MSG,
+    UINT,
+    WM_HOTKEY,
+    MOD_ALT,
+    MOD_CONTROL,
+    MOD_SHIFT,
+    MOD_WIN,
+    MOD_NOREPEAT,
+    VK_BACK,
+    VK_TAB,
+    VK_CLEAR,
+    VK_RETURN,
+    VK_PAUSE,
+    VK_CAPITAL,
+    VK_ESCAPE,
+    VK_SPACE,
+    VK_PRIOR,
+    VK_NEXT,
+    VK_END,
+    VK_HOME,
+    VK_LEFT,
+    VK_UP,
+    VK_RIGHT,
+    VK_DOWN,
+    VK_SNAPSHOT,
+    VK_INSERT,
+    VK_DELETE,
+    VK_APPS,
+    VK_NUMPAD0,
+    VK_MULTIPLY,
+    VK_ADD,
+    VK_SUBTRACT,
+    VK_DECIMAL,
+    VK_DIVIDE,
+    VK_F1,
+    VK_F24,
+    VK_NUMLOCK,
+    VK_SCROLL,
+    VK_VOLUME_MUTE,
+    VK_VOLUME_DOWN,
+    VK_VOLUME_UP,
+    VK_MEDIA_NEXT_TRACK,
+    VK_MEDIA_PREV_TRACK,
+    VK_MEDIA_STOP,
+    VK_MEDIA_PLAY_PAUSE,
+    VK_OEM_PLUS,
+    VK_OEM_COMMA,
+    VK_OEM_MINUS,
+    VK_OEM_PERIOD,
+};
+
+#[cfg(windows)]
+use crate::sys::{HWND, c_int, RegisterHotKey, UnregisterHotKey};
+#[cfg(windows)]
+use crate::message_loop::MessageThread;
+#[cfg(windows)]
+use crate::utils::{self, Result};
+#[cfg(windows)]
+use core::{ptr, sync::atomic};
+
+//Names of keys that cannot be derived from virtual key code.
+//First name is used when formatting.
+const KEY_NAMES: &[(&str, UINT)] = &[
+    ("Backspace", VK_BACK),
+    ("Tab", VK_TAB),
+    ("Clear", VK_CLEAR),
+    ("Enter", VK_RETURN),
+    ("Return", VK_RETURN),
+    ("Pause", VK_PAUSE),
+    ("CapsLock", VK_CAPITAL),
+    ("Esc", VK_ESCAPE),
+    ("Escape", VK_ESCAPE),
+    ("Space", VK_SPACE),
+    ("PageUp", VK_PRIOR),
+    ("PgUp", VK_PRIOR),
+    ("PageDown", VK_NEXT),
+    ("PgDn", VK_NEXT),
+    ("End", VK_END),
+    ("Home", VK_HOME),
+    ("Left", VK_LEFT),
+    ("Up", VK_UP),
+    ("Right", VK_RIGHT),
+    ("Down", VK_DOWN),
+    ("PrintScreen", VK_SNAPSHOT),
+    ("PrtSc", VK_SNAPSHOT),
+    ("Insert", VK_INSERT),
+    ("Ins", VK_INSERT),
+    ("Delete", VK_DELETE),
+    ("Del", VK_DELETE),
+    ("Apps", VK_APPS),
+    ("Multiply", VK_MULTIPLY),
+    ("Add", VK_ADD),
+    ("Subtract", VK_SUBTRACT),
+    ("Decimal", VK_DECIMAL),
+    ("Divide", VK_DIVIDE),
+    ("NumLock", VK_NUMLOCK),
+    ("ScrollLock", VK_SCROLL),
+    ("VolumeMute", VK_VOLUME_MUTE),
+    ("VolumeDown", VK_VOLUME_DOWN),
+    ("VolumeUp", VK_VOLUME_UP),
+    ("MediaNext", VK_MEDIA_NEXT_TRACK),
+    ("MediaPrev", VK_MEDIA_PREV_TRACK),
+    ("MediaStop", VK_MEDIA_STOP),
+    ("MediaPlayPause", VK_MEDIA_PLAY_PAUSE),
+    ("Plus", VK_OEM_PLUS),
+    ("Comma", VK_OEM_COMMA),
+    ("Minus", VK_OEM_MINUS),
+    ("Period", VK_OEM_PERIOD),
+];
+
+fn parse_numbered(name: &str, prefix: &str, min: UINT, max: UINT) -> Option<UINT> {
+    if name.len() <= prefix.len() || !name.is_char_boundary(prefix.len()) || !name[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        return None;
+    }
+
+    match name[prefix.len()..].parse::<UINT>() {
+        Ok(num) if num >= min && num <= max => Some(num),
+        _ => None,
+    }
+}
+
+///Parses name of key into virtual key code.
+///
+///Name is case insensitive and can be one of:
+///
+///- Latin letter or digit.
+///- Function key `F1`..`F24`.
+///- Numpad digit `Numpad0`..`Numpad9`.
+///- Named key like `Enter`, `Esc`, `PageUp`, `Plus`, `Comma`.
+pub fn parse_key(name: &str) -> Option<UINT> {
+    let mut chars = name.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return match ch {
+            'a'..='z' | 'A'..='Z' | '0'..='9' => Some(ch.to_ascii_uppercase() as UINT),
+            _ => None,
+        }
+    }
+
+    if let Some(num) = parse_numbered(name, "F", 1, VK_F24 - VK_F1 + 1) {
+        return Some(VK_F1 + num - 1);
+    }
+
+    if let Some(num) = parse_numbered(name, "Numpad", 0, 9) {
+        return Some(VK_NUMPAD0 + num);
+    }
+
+    KEY_NAMES.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, vk)| *vk)
+}
+
+///Retrieves name of virtual key code, that can be parsed back by [parse_key()](fn.parse_key.html).
+pub fn key_name(vk: UINT) -> Option<String> {
+    match vk {
+        0x30..=0x39 | 0x41..=0x5A => Some((vk as u8 as char).to_string()),
+        VK_F1..=VK_F24 => Some(format!("F{}", vk - VK_F1 + 1)),
+        _ if vk >= VK_NUMPAD0 && vk <= VK_NUMPAD0 + 9 => Some(format!("Numpad{}", vk - VK_NUMPAD0)),
+        vk => KEY_NAMES.iter().find(|(_, key)| *key == vk).map(|(name, _)| (*name).to_owned()),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Hotkey modifiers
+pub struct Modifiers {
+    inner: UINT
+}
+
+impl Modifiers {
+    #[inline]
+    ///Creates new instance without modifiers.
+    pub const fn new() -> Self {
+        Self {
+            inner: 0
+        }
+    }
+
+    #[inline]
+    ///Creates instance from raw `MOD_*` flags.
+    pub const fn from_raw(inner: UINT) -> Self {
+        Self {
+            inner
+        }
+    }
+
+    #[inline]
+    ///Returns raw `MOD_*` flags.
+    pub const fn raw(&self) -> UINT {
+        self.inner
+    }
+
+    ///Either ALT key must be held down.
+    pub fn alt(mut self) -> Self {
+        self.inner |= MOD_ALT;
+        self
+    }
+
+    ///Either CTRL key must be held down.
+    pub fn ctrl(mut self) -> Self {
+        self.inner |= MOD_CONTROL;
+        self
+    }
+
+    ///Either SHIFT key must be held down.
+    pub fn shift(mut self) -> Self {
+        self.inner |= MOD_SHIFT;
+        self
+    }
+
+    ///Either WINDOWS key must be held down.
+    ///
+    ///Keyboard shortcuts that involve the WINDOWS key are reserved for use by the operating system.
+    pub fn win(mut self) -> Self {
+        self.inner |= MOD_WIN;
+        self
+    }
+
+    ///Changes the hotkey behavior so that the keyboard auto-repeat does not yield multiple hotkey notifications.
+    pub fn no_repeat(mut self) -> Self {
+        self.inner |= MOD_NOREPEAT;
+        self
+    }
+
+    #[inline]
+    ///Returns whether ALT is required.
+    pub fn is_alt(&self) -> bool {
+        self.inner & MOD_ALT != 0
+    }
+
+    #[inline]
+    ///Returns whether CTRL is required.
+    pub fn is_ctrl(&self) -> bool {
+        self.inner & MOD_CONTROL != 0
+    }
+
+    #[inline]
+    ///Returns whether SHIFT is required.
+    pub fn is_shift(&self) -> bool {
+        self.inner & MOD_SHIFT != 0
+    }
+
+    #[inline]
+    ///Returns whether WINDOWS is required.
+    pub fn is_win(&self) -> bool {
+        self.inner & MOD_WIN != 0
+    }
+
+    #[inline]
+    ///Returns whether auto-repeat is suppressed.
+    pub fn is_no_repeat(&self) -> bool {
+        self.inner & MOD_NOREPEAT != 0
+    }
+}
+
+impl Default for Modifiers {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///Error parsing [Shortcut](struct.Shortcut.html)
+pub enum ParseError {
+    ///String has no keys or contains empty key.
+    Empty,
+    ///Unknown key name.
+    UnknownKey(String),
+    ///Only modifiers are specified.
+    MissingKey,
+    ///More than one non-modifier key is specified.
+    MultipleKeys,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("Empty key"),
+            ParseError::UnknownKey(key) => write!(f, "Unknown key '{}'", key),
+            ParseError::MissingKey => f.write_str("Missing non-modifier key"),
+            ParseError::MultipleKeys => f.write_str("Multiple non-modifier keys"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Key combination of hotkey.
+///
+///Can be parsed from string like `Ctrl+Shift+F12`, where modifiers are `Ctrl`, `Shift`, `Alt` and `Win`,
+///and key is parsed by [parse_key()](fn.parse_key.html).
+pub struct Shortcut {
+    ///Modifiers
+    pub modifiers: Modifiers,
+    ///Virtual key code
+    pub vk: UINT,
+}
+
+impl Shortcut {
+    #[inline]
+    ///Creates new instance
+    pub const fn new(modifiers: Modifiers, vk: UINT) -> Self {
+        Self {
+            modifiers,
+            vk,
+        }
+    }
+
+    ///Sets no repeat flag.
+    pub fn no_repeat(mut self) -> Self {
+        self.modifiers = self.modifiers.no_repeat();
+        self
+    }
+}
+
+impl str::FromStr for Shortcut {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> core::result::Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::new();
+        let mut vk = None;
+
+        for key in text.split('+').map(str::trim) {
+            if key.is_empty() {
+                return Err(ParseError::Empty);
+            }
+
+            modifiers = match key.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.ctrl(),
+                "shift" => modifiers.shift(),
+                "alt" => modifiers.alt(),
+                "win" | "windows" | "super" => modifiers.win(),
+                _ => match parse_key(key) {
+                    Some(_) if vk.is_some() => return Err(ParseError::MultipleKeys),
+                    Some(key) => {
+                        vk = Some(key);
+                        modifiers
+                    },
+                    None => return Err(ParseError::UnknownKey(key.to_owned())),
+                }
+            };
+        }
+
+        match vk {
+            Some(vk) => Ok(Self::new(modifiers, vk)),
+            None => Err(ParseError::MissingKey)
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.is_ctrl() {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.is_shift() {
+            f.write_str("Shift+")?;
+        }
+        if self.modifiers.is_alt() {
+            f.write_str("Alt+")?;
+        }
+        if self.modifiers.is_win() {
+            f.write_str("Win+")?;
+        }
+
+        match key_name(self.vk) {
+            Some(name) => f.write_str(&name),
+            None => write!(f, "{:#04X}", self.vk),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Notification of pressed hotkey.
+pub struct HotkeyEvent {
+    ///Identifier of hotkey.
+    ///
+    ///Negative values are reserved for system hotkeys, like `IDHOT_SNAPDESKTOP`.
+    pub id: i32,
+    ///Pressed key combination.
+    ///
+    ///Note that no repeat flag is never reported.
+    pub shortcut: Shortcut,
+}
+
+impl HotkeyEvent {
+    ///Extracts event from `WM_HOTKEY` message.
+    ///
+    ///Returns `None` for any other message.
+    pub fn from_msg(msg: &MSG) -> Option<Self> {
+        match msg.message {
+            WM_HOTKEY => Some(Self {
+                id: msg.wParam as i32,
+                shortcut: Shortcut::new(Modifiers::from_raw(msg.lParam as UINT & 0xFFFF), (msg.lParam as UINT >> 16) & 0xFFFF),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(windows)]
+fn next_id() -> c_int {
+    //Applications must use identifiers in range 0x0000 through 0xBFFF
+    static NEXT_ID: atomic::AtomicUsize = atomic::AtomicUsize::new(0);
+
+    (NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed) % 0xBFFF) as c_int + 1
+}
+
+#[cfg(windows)]
+///Registered hotkey.
+///
+///It is unregistered on drop.
+///
+///# Note:
+///
+///`WM_HOTKEY` is posted to the queue of the thread that registered hotkey
+///or to the window's queue if window is specified.
+///Therefore hotkey must be dropped on the thread that registered it.
+///Use [spawn()](#method.spawn) to handle hotkey on dedicated thread.
+pub struct Hotkey {
+    id: c_int,
+    window: HWND,
+    shortcut: Shortcut,
+}
+
+#[cfg(windows)]
+impl Hotkey {
+    ///Registers system-wide hotkey.
+    ///
+    ///# Parameters:
+    ///
+    ///* ```shortcut``` - Key combination.
+    ///* ```window``` - Window to receive `WM_HOTKEY`. If `None`, it is posted to the current thread.
+    ///
+    ///# Return:
+    ///
+    ///* ```Ok``` - Registered hotkey.
+    ///* ```Err``` - Error reason. E.g. hotkey is already registered by another application.
+    pub fn register(shortcut: Shortcut, window: Option<HWND>) -> Result<Self> {
+        let id = next_id();
+        let window = window.unwrap_or(ptr::null_mut());
+
+        match unsafe { RegisterHotKey(window, id, shortcut.modifiers.raw(), shortcut.vk) } {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(Self {
+                id,
+                window,
+                shortcut
+            })
+        }
+    }
+
+    ///Spawns message loop thread with registered hotkey, which invokes callback on each press.
+    ///
+    ///Hotkey is unregistered once returned thread is stopped.
+    pub fn spawn<F: FnMut(HotkeyEvent) + Send + 'static>(shortcut: Shortcut, mut cb: F) -> Result<MessageThread> {
+        MessageThread::spawn(move || {
+            let hotkey = Self::register(shortcut, None)?;
+
+            Ok(move |msg: &MSG| {
+                if let Some(event) = HotkeyEvent::from_msg(msg) {
+                    if hotkey.is_event(&event) {
+                        cb(event);
+                    }
+                }
+            })
+        })
+    }
+
+    #[inline]
+    ///Returns hotkey identifier, reported as `wParam` of `WM_HOTKEY`.
+    pub fn id(&self) -> c_int {
+        self.id
+    }
+
+    #[inline]
+    ///Returns registered key combination.
+    pub fn shortcut(&self) -> Shortcut {
+        self.shortcut
+    }
+
+    #[inline]
+    ///Returns whether event belongs to this hotkey.
+    pub fn is_event(&self, event: &HotkeyEvent) -> bool {
+        event.id == self.id
+    }
+}
+
+#[cfg(windows)]
+impl Drop for Hotkey {
+    fn drop(&mut self) {
+        unsafe {
+            UnregisterHotKey(self.window, self.id);
+        }
+    }
+}
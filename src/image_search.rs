@@ -0,0 +1,322 @@
+//! Search of template image on captures of screen and windows.
+//!
+//! Useful to locate controls that have no window of their own.
+//!
+//! Matching is platform independent and operates on [Image](../capture/struct.Image.html).
+
+use crate::capture::Image;
+
+#[cfg(windows)]
+use crate::sys::{HWND, POINT, WindowFromPoint};
+#[cfg(windows)]
+use crate::utils::Result;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Area of image to search in.
+pub struct Region {
+    ///Left coordinate.
+    pub x: u32,
+    ///Top coordinate.
+    pub y: u32,
+    ///Width.
+    pub width: u32,
+    ///Height.
+    pub height: u32,
+}
+
+impl Region {
+    #[inline]
+    ///Creates new region.
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+///Location of template.
+pub struct Match {
+    ///Left coordinate.
+    pub x: i32,
+    ///Top coordinate.
+    pub y: i32,
+    ///Width of template.
+    pub width: u32,
+    ///Height of template.
+    pub height: u32,
+    ///Sum of squared differences normalized to `[0, 1]`, where `0` is exact match.
+    pub score: f64,
+}
+
+impl Match {
+    #[inline]
+    ///Returns center of match.
+    pub fn center(&self) -> (i32, i32) {
+        (self.x + (self.width / 2) as i32, self.y + (self.height / 2) as i32)
+    }
+
+    #[inline]
+    ///Returns match moved by specified offset, e.g. to translate image coordinates into screen ones.
+    pub fn offset(&self, dx: i32, dy: i32) -> Self {
+        Self {
+            x: self.x + dx,
+            y: self.y + dy,
+            ..*self
+        }
+    }
+
+    fn overlaps(&self, other: &Match) -> bool {
+        self.x < other.x + other.width as i32 && other.x < self.x + self.width as i32 &&
+        self.y < other.y + other.height as i32 && other.y < self.y + self.height as i32
+    }
+}
+
+#[cfg(windows)]
+impl Match {
+    ///Retrieves window at center of match.
+    ///
+    ///Match must be in screen coordinates, as returned by
+    ///[find_in_window](struct.Search.html#method.find_in_window) and [find_on_screen](struct.Search.html#method.find_on_screen).
+    ///
+    ///Returned handle is not owned, as window usually belongs to other process.
+    pub fn window(&self) -> Option<HWND> {
+        let (x, y) = self.center();
+        match unsafe { WindowFromPoint(POINT { x, y }) } {
+            window if window.is_null() => None,
+            window => Some(window),
+        }
+    }
+}
+
+const MAX_CHANNEL_DIFF: u64 = 255 * 255;
+
+///Search of template image.
+///
+///By default only exact matches are accepted, comparing blue, green and red channels.
+///Alpha is ignored as captures have no transparency.
+///
+///To search by similarity, allow any difference with `tolerance(255)` and limit `max_score`.
+pub struct Search<'a> {
+    template: &'a Image,
+    tolerance: u8,
+    max_score: Option<f64>,
+    region: Option<Region>,
+    limit: usize,
+}
+
+impl<'a> Search<'a> {
+    ///Creates new search of template.
+    pub fn new(template: &'a Image) -> Self {
+        Self {
+            template,
+            tolerance: 0,
+            max_score: None,
+            region: None,
+            limit: usize::max_value(),
+        }
+    }
+
+    ///Sets maximum difference of each channel of pixel.
+    ///
+    ///Defaults to `0`, i.e. exact match.
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    ///Sets maximum score of match. Refer to [Match::score](struct.Match.html#structfield.score).
+    ///
+    ///Not limited by default.
+    pub fn max_score(mut self, max_score: f64) -> Self {
+        self.max_score = Some(max_score);
+        self
+    }
+
+    ///Restricts search to area of image.
+    ///
+    ///Template must lie entirely within area. Area is clipped by image's bounds.
+    pub fn region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    ///Sets maximum number of matches returned by [find_all](#method.find_all).
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    fn max_ssd(&self) -> u64 {
+        let total = self.template.width() as u64 * self.template.height() as u64 * 3 * MAX_CHANNEL_DIFF;
+
+        match self.max_score {
+            Some(score) if score <= 0.0 => 0,
+            Some(score) if score < 1.0 => (total as f64 * score) as u64,
+            _ => total,
+        }
+    }
+
+    fn normalize(&self, ssd: u64) -> f64 {
+        let total = self.template.width() as u64 * self.template.height() as u64 * 3 * MAX_CHANNEL_DIFF;
+        ssd as f64 / total as f64
+    }
+
+    //Returns sum of squared differences, unless it exceeds limit or any channel exceeds tolerance.
+    fn ssd_at(&self, image: &Image, x: u32, y: u32, limit: u64) -> Option<u64> {
+        let tolerance = self.tolerance as i32;
+        let start = x as usize * 4;
+        let len = self.template.width() as usize * 4;
+        let mut ssd = 0u64;
+
+        for row in 0..self.template.height() {
+            let expected = self.template.row(row);
+            let actual = &image.row(y + row)[start..start + len];
+
+            for (expected, actual) in expected.chunks_exact(4).zip(actual.chunks_exact(4)) {
+                for channel in 0..3 {
+                    let diff = (expected[channel] as i32 - actual[channel] as i32).abs();
+                    if diff > tolerance {
+                        return None;
+                    }
+                    ssd += (diff * diff) as u64;
+                }
+            }
+
+            if ssd > limit {
+                return None;
+            }
+        }
+
+        Some(ssd)
+    }
+
+    //Returns range of positions of template's top-left corner as `(x, y, width, height)`.
+    fn positions(&self, image: &Image) -> Option<(u32, u32, u32, u32)> {
+        let region = self.region.unwrap_or_else(|| Region::new(0, 0, image.width(), image.height()));
+
+        if region.x >= image.width() || region.y >= image.height() {
+            return None;
+        }
+
+        let width = region.width.min(image.width() - region.x);
+        let height = region.height.min(image.height() - region.y);
+
+        if self.template.width() == 0 || self.template.height() == 0 || self.template.width() > width || self.template.height() > height {
+            return None;
+        }
+
+        Some((region.x, region.y, width - self.template.width() + 1, height - self.template.height() + 1))
+    }
+
+    fn to_match(&self, x: u32, y: u32, ssd: u64) -> Match {
+        Match {
+            x: x as i32,
+            y: y as i32,
+            width: self.template.width(),
+            height: self.template.height(),
+            score: self.normalize(ssd),
+        }
+    }
+
+    ///Computes score of template at position.
+    ///
+    ///# Return
+    ///
+    ///* ```Some``` - Score, if template fits into image and matches.
+    ///* ```None``` - Template doesn't match according to tolerance and maximum score.
+    pub fn score_at(&self, image: &Image, x: u32, y: u32) -> Option<f64> {
+        if x.checked_add(self.template.width())? > image.width() || y.checked_add(self.template.height())? > image.height() {
+            return None;
+        }
+
+        self.ssd_at(image, x, y, self.max_ssd()).map(|ssd| self.normalize(ssd))
+    }
+
+    ///Looks up best match in image coordinates.
+    ///
+    ///Of equally good matches, top-most then left-most one is returned.
+    pub fn find(&self, image: &Image) -> Option<Match> {
+        let (left, top, width, height) = self.positions(image)?;
+        let mut limit = self.max_ssd();
+        let mut best = None;
+
+        for y in top..top + height {
+            for x in left..left + width {
+                if let Some(ssd) = self.ssd_at(image, x, y, limit) {
+                    if ssd == 0 {
+                        return Some(self.to_match(x, y, ssd));
+                    }
+
+                    //Further matches must be strictly better.
+                    limit = ssd - 1;
+                    best = Some((x, y, ssd));
+                }
+            }
+        }
+
+        best.map(|(x, y, ssd)| self.to_match(x, y, ssd))
+    }
+
+    ///Looks up all matches in image coordinates, best first.
+    ///
+    ///Matches that overlap better one are discarded, so that each occurrence is reported once.
+    pub fn find_all(&self, image: &Image) -> Vec<Match> {
+        let (left, top, width, height) = match self.positions(image) {
+            Some(positions) => positions,
+            None => return Vec::new(),
+        };
+        let limit = self.max_ssd();
+
+        let mut candidates = Vec::new();
+        for y in top..top + height {
+            for x in left..left + width {
+                if let Some(ssd) = self.ssd_at(image, x, y, limit) {
+                    candidates.push(self.to_match(x, y, ssd));
+                }
+            }
+        }
+
+        //Sort is stable, so position order is kept for equal scores.
+        candidates.sort_by(|left, right| left.score.partial_cmp(&right.score).unwrap_or(core::cmp::Ordering::Equal));
+
+        let mut result: Vec<Match> = Vec::new();
+        for candidate in candidates {
+            if result.len() >= self.limit {
+                break;
+            }
+
+            if !result.iter().any(|accepted| accepted.overlaps(&candidate)) {
+                result.push(candidate);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(windows)]
+impl<'a> Search<'a> {
+    ///Looks up best match in window, returning it in screen coordinates.
+    ///
+    ///Region, if any, is relative to window's upper-left corner.
+    pub fn find_in_window(&self, window: HWND) -> Result<Option<Match>> {
+        let rect = crate::raw::window::get_rect(window)?;
+        let image = crate::capture::window(window)?;
+
+        Ok(self.find(&image).map(|found| found.offset(rect.left, rect.top)))
+    }
+
+    ///Looks up best match on all monitors, returning it in screen coordinates.
+    ///
+    ///Region, if any, is relative to upper-left corner of virtual screen.
+    pub fn find_on_screen(&self) -> Result<Option<Match>> {
+        let screen = crate::input::VirtualScreen::current();
+        let image = crate::capture::virtual_screen()?;
+
+        Ok(self.find(&image).map(|found| found.offset(screen.x, screen.y)))
+    }
+}
@@ -0,0 +1,472 @@
+//! Keyboard and mouse input synthesis.
+//!
+//! [InputBuilder](struct.InputBuilder.html) generates sequence of input events,
+//! which is submitted by [send()](fn.send.html) via `SendInput`.
+//!
+//! Generation of events is platform independent.
+
+use crate::sys::{
+    UINT,
+    DWORD,
+    KEYEVENTF_EXTENDEDKEY,
+    KEYEVENTF_KEYUP,
+    KEYEVENTF_UNICODE,
+    MOUSEEVENTF_MOVE,
+    MOUSEEVENTF_LEFTDOWN,
+    MOUSEEVENTF_LEFTUP,
+    MOUSEEVENTF_RIGHTDOWN,
+    MOUSEEVENTF_RIGHTUP,
+    MOUSEEVENTF_MIDDLEDOWN,
+    MOUSEEVENTF_MIDDLEUP,
+    MOUSEEVENTF_XDOWN,
+    MOUSEEVENTF_XUP,
+    MOUSEEVENTF_WHEEL,
+    MOUSEEVENTF_HWHEEL,
+    MOUSEEVENTF_VIRTUALDESK,
+    MOUSEEVENTF_ABSOLUTE,
+    XBUTTON1,
+    XBUTTON2,
+    VK_SHIFT,
+    VK_CONTROL,
+    VK_MENU,
+    VK_LWIN,
+    VK_RWIN,
+    VK_APPS,
+    VK_PRIOR,
+    VK_NEXT,
+    VK_END,
+    VK_HOME,
+    VK_LEFT,
+    VK_UP,
+    VK_RIGHT,
+    VK_DOWN,
+    VK_SNAPSHOT,
+    VK_INSERT,
+    VK_DELETE,
+    VK_DIVIDE,
+    VK_NUMLOCK,
+    VK_RCONTROL,
+    VK_RMENU,
+};
+use crate::hotkey::Shortcut;
+
+#[cfg(windows)]
+use crate::sys::{
+    c_int,
+    LONG,
+    INPUT,
+    INPUT_u,
+    KEYBDINPUT,
+    MOUSEINPUT,
+    INPUT_KEYBOARD,
+    INPUT_MOUSE,
+    SM_XVIRTUALSCREEN,
+    SM_YVIRTUALSCREEN,
+    SM_CXVIRTUALSCREEN,
+    SM_CYVIRTUALSCREEN,
+    SendInput,
+    GetSystemMetrics,
+};
+#[cfg(windows)]
+use crate::utils::{self, Result};
+#[cfg(windows)]
+use core::mem;
+
+///Maximum value of normalized absolute coordinate.
+pub const MAX_ABSOLUTE: i32 = 65535;
+
+///Returns whether virtual key requires `KEYEVENTF_EXTENDEDKEY` flag.
+pub fn is_extended_key(vk: UINT) -> bool {
+    match vk {
+        VK_PRIOR | VK_NEXT | VK_END | VK_HOME | VK_LEFT | VK_UP | VK_RIGHT | VK_DOWN => true,
+        VK_SNAPSHOT | VK_INSERT | VK_DELETE | VK_DIVIDE | VK_NUMLOCK => true,
+        VK_LWIN | VK_RWIN | VK_APPS | VK_RCONTROL | VK_RMENU => true,
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Keyboard event, corresponding to `KEYBDINPUT`.
+pub struct KeyboardInput {
+    ///Virtual key code. Zero for unicode events.
+    pub vk: u16,
+    ///Hardware scan code or UTF-16 code unit for unicode events.
+    pub scan: u16,
+    ///`KEYEVENTF_*` flags.
+    pub flags: DWORD,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Mouse event, corresponding to `MOUSEINPUT`.
+pub struct MouseInput {
+    ///Horizontal position or movement.
+    pub dx: i32,
+    ///Vertical position or movement.
+    pub dy: i32,
+    ///Wheel movement or X button.
+    pub data: i32,
+    ///`MOUSEEVENTF_*` flags.
+    pub flags: DWORD,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Input event.
+pub enum Input {
+    ///Keyboard event.
+    Keyboard(KeyboardInput),
+    ///Mouse event.
+    Mouse(MouseInput),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Mouse button.
+pub enum MouseButton {
+    ///Left button.
+    Left,
+    ///Right button.
+    Right,
+    ///Middle button.
+    Middle,
+    ///First X button.
+    X1,
+    ///Second X button.
+    X2,
+}
+
+impl MouseButton {
+    fn input(self, is_down: bool) -> MouseInput {
+        let (flags, data) = match (self, is_down) {
+            (MouseButton::Left, true) => (MOUSEEVENTF_LEFTDOWN, 0),
+            (MouseButton::Left, false) => (MOUSEEVENTF_LEFTUP, 0),
+            (MouseButton::Right, true) => (MOUSEEVENTF_RIGHTDOWN, 0),
+            (MouseButton::Right, false) => (MOUSEEVENTF_RIGHTUP, 0),
+            (MouseButton::Middle, true) => (MOUSEEVENTF_MIDDLEDOWN, 0),
+            (MouseButton::Middle, false) => (MOUSEEVENTF_MIDDLEUP, 0),
+            (MouseButton::X1, true) => (MOUSEEVENTF_XDOWN, XBUTTON1),
+            (MouseButton::X1, false) => (MOUSEEVENTF_XUP, XBUTTON1),
+            (MouseButton::X2, true) => (MOUSEEVENTF_XDOWN, XBUTTON2),
+            (MouseButton::X2, false) => (MOUSEEVENTF_XUP, XBUTTON2),
+        };
+
+        MouseInput {
+            dx: 0,
+            dy: 0,
+            data: data as i32,
+            flags,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Bounding rectangle of all monitors, in pixels.
+pub struct VirtualScreen {
+    ///Left-most coordinate. Can be negative.
+    pub x: i32,
+    ///Top-most coordinate. Can be negative.
+    pub y: i32,
+    ///Width.
+    pub width: i32,
+    ///Height.
+    pub height: i32,
+}
+
+fn normalize_axis(pos: i32, start: i32, len: i32) -> i32 {
+    if len <= 1 {
+        return 0;
+    }
+
+    let max = (len - 1) as i64;
+    let pos = (pos as i64 - start as i64).max(0).min(max);
+    //Round to nearest
+    ((pos * MAX_ABSOLUTE as i64 + max / 2) / max) as i32
+}
+
+impl VirtualScreen {
+    #[cfg(windows)]
+    ///Retrieves current virtual screen.
+    pub fn current() -> Self {
+        unsafe {
+            Self {
+                x: GetSystemMetrics(SM_XVIRTUALSCREEN),
+                y: GetSystemMetrics(SM_YVIRTUALSCREEN),
+                width: GetSystemMetrics(SM_CXVIRTUALSCREEN),
+                height: GetSystemMetrics(SM_CYVIRTUALSCREEN),
+            }
+        }
+    }
+
+    ///Converts pixel coordinates into normalized absolute coordinates `0..=65535`.
+    ///
+    ///Coordinates outside of screen are clamped to its edges.
+    pub fn normalize(&self, x: i32, y: i32) -> (i32, i32) {
+        (normalize_axis(x, self.x, self.width), normalize_axis(y, self.y, self.height))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+///Builder of input events sequence.
+pub struct InputBuilder {
+    inputs: Vec<Input>,
+}
+
+impl InputBuilder {
+    ///Creates new empty sequence.
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::new()
+        }
+    }
+
+    #[inline]
+    ///Adds raw event.
+    pub fn push(&mut self, input: Input) -> &mut Self {
+        self.inputs.push(input);
+        self
+    }
+
+    fn key(&mut self, vk: UINT, is_down: bool) -> &mut Self {
+        let mut flags = match is_down {
+            true => 0,
+            false => KEYEVENTF_KEYUP,
+        };
+
+        if is_extended_key(vk) {
+            flags |= KEYEVENTF_EXTENDEDKEY;
+        }
+
+        self.push(Input::Keyboard(KeyboardInput {
+            vk: vk as u16,
+            scan: 0,
+            flags,
+        }))
+    }
+
+    #[inline]
+    ///Presses key by virtual key code.
+    pub fn key_down(&mut self, vk: UINT) -> &mut Self {
+        self.key(vk, true)
+    }
+
+    #[inline]
+    ///Releases key by virtual key code.
+    pub fn key_up(&mut self, vk: UINT) -> &mut Self {
+        self.key(vk, false)
+    }
+
+    #[inline]
+    ///Presses and releases key by virtual key code.
+    pub fn key_press(&mut self, vk: UINT) -> &mut Self {
+        self.key_down(vk).key_up(vk)
+    }
+
+    fn unicode(&mut self, unit: u16, is_down: bool) -> &mut Self {
+        let flags = match is_down {
+            true => KEYEVENTF_UNICODE,
+            false => KEYEVENTF_UNICODE | KEYEVENTF_KEYUP,
+        };
+
+        self.push(Input::Keyboard(KeyboardInput {
+            vk: 0,
+            scan: unit,
+            flags,
+        }))
+    }
+
+    #[inline]
+    ///Presses key by UTF-16 code unit.
+    pub fn unicode_down(&mut self, unit: u16) -> &mut Self {
+        self.unicode(unit, true)
+    }
+
+    #[inline]
+    ///Releases key by UTF-16 code unit.
+    pub fn unicode_up(&mut self, unit: u16) -> &mut Self {
+        self.unicode(unit, false)
+    }
+
+    ///Types text as sequence of unicode key presses.
+    ///
+    ///Each UTF-16 code unit is pressed and released separately,
+    ///surrogate pairs are sent as two consecutive presses.
+    pub fn text(&mut self, text: &str) -> &mut Self {
+        for unit in text.encode_utf16() {
+            self.unicode_down(unit).unicode_up(unit);
+        }
+        self
+    }
+
+    ///Presses key combination, releasing keys in reverse order.
+    pub fn shortcut(&mut self, shortcut: &Shortcut) -> &mut Self {
+        let mut modifiers = Vec::with_capacity(4);
+        if shortcut.modifiers.is_ctrl() {
+            modifiers.push(VK_CONTROL);
+        }
+        if shortcut.modifiers.is_shift() {
+            modifiers.push(VK_SHIFT);
+        }
+        if shortcut.modifiers.is_alt() {
+            modifiers.push(VK_MENU);
+        }
+        if shortcut.modifiers.is_win() {
+            modifiers.push(VK_LWIN);
+        }
+
+        for vk in modifiers.iter() {
+            self.key_down(*vk);
+        }
+        self.key_press(shortcut.vk);
+        for vk in modifiers.iter().rev() {
+            self.key_up(*vk);
+        }
+
+        self
+    }
+
+    ///Moves cursor to pixel coordinates on the virtual screen.
+    pub fn mouse_move_to(&mut self, x: i32, y: i32, screen: &VirtualScreen) -> &mut Self {
+        let (dx, dy) = screen.normalize(x, y);
+
+        self.push(Input::Mouse(MouseInput {
+            dx,
+            dy,
+            data: 0,
+            flags: MOUSEEVENTF_MOVE | MOUSEEVENTF_ABSOLUTE | MOUSEEVENTF_VIRTUALDESK,
+        }))
+    }
+
+    ///Moves cursor relative to its current position.
+    ///
+    ///Note that relative movement is affected by mouse speed and acceleration settings.
+    pub fn mouse_move_by(&mut self, dx: i32, dy: i32) -> &mut Self {
+        self.push(Input::Mouse(MouseInput {
+            dx,
+            dy,
+            data: 0,
+            flags: MOUSEEVENTF_MOVE,
+        }))
+    }
+
+    #[inline]
+    ///Presses mouse button.
+    pub fn button_down(&mut self, button: MouseButton) -> &mut Self {
+        self.push(Input::Mouse(button.input(true)))
+    }
+
+    #[inline]
+    ///Releases mouse button.
+    pub fn button_up(&mut self, button: MouseButton) -> &mut Self {
+        self.push(Input::Mouse(button.input(false)))
+    }
+
+    #[inline]
+    ///Clicks mouse button.
+    pub fn click(&mut self, button: MouseButton) -> &mut Self {
+        self.button_down(button).button_up(button)
+    }
+
+    #[inline]
+    ///Double clicks mouse button.
+    pub fn double_click(&mut self, button: MouseButton) -> &mut Self {
+        self.click(button).click(button)
+    }
+
+    ///Rotates vertical wheel.
+    ///
+    ///Positive delta is forward, away from user. One wheel click is `WHEEL_DELTA`.
+    pub fn wheel(&mut self, delta: i32) -> &mut Self {
+        self.push(Input::Mouse(MouseInput {
+            dx: 0,
+            dy: 0,
+            data: delta,
+            flags: MOUSEEVENTF_WHEEL,
+        }))
+    }
+
+    ///Rotates horizontal wheel.
+    ///
+    ///Positive delta is to the right. One wheel click is `WHEEL_DELTA`.
+    pub fn hwheel(&mut self, delta: i32) -> &mut Self {
+        self.push(Input::Mouse(MouseInput {
+            dx: 0,
+            dy: 0,
+            data: delta,
+            flags: MOUSEEVENTF_HWHEEL,
+        }))
+    }
+
+    #[inline]
+    ///Returns generated events.
+    pub fn inputs(&self) -> &[Input] {
+        &self.inputs
+    }
+
+    #[inline]
+    ///Returns generated events, consuming self.
+    pub fn into_inputs(self) -> Vec<Input> {
+        self.inputs
+    }
+
+    #[cfg(windows)]
+    #[inline]
+    ///Submits generated events.
+    ///
+    ///Refer to [send()](fn.send.html)
+    pub fn send(&self) -> Result<()> {
+        send(&self.inputs)
+    }
+}
+
+#[cfg(windows)]
+impl From<Input> for INPUT {
+    fn from(input: Input) -> INPUT {
+        match input {
+            Input::Keyboard(input) => INPUT {
+                type_: INPUT_KEYBOARD,
+                u: INPUT_u {
+                    ki: KEYBDINPUT {
+                        wVk: input.vk,
+                        wScan: input.scan,
+                        dwFlags: input.flags,
+                        time: 0,
+                        dwExtraInfo: 0,
+                    }
+                }
+            },
+            Input::Mouse(input) => INPUT {
+                type_: INPUT_MOUSE,
+                u: INPUT_u {
+                    mi: MOUSEINPUT {
+                        dx: input.dx as LONG,
+                        dy: input.dy as LONG,
+                        mouseData: input.data as DWORD,
+                        dwFlags: input.flags,
+                        time: 0,
+                        dwExtraInfo: 0,
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[cfg(windows)]
+///Submits events via `SendInput`.
+///
+///Events are inserted serially and are not interspersed with other input events.
+///
+///# Note:
+///
+///Input can be blocked by UIPI, when target application runs at higher integrity level.
+///
+///# Return
+///
+///* ```Ok``` - All events are inserted.
+///* ```Err``` - Error reason, if not all events are inserted.
+pub fn send(inputs: &[Input]) -> Result<()> {
+    let mut raw: Vec<INPUT> = inputs.iter().map(|input| INPUT::from(*input)).collect();
+
+    match unsafe { SendInput(raw.len() as UINT, raw.as_mut_ptr(), mem::size_of::<INPUT>() as c_int) } {
+        inserted if inserted as usize == raw.len() => Ok(()),
+        _ => Err(utils::get_last_error()),
+    }
+}
@@ -1,26 +1,50 @@
-#![cfg(windows)]
 //! Windows WinAPI
 //!
 //! Some windows hacking library with utilities to find windows and access them.
 //!
+//! On other platforms only WinAPI definitions and platform independent logic are available.
+//!
 
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::not_unsafe_ptr_arg_deref))]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::derivable_impls))]
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+#![allow(clippy::style)]
+#![allow(clippy::derivable_impls)]
 
 pub mod sys;
 
+#[cfg(windows)]
 use std::ffi;
+#[cfg(windows)]
 use core::{ptr, mem, convert};
 
+#[cfg(windows)]
 #[path="raw/mod.rs"]
 mod inner_raw;
 pub mod utils;
 pub mod ui;
+#[cfg(windows)]
+pub mod message_loop;
+#[cfg(windows)]
+pub mod future;
+#[cfg(windows)]
+pub mod clipboard;
+pub mod hotkey;
+pub mod input;
+pub mod hook;
+pub mod win_event;
+pub mod process_tree;
+pub mod window_tree;
+pub mod backend;
+pub mod controls;
+pub mod menu;
+pub mod capture;
+pub mod image_search;
+pub mod monitor;
+pub mod console;
 
 pub use utils::{ErrorCode, Result};
 
+#[cfg(windows)]
 pub mod raw {
     //! Provides direct bindings to WinAPI functions of crate.
     pub use super::inner_raw::process;
@@ -32,6 +56,7 @@ pub mod raw {
     pub use super::inner_raw::timer;
 }
 
+#[cfg(windows)]
 use sys::{
     HANDLE,
     HWND,
@@ -49,11 +74,13 @@ use sys::{
 };
 
 ///Windows process representation
+#[cfg(windows)]
 pub struct Process {
     pid: u32,
     inner: HANDLE,
 }
 
+#[cfg(windows)]
 impl Process {
     ///Creates handle to a new process by opening it through pid.
     ///
@@ -129,6 +156,36 @@ impl Process {
         raw::window::get_by_pid(self.pid)
     }
 
+    #[inline]
+    ///Retrieves handles of all process's top-level windows.
+    pub fn windows(&self) -> Result<Vec<HWND>> {
+        raw::window::get_all_by_pid(self.pid)
+    }
+
+    #[inline]
+    ///Creates query of process's windows, which allows to include child windows and windows of descendant processes.
+    ///
+    ///For more information refer to [ProcessWindows](raw/window/struct.ProcessWindows.html)
+    pub fn windows_query(&self) -> raw::window::ProcessWindows {
+        raw::window::ProcessWindows::new(self.pid)
+    }
+
+    #[inline]
+    ///Waits for process's visible window.
+    ///
+    ///For more information refer to [wait_by_pid()](raw/window/fn.wait_by_pid.html)
+    pub fn wait_for_window(&self, timeout: Option<c_ulong>) -> Result<Option<HWND>> {
+        raw::window::wait_by_pid(self.pid, timeout)
+    }
+
+    #[inline]
+    ///Waits until process finishes its initialization and waits for user input.
+    ///
+    ///For more information refer to [wait_for_input_idle()](raw/process/fn.wait_for_input_idle.html)
+    pub fn wait_for_input_idle(&self, timeout: Option<c_ulong>) -> Result<bool> {
+        raw::process::wait_for_input_idle(self.inner, timeout)
+    }
+
     #[inline]
     ///Reads memory from process.
     ///
@@ -180,6 +237,7 @@ impl Process {
     }
 }
 
+#[cfg(windows)]
 impl Drop for Process {
     fn drop(&mut self) {
         self.close()
@@ -190,10 +248,12 @@ impl Drop for Process {
 ///
 ///On drop it translates and dispatches message.
 ///You can do it yourself though.
+#[cfg(windows)]
 pub struct Msg {
     inner: MSG
 }
 
+#[cfg(windows)]
 impl Msg {
     ///Creates new instance by taking raw `MSG`
     pub fn new(message: MSG) -> Msg {
@@ -246,6 +306,7 @@ impl Msg {
     }
 }
 
+#[cfg(windows)]
 impl Drop for Msg {
     fn drop(&mut self) {
         raw::message::translate(self.as_mut_ptr());
@@ -261,12 +322,14 @@ impl Drop for Msg {
 ///
 ///* window - For which window to received messages.
 ///* range - Range of message identifiers to receive.
+#[cfg(windows)]
 pub struct Messages {
     window: Option<HWND>,
     range: (Option<UINT>, Option<UINT>),
     is_block: bool
 }
 
+#[cfg(windows)]
 impl Messages {
     ///Initializes new iterator with default no filtering.
     pub fn new() -> Messages {
@@ -312,6 +375,7 @@ impl Messages {
     }
 }
 
+#[cfg(windows)]
 impl Iterator for Messages {
     type Item = Result<Msg>;
 
@@ -339,10 +403,12 @@ impl Iterator for Messages {
 ///
 ///This struct destroys window on drop and it is bad idea to do it for not your own window.
 ///If lucky, it fails but still not great idea.
+#[cfg(windows)]
 pub struct Window {
     inner: HWND
 }
 
+#[cfg(windows)]
 impl Window {
     #[inline]
     ///Creates new instance by taking ownership over provided window.
@@ -356,6 +422,16 @@ impl Window {
         builder.create().map(|win| Window::from_hwnd(win))
     }
 
+    #[inline]
+    ///Waits for top-level window that satisfies predicate.
+    ///
+    ///Handle is returned as it is, without taking ownership.
+    ///
+    ///For more information refer to [wait_by()](raw/window/fn.wait_by.html)
+    pub fn wait_for<T: FnMut(HWND) -> bool>(predicate: T, timeout: Option<c_ulong>) -> Result<Option<HWND>> {
+        raw::window::wait_by(predicate, timeout)
+    }
+
     #[inline]
     ///Returns underlying window.
     ///
@@ -406,12 +482,66 @@ impl Window {
         raw::window::get_text(self.inner)
     }
 
+    #[inline]
+    ///Retrieves window's text with specified options.
+    ///
+    ///For more information refer to [get_text_with()](raw/window/fn.get_text_with.html)
+    pub fn text_with(&self, options: raw::window::TextOptions) -> core::result::Result<Option<String>, raw::window::SendError> {
+        raw::window::get_text_with(self.inner, &options)
+    }
+
+    #[inline]
+    ///Captures window's image.
+    ///
+    ///For more information refer to [capture::window()](capture/fn.window.html)
+    pub fn capture(&self) -> Result<capture::Image> {
+        capture::window(self.inner)
+    }
+
+    #[inline]
+    ///Retrieves monitor that displays window, or nearest one.
+    pub fn monitor(&self) -> Result<monitor::Monitor> {
+        monitor::Monitor::of_window(self.inner)
+    }
+
+    #[inline]
+    ///Retrieves window's DPI.
+    ///
+    ///For more information refer to [monitor::window_dpi()](monitor/fn.window_dpi.html)
+    pub fn dpi(&self) -> Result<UINT> {
+        monitor::window_dpi(self.inner)
+    }
+
+    #[inline]
+    ///Retrieves window's menu bar.
+    ///
+    ///For more information refer to [Menu](menu/struct.Menu.html)
+    pub fn menu(&self) -> Result<Option<menu::Menu>> {
+        menu::Menu::of_window(self.inner)
+    }
+
+    #[inline]
+    ///Retrieves window's system menu.
+    ///
+    ///For more information refer to [Menu](menu/struct.Menu.html)
+    pub fn system_menu(&self) -> Result<Option<menu::Menu>> {
+        menu::Menu::system(self.inner)
+    }
+
     #[inline]
     ///Retrieves tuple of thread and process ids.
     pub fn thread_pid(&self) -> (u32, u32) {
         raw::window::get_thread_process_id(self.inner)
     }
 
+    #[inline]
+    ///Builds tree of window and all its children.
+    ///
+    ///For more information refer to [WindowNode](window_tree/struct.WindowNode.html)
+    pub fn tree(&self) -> Result<window_tree::WindowNode> {
+        window_tree::WindowNode::from_hwnd(self.inner)
+    }
+
     #[inline]
     ///Sends message to underlying window.
     ///
@@ -420,6 +550,56 @@ impl Window {
         raw::window::send_message(self.inner, msg_type, w_param, l_param, timeout)
     }
 
+    #[inline]
+    ///Sends message to underlying window with specified timeout and flags.
+    ///
+    ///For more information refer to [send_message_timeout()](raw/window/fn.send_message_timeout.html)
+    pub fn send_message_timeout(&self, msg_type: UINT, w_param: WPARAM, l_param: LPARAM, timeout: UINT, flags: raw::window::SendMessageFlags) -> core::result::Result<LRESULT, raw::window::SendError> {
+        raw::window::send_message_timeout(self.inner, msg_type, w_param, l_param, timeout, flags)
+    }
+
+    #[inline]
+    ///Determines whether window is not responding.
+    pub fn is_hung(&self) -> bool {
+        raw::window::is_hung(self.inner)
+    }
+
+    #[inline]
+    ///Sets window's opacity, where `0` is fully transparent and `255` is opaque.
+    pub fn set_opacity(&self, alpha: u8) -> Result<()> {
+        raw::window::set_opacity(self.inner, alpha)
+    }
+
+    #[inline]
+    ///Makes pixels of specified color fully transparent.
+    pub fn set_color_key(&self, color_key: sys::COLORREF) -> Result<()> {
+        raw::window::set_color_key(self.inner, color_key)
+    }
+
+    #[inline]
+    ///Retrieves window's rectangle as rendered by DWM, excluding invisible borders.
+    pub fn extended_frame_bounds(&self) -> Result<sys::RECT> {
+        raw::window::get_extended_frame_bounds(self.inner)
+    }
+
+    #[inline]
+    ///Determines whether window is cloaked by DWM.
+    pub fn is_cloaked(&self) -> bool {
+        raw::window::is_cloaked(self.inner)
+    }
+
+    #[inline]
+    ///Enables or disables dark mode of window's title bar.
+    pub fn set_dark_mode(&self, is_enabled: bool) -> Result<()> {
+        raw::window::set_dark_mode(self.inner, is_enabled)
+    }
+
+    #[inline]
+    ///Sets preference of rounding window's corners.
+    pub fn set_corner_preference(&self, preference: raw::window::CornerPreference) -> Result<()> {
+        raw::window::set_corner_preference(self.inner, preference)
+    }
+
     #[inline]
     ///Sends `BM_CLICK` message to underlying window.
     ///
@@ -459,29 +639,35 @@ impl Window {
     }
 }
 
+#[cfg(windows)]
 impl convert::From<HWND> for Window {
     fn from(window: HWND) -> Window {
         Window { inner: window }
     }
 }
 
+#[cfg(windows)]
 impl convert::Into<HWND> for Window {
     fn into(self) -> HWND {
         self.into_inner()
     }
 }
 
+#[cfg(windows)]
 impl Drop for Window {
     fn drop(&mut self) {
         raw::window::destroy(self.inner);
     }
 }
 
+#[cfg(windows)]
 enum TimerCallbackType {
     None,
     Raw(raw::timer::CallbackType, *mut c_void),
+    Closure(Box<dyn FnMut() + Send>),
 }
 
+#[cfg(windows)]
 enum TimeoutType {
     None,
     Single(c_ulong),
@@ -489,6 +675,7 @@ enum TimeoutType {
     Both(c_ulong, c_ulong)
 }
 
+#[cfg(windows)]
 impl TimeoutType {
     fn into_raw(self) -> (c_ulong, c_ulong) {
         match self {
@@ -500,6 +687,7 @@ impl TimeoutType {
     }
 }
 
+#[cfg(windows)]
 unsafe extern "system" fn timer_rust_callback(param: *mut c_void, _: c_uchar) {
     if !param.is_null() {
         let cb: fn() -> () = mem::transmute(param);
@@ -521,6 +709,7 @@ unsafe extern "system" fn timer_rust_callback(param: *mut c_void, _: c_uchar) {
 ///to run in interval.
 ///
 ///By default timer starts as one-shot with timeout 0.
+#[cfg(windows)]
 pub struct TimerBuilder<'a> {
     queue: Option<&'a raw::timer::TimerQueue>,
     callback: TimerCallbackType,
@@ -528,6 +717,7 @@ pub struct TimerBuilder<'a> {
     flags: raw::timer::TimerFlags
 }
 
+#[cfg(windows)]
 impl<'a> TimerBuilder<'a> {
     ///Creates new instance
     pub fn new() -> Self {
@@ -551,6 +741,15 @@ impl<'a> TimerBuilder<'a> {
         self
     }
 
+    ///Sets Rust closure as callback.
+    ///
+    ///Closure is owned by created timer and freed once timer is deleted.
+    ///See [timer_with](raw/timer/struct.TimerQueue.html#method.timer_with) for details.
+    pub fn callback<F: FnMut() + Send + 'static>(mut self, cb: F) -> Self {
+        self.callback = TimerCallbackType::Closure(Box::new(cb));
+        self
+    }
+
     ///Sets timer queue.
     ///
     ///If not set, default shall be used.
@@ -594,6 +793,7 @@ impl<'a> TimerBuilder<'a> {
         let (cb, param) = match self.callback {
             TimerCallbackType::None => (None, ptr::null_mut()),
             TimerCallbackType::Raw(cb, param) => (cb, param),
+            TimerCallbackType::Closure(cb) => return queue.timer_with(cb, delay, period, self.flags),
         };
 
         queue.timer(cb, param, delay, period, self.flags)
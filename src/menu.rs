@@ -0,0 +1,307 @@
+//! Menus of windows, including windows of other processes.
+//!
+//! Menu is read into plain data, which can be searched by path
+//! and whose items can be invoked by posting command to the owning window.
+//!
+//! Look up is platform independent.
+
+use crate::sys::{
+    UINT,
+    WPARAM,
+    LPARAM,
+    WM_COMMAND,
+    WM_SYSCOMMAND,
+    MF_GRAYED,
+    MF_DISABLED,
+    MFS_CHECKED,
+    MFS_HILITE,
+    MFS_DEFAULT,
+};
+
+#[cfg(windows)]
+use crate::sys::{
+    HWND,
+    HMENU,
+    MENUITEMINFOW,
+    MIIM_STATE,
+    MIIM_ID,
+    MIIM_SUBMENU,
+    MIIM_STRING,
+    MIIM_FTYPE,
+    MFT_SEPARATOR,
+    GetMenu,
+    GetSystemMenu,
+    GetMenuItemCount,
+    GetMenuItemInfoW,
+};
+#[cfg(windows)]
+use crate::utils::{self, ErrorCode, Result};
+#[cfg(windows)]
+use core::{mem, ptr};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+///State of menu item.
+pub struct MenuState {
+    inner: UINT
+}
+
+impl MenuState {
+    #[inline]
+    ///Creates state from raw `MFS_*` flags.
+    pub fn from_raw(inner: UINT) -> Self {
+        Self {
+            inner
+        }
+    }
+
+    #[inline]
+    ///Returns raw flags.
+    pub fn raw(&self) -> UINT {
+        self.inner
+    }
+
+    #[inline]
+    ///Returns whether item is checked.
+    pub fn is_checked(&self) -> bool {
+        self.inner & MFS_CHECKED != 0
+    }
+
+    #[inline]
+    ///Returns whether item is disabled.
+    ///
+    ///Item can be disabled without being grayed, but it cannot be selected either way.
+    pub fn is_disabled(&self) -> bool {
+        self.inner & MF_DISABLED != 0
+    }
+
+    #[inline]
+    ///Returns whether item is grayed.
+    pub fn is_grayed(&self) -> bool {
+        self.inner & MF_GRAYED != 0
+    }
+
+    #[inline]
+    ///Returns whether item is highlighted.
+    pub fn is_highlighted(&self) -> bool {
+        self.inner & MFS_HILITE != 0
+    }
+
+    #[inline]
+    ///Returns whether item is default.
+    pub fn is_default(&self) -> bool {
+        self.inner & MFS_DEFAULT != 0
+    }
+
+    #[inline]
+    ///Returns whether item can be selected.
+    pub fn is_enabled(&self) -> bool {
+        !self.is_disabled() && !self.is_grayed()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+///Item of menu.
+pub struct MenuItem {
+    ///Command identifier.
+    pub id: UINT,
+    ///Text, including accelerator prefix and shortcut, e.g. `&Save\tCtrl+S`.
+    pub text: String,
+    ///State.
+    pub state: MenuState,
+    ///Whether item is separator.
+    pub is_separator: bool,
+    ///Handle of window that owns menu.
+    pub window: usize,
+    ///Whether item belongs to system menu.
+    pub is_system: bool,
+    ///Submenu that item opens.
+    pub submenu: Option<Menu>,
+}
+
+impl MenuItem {
+    ///Returns text without accelerator prefix and shortcut, e.g. `Save` for `&Save\tCtrl+S`.
+    pub fn label(&self) -> String {
+        label(&self.text)
+    }
+
+    ///Returns message that invokes item's command as `(message, w_param, l_param)`.
+    ///
+    ///It is `WM_SYSCOMMAND` for items of system menu and `WM_COMMAND` otherwise.
+    pub fn command(&self) -> (UINT, WPARAM, LPARAM) {
+        match self.is_system {
+            true => (WM_SYSCOMMAND, self.id as WPARAM, 0),
+            //High word is 0 for menu.
+            false => (WM_COMMAND, (self.id & 0xFFFF) as WPARAM, 0),
+        }
+    }
+}
+
+#[cfg(windows)]
+impl MenuItem {
+    ///Invokes item's command, posting message to owning window.
+    ///
+    ///Fails with `ERROR_INVALID_OPERATION` if item cannot be selected, i.e. disabled, separator or has submenu.
+    pub fn invoke(&self) -> Result<()> {
+        //ERROR_INVALID_OPERATION
+        const INVALID_OPERATION: i32 = 4317;
+
+        if !self.state.is_enabled() || self.is_separator || self.submenu.is_some() {
+            return Err(ErrorCode::new_system(INVALID_OPERATION));
+        }
+
+        let (msg_type, w_param, l_param) = self.command();
+        crate::raw::message::post(self.window as HWND, msg_type, w_param, l_param)
+    }
+}
+
+fn label(text: &str) -> String {
+    let text = text.split('\t').next().unwrap_or("");
+    let mut result = String::with_capacity(text.len());
+
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            //`&&` stands for literal ampersand
+            '&' => if let Some(ch) = chars.next() {
+                result.push(ch);
+            },
+            ch => result.push(ch),
+        }
+    }
+
+    result.trim().to_owned()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+///Menu.
+pub struct Menu {
+    ///Items in order of appearance.
+    pub items: Vec<MenuItem>,
+}
+
+impl Menu {
+    ///Looks up item by path of labels separated by `/`, e.g. `File/Save As...`.
+    ///
+    ///Labels are compared case-insensitively, ignoring accelerator prefix and shortcut.
+    pub fn find(&self, path: &str) -> Option<&MenuItem> {
+        let mut menu = self;
+        let mut parts = path.split('/').map(|part| part.trim()).peekable();
+
+        while let Some(part) = parts.next() {
+            let item = menu.items.iter().find(|item| !item.is_separator && item.label().to_lowercase() == part.to_lowercase())?;
+
+            match parts.peek() {
+                None => return Some(item),
+                Some(_) => menu = item.submenu.as_ref()?,
+            }
+        }
+
+        None
+    }
+
+    ///Looks up item by command identifier, including submenus.
+    pub fn find_by_id(&self, id: UINT) -> Option<&MenuItem> {
+        self.items.iter().find_map(|item| match item.submenu.as_ref() {
+            Some(submenu) => submenu.find_by_id(id),
+            None if !item.is_separator && item.id == id => Some(item),
+            None => None,
+        })
+    }
+}
+
+#[cfg(windows)]
+impl Menu {
+    ///Reads menu bar of window.
+    ///
+    ///# Note
+    ///
+    ///Applications often update state of items only when menu is about to be shown.
+    ///
+    ///# Return
+    ///
+    ///* ```Ok(Some)``` - Menu.
+    ///* ```Ok(None)``` - Window has no menu.
+    ///* ```Err``` - Error reason.
+    pub fn of_window(window: HWND) -> Result<Option<Self>> {
+        match unsafe { GetMenu(window) } {
+            menu if menu.is_null() => Ok(None),
+            menu => Self::from_handle(menu, window, false).map(Some),
+        }
+    }
+
+    ///Reads system menu of window, i.e. one with `Move`, `Close` and etc.
+    ///
+    ///# Return
+    ///
+    ///* ```Ok(Some)``` - Menu.
+    ///* ```Ok(None)``` - Window has no system menu.
+    ///* ```Err``` - Error reason.
+    pub fn system(window: HWND) -> Result<Option<Self>> {
+        match unsafe { GetSystemMenu(window, 0) } {
+            menu if menu.is_null() => Ok(None),
+            menu => Self::from_handle(menu, window, true).map(Some),
+        }
+    }
+
+    ///Reads menu by handle.
+    ///
+    ///# Parameters
+    ///
+    ///* ```menu``` - Handle to menu.
+    ///* ```window``` - Window that owns menu, to which commands are to be sent.
+    ///* ```is_system``` - Whether menu is system menu.
+    pub fn from_handle(menu: HMENU, window: HWND, is_system: bool) -> Result<Self> {
+        let len = unsafe { GetMenuItemCount(menu) };
+        if len < 0 {
+            return Err(utils::get_last_error());
+        }
+
+        let mut items = Vec::with_capacity(len as usize);
+        for idx in 0..len as UINT {
+            let mut info: MENUITEMINFOW = unsafe { mem::zeroed() };
+            info.cbSize = mem::size_of::<MENUITEMINFOW>() as UINT;
+            info.fMask = MIIM_STATE | MIIM_ID | MIIM_SUBMENU | MIIM_STRING | MIIM_FTYPE;
+
+            //First retrieve length of text
+            if unsafe { GetMenuItemInfoW(menu, idx, 1, &mut info) } == 0 {
+                return Err(utils::get_last_error());
+            }
+
+            let text = match info.cch {
+                0 => String::new(),
+                cch => {
+                    let mut text: Vec<u16> = vec![0; cch as usize + 1];
+                    info.dwTypeData = text.as_mut_ptr();
+                    info.cch = text.len() as UINT;
+
+                    if unsafe { GetMenuItemInfoW(menu, idx, 1, &mut info) } == 0 {
+                        return Err(utils::get_last_error());
+                    }
+                    info.dwTypeData = ptr::null_mut();
+
+                    let len = text.iter().position(|ch| *ch == 0).unwrap_or(text.len());
+                    String::from_utf16_lossy(&text[..len])
+                }
+            };
+
+            let submenu = match info.hSubMenu.is_null() {
+                true => None,
+                false => Some(Self::from_handle(info.hSubMenu, window, is_system)?),
+            };
+
+            items.push(MenuItem {
+                id: info.wID,
+                text,
+                state: MenuState::from_raw(info.fState),
+                is_separator: info.fType & MFT_SEPARATOR != 0,
+                window: window as usize,
+                is_system,
+                submenu,
+            });
+        }
+
+        Ok(Self {
+            items
+        })
+    }
+}
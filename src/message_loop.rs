@@ -0,0 +1,122 @@
+//! Dedicated message loop thread.
+//!
+//! Windows delivers messages to the thread that created the window, hook or timer.
+//! [MessageThread](struct.MessageThread.html) spawns thread which initializes such resources
+//! and then runs message loop on them until it is stopped.
+
+use std::{thread, panic};
+use std::sync::mpsc;
+
+use crate::sys::{MSG, DWORD, UINT, WPARAM, LPARAM, WM_QUIT, WM_USER, PM_NOREMOVE};
+use crate::raw;
+use crate::utils::Result;
+
+///Thread running Windows message loop.
+///
+///On creation it runs user's initialization routine on the new thread,
+///which returns handler to be called on each retrieved message.
+///Messages are translated and dispatched after handler is invoked.
+///
+///Loop is stopped on `WM_QUIT` and on drop, which waits for the thread to finish.
+///Initialization's resources are dropped on the loop thread.
+pub struct MessageThread {
+    thread_id: DWORD,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl MessageThread {
+    ///Spawns new message loop thread.
+    ///
+    ///# Parameters:
+    ///
+    ///* ```init``` - Runs on new thread, before loop is started. Returns message handler.
+    ///
+    ///# Return:
+    ///
+    ///* ```Ok``` - Thread is running message loop.
+    ///* ```Err``` - Either initialization error or inability to spawn thread.
+    pub fn spawn<I, F>(init: I) -> Result<Self> where I: FnOnce() -> Result<F> + Send + 'static, F: FnMut(&MSG) + 'static {
+        let (sender, receiver) = mpsc::channel();
+
+        let handle = thread::Builder::new().name("windows-win-message-loop".to_owned()).spawn(move || {
+            //Forces system to create message queue so that posting works right after spawn.
+            let _ = raw::message::peek(None, Some(WM_USER), Some(WM_USER), Some(PM_NOREMOVE));
+
+            let mut handler = match init() {
+                Ok(handler) => {
+                    let _ = sender.send(Ok(raw::message::current_thread_id()));
+                    handler
+                },
+                Err(error) => {
+                    let _ = sender.send(Err(error));
+                    return;
+                }
+            };
+            drop(sender);
+
+            while let Ok(mut msg) = raw::message::get(None, None, None) {
+                if msg.message == WM_QUIT {
+                    break;
+                }
+
+                handler(&msg);
+                raw::message::translate(&mut msg);
+                raw::message::dispatch(&mut msg);
+            }
+        });
+
+        let handle = match handle {
+            Ok(handle) => handle,
+            Err(error) => return Err(error.into()),
+        };
+
+        match receiver.recv() {
+            Ok(Ok(thread_id)) => Ok(Self {
+                thread_id,
+                handle: Some(handle)
+            }),
+            Ok(Err(error)) => {
+                let _ = handle.join();
+                Err(error)
+            },
+            //Initialization panicked, so propagate it to the caller.
+            Err(_) => match handle.join() {
+                Ok(()) => unreachable!(),
+                Err(panic) => panic::resume_unwind(panic),
+            }
+        }
+    }
+
+    #[inline]
+    ///Returns identifier of the loop thread.
+    pub fn thread_id(&self) -> DWORD {
+        self.thread_id
+    }
+
+    #[inline]
+    ///Posts message to the loop thread.
+    ///
+    ///Message is passed to handler, but as it has no window, it is not dispatched anywhere.
+    pub fn post(&self, msg_type: UINT, w_param: WPARAM, l_param: LPARAM) -> Result<()> {
+        raw::message::post_thread(self.thread_id, msg_type, w_param, l_param)
+    }
+
+    ///Stops message loop and waits for thread to finish.
+    pub fn stop(mut self) {
+        self.inner_stop();
+    }
+
+    fn inner_stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            //Error means thread has already exited
+            let _ = self.post(WM_QUIT, 0, 0);
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for MessageThread {
+    fn drop(&mut self) {
+        self.inner_stop();
+    }
+}
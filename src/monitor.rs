@@ -0,0 +1,344 @@
+//! Monitors and DPI scaling.
+//!
+//! Coordinates are either physical, i.e. pixels of monitor, or logical, i.e. pixels at 96 DPI.
+//! [Scale](struct.Scale.html) converts between them and is platform independent.
+
+use crate::sys::{
+    UINT,
+    LONG,
+    USER_DEFAULT_SCREEN_DPI,
+    DPI_AWARENESS_CONTEXT,
+    DPI_AWARENESS_CONTEXT_UNAWARE,
+    DPI_AWARENESS_CONTEXT_SYSTEM_AWARE,
+    DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE,
+    DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+    DPI_AWARENESS_CONTEXT_UNAWARE_GDISCALED,
+};
+use crate::window_tree::Rect;
+
+#[cfg(windows)]
+use crate::sys::{
+    HWND,
+    HDC,
+    HMONITOR,
+    DWORD,
+    RECT,
+    LPARAM,
+    BOOL,
+    S_OK,
+    MDT_EFFECTIVE_DPI,
+    MONITOR_DEFAULTTONEAREST,
+    MONITOR_DEFAULTTOPRIMARY,
+    MONITORINFOF_PRIMARY,
+    MONITORINFOEXW,
+    POINT,
+    EnumDisplayMonitors,
+    GetMonitorInfoW,
+    MonitorFromWindow,
+    MonitorFromPoint,
+    GetDpiForWindow,
+    GetDpiForSystem,
+    GetDpiForMonitor,
+    SetProcessDpiAwarenessContext,
+    SetThreadDpiAwarenessContext,
+};
+#[cfg(windows)]
+use crate::utils::{self, ErrorCode, Result};
+#[cfg(windows)]
+use core::{mem, ptr};
+
+//Computes `value * numerator / denominator`, rounding halves away from zero like `MulDiv`.
+fn mul_div(value: LONG, numerator: UINT, denominator: UINT) -> LONG {
+    if denominator == 0 {
+        return value;
+    }
+
+    //LONG is 64-bit on some hosts, so product might not fit into i64.
+    let product = value as i128 * numerator as i128;
+    let denominator = denominator as i128;
+    let half = denominator / 2;
+
+    let result = match product < 0 {
+        true => (product - half) / denominator,
+        false => (product + half) / denominator,
+    };
+
+    result as LONG
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///Scale of monitor or window, defined by its DPI.
+///
+///Conversions round to nearest integer, with halves rounded away from zero, same as `MulDiv`.
+///Therefore converting back and forth is not always lossless, e.g. at 144 DPI `1` logical pixel
+///is `2` physical pixels, while `2` physical pixels are `1` logical pixel and `1` physical pixel
+///rounds to `1` logical pixel too.
+pub struct Scale {
+    dpi: UINT,
+}
+
+impl Scale {
+    #[inline]
+    ///Creates scale for DPI.
+    ///
+    ///DPI of `0` is treated as `96`.
+    pub fn new(dpi: UINT) -> Self {
+        Self {
+            dpi: match dpi {
+                0 => USER_DEFAULT_SCREEN_DPI,
+                dpi => dpi,
+            }
+        }
+    }
+
+    #[inline]
+    ///Returns DPI.
+    pub fn dpi(&self) -> UINT {
+        self.dpi
+    }
+
+    #[inline]
+    ///Returns scale factor, e.g. `1.5` for 144 DPI.
+    pub fn factor(&self) -> f64 {
+        self.dpi as f64 / USER_DEFAULT_SCREEN_DPI as f64
+    }
+
+    #[inline]
+    ///Returns scale factor in percents, e.g. `150` for 144 DPI.
+    pub fn percent(&self) -> UINT {
+        mul_div(100, self.dpi, USER_DEFAULT_SCREEN_DPI) as UINT
+    }
+
+    #[inline]
+    ///Converts logical value to physical.
+    pub fn to_physical(&self, value: LONG) -> LONG {
+        mul_div(value, self.dpi, USER_DEFAULT_SCREEN_DPI)
+    }
+
+    #[inline]
+    ///Converts physical value to logical.
+    pub fn to_logical(&self, value: LONG) -> LONG {
+        mul_div(value, USER_DEFAULT_SCREEN_DPI, self.dpi)
+    }
+
+    #[inline]
+    ///Converts logical point to physical.
+    pub fn point_to_physical(&self, (x, y): (LONG, LONG)) -> (LONG, LONG) {
+        (self.to_physical(x), self.to_physical(y))
+    }
+
+    #[inline]
+    ///Converts physical point to logical.
+    pub fn point_to_logical(&self, (x, y): (LONG, LONG)) -> (LONG, LONG) {
+        (self.to_logical(x), self.to_logical(y))
+    }
+
+    ///Converts logical rectangle to physical.
+    ///
+    ///Each edge is converted separately, so that adjacent rectangles remain adjacent.
+    pub fn rect_to_physical(&self, rect: Rect) -> Rect {
+        Rect {
+            left: self.to_physical(rect.left),
+            top: self.to_physical(rect.top),
+            right: self.to_physical(rect.right),
+            bottom: self.to_physical(rect.bottom),
+        }
+    }
+
+    ///Converts physical rectangle to logical.
+    ///
+    ///Each edge is converted separately, so that adjacent rectangles remain adjacent.
+    pub fn rect_to_logical(&self, rect: Rect) -> Rect {
+        Rect {
+            left: self.to_logical(rect.left),
+            top: self.to_logical(rect.top),
+            right: self.to_logical(rect.right),
+            bottom: self.to_logical(rect.bottom),
+        }
+    }
+}
+
+impl Default for Scale {
+    #[inline]
+    fn default() -> Self {
+        Self::new(USER_DEFAULT_SCREEN_DPI)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+///DPI awareness of process or thread.
+pub enum DpiAwareness {
+    ///Always 96 DPI, scaled by system.
+    Unaware,
+    ///DPI of primary monitor at the time of login, scaled by system on other monitors.
+    System,
+    ///DPI of monitor, notified via `WM_DPICHANGED` to top-level windows.
+    PerMonitor,
+    ///DPI of monitor, with non-client area and child windows scaled too.
+    PerMonitorV2,
+    ///Same as `Unaware`, but GDI content is rendered at higher quality.
+    UnawareGdiScaled,
+}
+
+impl DpiAwareness {
+    ///Returns raw awareness context.
+    pub fn raw(&self) -> DPI_AWARENESS_CONTEXT {
+        match self {
+            DpiAwareness::Unaware => DPI_AWARENESS_CONTEXT_UNAWARE,
+            DpiAwareness::System => DPI_AWARENESS_CONTEXT_SYSTEM_AWARE,
+            DpiAwareness::PerMonitor => DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE,
+            DpiAwareness::PerMonitorV2 => DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+            DpiAwareness::UnawareGdiScaled => DPI_AWARENESS_CONTEXT_UNAWARE_GDISCALED,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Display monitor.
+pub struct Monitor {
+    ///Monitor's handle as integer.
+    pub handle: usize,
+    ///Monitor's rectangle in virtual screen coordinates.
+    pub rect: Rect,
+    ///Area of monitor not occupied by taskbar and docked toolbars.
+    pub work_area: Rect,
+    ///Whether monitor is primary.
+    pub is_primary: bool,
+    ///Name of device, e.g. `\\.\DISPLAY1`.
+    pub device: String,
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn enum_monitors(monitor: HMONITOR, _: HDC, _: *mut RECT, param: LPARAM) -> BOOL {
+    let monitors = &mut *(param as *mut Vec<HMONITOR>);
+    monitors.push(monitor);
+    1
+}
+
+#[cfg(windows)]
+impl Monitor {
+    ///Retrieves all monitors.
+    pub fn all() -> Result<Vec<Self>> {
+        let mut handles: Vec<HMONITOR> = Vec::new();
+
+        match unsafe { EnumDisplayMonitors(ptr::null_mut(), ptr::null(), Some(enum_monitors), &mut handles as *mut _ as LPARAM) } {
+            0 => Err(utils::get_last_error()),
+            _ => handles.into_iter().map(Self::from_handle).collect(),
+        }
+    }
+
+    ///Retrieves primary monitor.
+    pub fn primary() -> Result<Self> {
+        let monitor = unsafe { MonitorFromPoint(POINT { x: 0, y: 0 }, MONITOR_DEFAULTTOPRIMARY) };
+        Self::from_handle(monitor)
+    }
+
+    ///Retrieves monitor that has largest intersection with window, or nearest one.
+    pub fn of_window(window: HWND) -> Result<Self> {
+        match unsafe { MonitorFromWindow(window, MONITOR_DEFAULTTONEAREST) } {
+            monitor if monitor.is_null() => Err(utils::get_last_error()),
+            monitor => Self::from_handle(monitor),
+        }
+    }
+
+    ///Retrieves information about monitor by handle.
+    pub fn from_handle(monitor: HMONITOR) -> Result<Self> {
+        let mut info: MONITORINFOEXW = unsafe { mem::zeroed() };
+        info.cbSize = mem::size_of::<MONITORINFOEXW>() as DWORD;
+
+        if unsafe { GetMonitorInfoW(monitor, &mut info) } == 0 {
+            return Err(utils::get_last_error());
+        }
+
+        let len = info.szDevice.iter().position(|ch| *ch == 0).unwrap_or(info.szDevice.len());
+
+        Ok(Self {
+            handle: monitor as usize,
+            rect: info.rcMonitor.into(),
+            work_area: info.rcWork.into(),
+            is_primary: info.dwFlags & MONITORINFOF_PRIMARY != 0,
+            device: String::from_utf16_lossy(&info.szDevice[..len]),
+        })
+    }
+
+    ///Retrieves effective DPI of monitor.
+    ///
+    ///Unless process is per-monitor aware, system DPI is returned.
+    pub fn dpi(&self) -> Result<UINT> {
+        let mut dpi_x = 0;
+        let mut dpi_y = 0;
+
+        match unsafe { GetDpiForMonitor(self.handle as HMONITOR, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) } {
+            S_OK => Ok(dpi_x),
+            error => Err(utils::from_hresult(error)),
+        }
+    }
+
+    #[inline]
+    ///Retrieves scale of monitor.
+    pub fn scale(&self) -> Result<Scale> {
+        self.dpi().map(Scale::new)
+    }
+}
+
+#[cfg(windows)]
+///Retrieves DPI of window.
+///
+///Result depends on DPI awareness of window, e.g. it is always `96` for unaware windows.
+pub fn window_dpi(window: HWND) -> Result<UINT> {
+    match unsafe { GetDpiForWindow(window) } {
+        0 => Err(utils::get_last_error()),
+        dpi => Ok(dpi),
+    }
+}
+
+#[cfg(windows)]
+///Retrieves system DPI, i.e. DPI of primary monitor at the time of login.
+pub fn system_dpi() -> UINT {
+    unsafe { GetDpiForSystem() }
+}
+
+#[cfg(windows)]
+///Sets DPI awareness of current process.
+///
+///Must be called before any window is created. Fails if awareness is already set, including via manifest.
+pub fn set_process_dpi_awareness(awareness: DpiAwareness) -> Result<()> {
+    match unsafe { SetProcessDpiAwarenessContext(awareness.raw()) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(windows)]
+///Sets DPI awareness of current thread, restoring previous one on drop.
+///
+///Windows created by thread keep awareness that was set at time of creation.
+pub struct ThreadDpiAwareness {
+    previous: DPI_AWARENESS_CONTEXT,
+}
+
+#[cfg(windows)]
+impl ThreadDpiAwareness {
+    ///Sets DPI awareness of current thread.
+    pub fn set(awareness: DpiAwareness) -> Result<Self> {
+        //ERROR_INVALID_PARAMETER
+        const INVALID_PARAMETER: i32 = 87;
+
+        match unsafe { SetThreadDpiAwarenessContext(awareness.raw()) } {
+            previous if previous.is_null() => Err(ErrorCode::new_system(INVALID_PARAMETER)),
+            previous => Ok(Self {
+                previous
+            }),
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for ThreadDpiAwareness {
+    fn drop(&mut self) {
+        unsafe {
+            SetThreadDpiAwarenessContext(self.previous);
+        }
+    }
+}
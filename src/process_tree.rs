@@ -0,0 +1,65 @@
+//! Process tree built from parent-pid relationships.
+//!
+//! Windows doesn't keep track of process hierarchy, only pid of parent at the moment of creation is known.
+//! As pids are re-used, parent can be already dead and its pid taken by unrelated process,
+//! which can even result in cycle. Tree doesn't attempt to detect such processes, but guards against cycles.
+//!
+//! Use [raw::process::list()](../raw/process/fn.list.html) to retrieve running processes.
+//!
+//! Tree itself is platform independent.
+
+use std::collections::{HashMap, HashSet};
+
+///Process tree.
+pub struct ProcessTree {
+    children: HashMap<u32, Vec<u32>>,
+}
+
+impl ProcessTree {
+    ///Creates tree from iterator over pairs of `(pid, parent_pid)`.
+    pub fn new<I: IntoIterator<Item = (u32, u32)>>(processes: I) -> Self {
+        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+
+        for (pid, parent_pid) in processes {
+            //System idle process is parent of itself.
+            if pid != parent_pid {
+                children.entry(parent_pid).or_default().push(pid);
+            }
+        }
+
+        Self {
+            children
+        }
+    }
+
+    ///Returns direct children of process.
+    pub fn children(&self, pid: u32) -> &[u32] {
+        self.children.get(&pid).map(|children| children.as_slice()).unwrap_or(&[])
+    }
+
+    ///Returns all descendants of process in breadth-first order.
+    ///
+    ///Process itself is not included.
+    pub fn descendants(&self, pid: u32) -> Vec<u32> {
+        let mut visited = HashSet::new();
+        visited.insert(pid);
+
+        let mut result = Vec::new();
+        let mut idx = 0;
+        let mut current = pid;
+
+        loop {
+            for child in self.children(current) {
+                if visited.insert(*child) {
+                    result.push(*child);
+                }
+            }
+
+            match result.get(idx) {
+                Some(next) => current = *next,
+                None => break result,
+            }
+            idx += 1;
+        }
+    }
+}
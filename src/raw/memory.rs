@@ -87,9 +87,8 @@ impl Iterator for Virtual {
     type Item = Info;
 
     fn next(&mut self) -> Option<Self::Item> {
-        virtual_query_ex(self.handle, self.addr as *const c_void).ok().map(|info| {
+        virtual_query_ex(self.handle, self.addr as *const c_void).ok().inspect(|info| {
             self.addr = unsafe { self.addr.add(info.size()) };
-            info
         })
     }
 }
@@ -114,3 +113,69 @@ pub fn virtual_query_ex(handle: HANDLE, base: *const c_void) -> Result<Info> {
         Err(utils::get_last_error())
     }
 }
+
+///Memory allocated in another process.
+///
+///Used to pass structures to windows of other processes, as pointers are only valid within own process.
+///
+///Memory is freed on drop.
+///
+///# Note:
+///
+///Process must be opened with `PROCESS_VM_OPERATION`, `PROCESS_VM_READ` and `PROCESS_VM_WRITE` access rights,
+///and its handle must remain valid as long as buffer is alive.
+pub struct RemoteBuffer {
+    process: HANDLE,
+    addr: usize,
+    size: usize,
+}
+
+impl RemoteBuffer {
+    ///Allocates zeroed read-write memory using `VirtualAllocEx`.
+    pub fn alloc(process: HANDLE, size: usize) -> Result<Self> {
+        let addr = unsafe { VirtualAllocEx(process, ptr::null_mut(), size as SIZE_T, MEM_COMMIT | MEM_RESERVE, PAGE_READWRITE) };
+
+        if addr.is_null() {
+            Err(utils::get_last_error())
+        }
+        else {
+            Ok(Self {
+                process,
+                addr: addr as usize,
+                size,
+            })
+        }
+    }
+
+    #[inline]
+    ///Returns address within process.
+    pub fn addr(&self) -> usize {
+        self.addr
+    }
+
+    #[inline]
+    ///Returns size of allocated memory.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    ///Writes data at specified offset.
+    pub fn write(&self, offset: usize, data: &[u8]) -> Result<()> {
+        debug_assert!(offset + data.len() <= self.size);
+        super::process::write_memory(self.process, self.addr + offset, data)
+    }
+
+    ///Reads data from specified offset.
+    pub fn read(&self, offset: usize, storage: &mut [u8]) -> Result<()> {
+        debug_assert!(offset + storage.len() <= self.size);
+        super::process::read_memory(self.process, self.addr + offset, storage)
+    }
+}
+
+impl Drop for RemoteBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            VirtualFreeEx(self.process, self.addr as LPVOID, 0, MEM_RELEASE);
+        }
+    }
+}
@@ -104,3 +104,55 @@ pub fn dispatch(msg: LPMSG) -> LRESULT {
         DispatchMessageW(msg)
     }
 }
+
+///Posts message to the message queue of the thread that created window.
+///
+///Unlike sending, it returns without waiting for message to be processed.
+///
+///# Parameters:
+///
+///* ```window``` - Handle to the window whose window procedure is to receive the message.
+///* ```msg_type``` - Type of message. See WinAPI docs.
+///* ```w_param``` - Additional message specific parameter.
+///* ```l_param``` - Additional message specific parameter.
+///
+///# Return
+///
+///* ```Ok``` - Message has been posted.
+///* ```Err``` - Error reason.
+pub fn post(window: HWND, msg_type: UINT, w_param: WPARAM, l_param: LPARAM) -> Result<()> {
+    match unsafe { PostMessageW(window, msg_type, w_param, l_param) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(())
+    }
+}
+
+///Posts message to the message queue of the specified thread.
+///
+///# Parameters:
+///
+///* ```thread_id``` - Identifier of the thread to which the message is to be posted.
+///* ```msg_type``` - Type of message. See WinAPI docs.
+///* ```w_param``` - Additional message specific parameter.
+///* ```l_param``` - Additional message specific parameter.
+///
+///# Return
+///
+///* ```Ok``` - Message has been posted.
+///* ```Err``` - Error reason. Most likely thread has no message queue.
+pub fn post_thread(thread_id: DWORD, msg_type: UINT, w_param: WPARAM, l_param: LPARAM) -> Result<()> {
+    match unsafe { PostThreadMessageW(thread_id, msg_type, w_param, l_param) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(())
+    }
+}
+
+#[inline]
+///Retrieves identifier of the calling thread.
+///
+///It can be used with [post_thread()](fn.post_thread.html).
+pub fn current_thread_id() -> DWORD {
+    unsafe {
+        GetCurrentThreadId()
+    }
+}
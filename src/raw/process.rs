@@ -5,6 +5,7 @@ use core::ffi::c_void;
 
 use crate::sys::*;
 use crate::utils::{self, Result};
+use crate::process_tree::ProcessTree;
 
 ///Opens process by pid.
 ///
@@ -198,3 +199,107 @@ pub fn is_elevated(process: HANDLE) -> bool {
 
     result
 }
+
+///Waits until process is waiting for user input with no input pending, or until timeout expires.
+///
+///Useful to make sure that newly started process created its windows.
+///
+///# Parameters
+///
+///* ```process``` - Pointer to a opened process.
+///* ```timeout``` - Timeout in milliseconds. If `None`, waits indefinitely.
+///
+///# Return
+///
+///* ```Ok(true)``` - Process is idle.
+///* ```Ok(false)``` - Timeout expired.
+///* ```Err``` - Error reason, e.g. process has no message queue as console application.
+pub fn wait_for_input_idle(process: HANDLE, timeout: Option<c_ulong>) -> Result<bool> {
+    match unsafe { WaitForInputIdle(process, timeout.unwrap_or(INFINITE)) } {
+        0 => Ok(true),
+        WAIT_TIMEOUT => Ok(false),
+        _ => Err(utils::get_last_error()),
+    }
+}
+
+///Information about running process.
+pub struct ProcessEntry {
+    ///Pid of the process.
+    pub pid: u32,
+    ///Pid of the process that created it.
+    ///
+    ///Note that parent can be already terminated and its pid re-used.
+    pub parent_pid: u32,
+    ///Number of threads.
+    pub threads: u32,
+    ///Name of executable file.
+    pub exe: String,
+}
+
+///Retrieves list of running processes using `CreateToolhelp32Snapshot`.
+///
+///# Return
+///
+///* ```Ok``` - Vector of processes.
+///* ```Err``` - Error reason.
+pub fn list() -> Result<Vec<ProcessEntry>> {
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) };
+    if snapshot == INVALID_HANDLE_VALUE {
+        return Err(utils::get_last_error());
+    }
+
+    let mut result = Vec::new();
+    let mut entry: PROCESSENTRY32W = unsafe { mem::zeroed() };
+    entry.dwSize = mem::size_of::<PROCESSENTRY32W>() as DWORD;
+
+    let mut is_next = unsafe { Process32FirstW(snapshot, &mut entry) } != 0;
+    while is_next {
+        let exe_len = entry.szExeFile.iter().position(|ch| *ch == 0).unwrap_or(entry.szExeFile.len());
+        result.push(ProcessEntry {
+            pid: entry.th32ProcessID,
+            parent_pid: entry.th32ParentProcessID,
+            threads: entry.cntThreads,
+            exe: String::from_utf16_lossy(&entry.szExeFile[..exe_len]),
+        });
+
+        is_next = unsafe { Process32NextW(snapshot, &mut entry) } != 0;
+    }
+
+    let error = utils::get_last_error();
+    unsafe {
+        CloseHandle(snapshot);
+    }
+
+    match error.raw_code() as DWORD {
+        ERROR_NO_MORE_FILES => Ok(result),
+        _ => Err(error),
+    }
+}
+
+///Retrieves pids of all descendants of the process.
+///
+///For details see [ProcessTree](../../process_tree/struct.ProcessTree.html).
+pub fn descendants(pid: u32) -> Result<Vec<u32>> {
+    let processes = list()?;
+    let tree = ProcessTree::new(processes.iter().map(|process| (process.pid, process.parent_pid)));
+    Ok(tree.descendants(pid))
+}
+
+///Determines whether process is 32-bit application running on 64-bit Windows.
+///
+///# Parameters
+///
+///* ```process``` - Pointer to a opened process.
+///
+///# Return
+///
+///* ```Ok``` - Whether process runs under WOW64.
+///* ```Err``` - Error reason.
+pub fn is_wow64(process: HANDLE) -> Result<bool> {
+    let mut result: BOOL = 0;
+
+    match unsafe { IsWow64Process(process, &mut result) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(result != 0),
+    }
+}
@@ -8,8 +8,14 @@ use crate::sys::{
     DeleteTimerQueueEx,
     DeleteTimerQueueTimer,
     ChangeTimerQueueTimer,
+    CreateEventW,
+    WaitForSingleObject,
+    CloseHandle,
     HANDLE,
+    BOOLEAN,
+    INFINITE,
     INVALID_HANDLE_VALUE,
+    ERROR_IO_PENDING,
     WT_EXECUTEINTIMERTHREAD,
     WT_EXECUTEINPERSISTENTTHREAD,
     WT_EXECUTELONGFUNCTION,
@@ -23,6 +29,8 @@ use crate::sys::{
 use crate::utils::{self, Result};
 
 use core::{ptr, mem};
+use std::{panic, thread};
+use std::sync::Mutex;
 
 ///Retrieves the frequency of the performance counter.
 ///
@@ -193,9 +201,50 @@ impl TimerQueue {
 
         match unsafe { CreateTimerQueueTimer(&mut timer as *mut _, self.handle, cb, param, due_time, period, flags.inner) } {
             0 => Err(utils::get_last_error()),
-            _ => Ok(QueueTimer { queue: self.handle, inner: timer })
+            _ => Ok(QueueTimer { queue: self.handle, inner: timer, closure: ptr::null_mut() })
         }
     }
+
+    ///Creates new timer on queue with Rust closure as callback.
+    ///
+    ///Closure is owned by timer and freed once timer is deleted and its callbacks are finished.
+    ///
+    ///Panic in closure is caught and doesn't affect subsequent invocations.
+    ///If previous invocation is still running when timer fires, this invocation is skipped.
+    ///
+    ///## Parameters
+    ///
+    ///- `cb` - Closure to be executed.
+    ///- `due_time` - The amount of time in milliseconds relative to the current time that must elapse before the timer is signaled for the first time.
+    ///- `period` - The period of the timer, in milliseconds. If this parameter is zero, the timer is signaled once.
+    ///- `flags` - Timer flags
+    pub fn timer_with<F: FnMut() + Send + 'static>(&self, cb: F, due_time: c_ulong, period: c_ulong, flags: TimerFlags) -> Result<QueueTimer> {
+        let closure: *mut Closure = Box::into_raw(Box::new(Mutex::new(Box::new(cb))));
+
+        match self.timer(Some(closure_callback), closure as *mut c_void, due_time, period, flags) {
+            Ok(mut timer) => {
+                timer.closure = closure;
+                Ok(timer)
+            },
+            Err(error) => {
+                //Timer is not created, so closure is never invoked.
+                drop(unsafe { Box::from_raw(closure) });
+                Err(error)
+            }
+        }
+    }
+}
+
+type Closure = Mutex<Box<dyn FnMut() + Send>>;
+
+unsafe extern "system" fn closure_callback(param: *mut c_void, _: BOOLEAN) {
+    let closure = &*(param as *const Closure);
+
+    //Closure cannot be invoked concurrently, and lock is never poisoned as panic is caught within it.
+    if let Ok(mut cb) = closure.try_lock() {
+        //Panic must not cross FFI boundary.
+        let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| cb()));
+    }
 }
 
 ///Raw type of callback function
@@ -226,9 +275,17 @@ unsafe impl Sync for TimerQueue {}
 ///By default `Drop` implementation deletes queue without waiting for
 ///callback to be finished.
 ///If you want to wait then you can use `delete` method.
+///
+///Closure of timer, created by [timer_with](struct.TimerQueue.html#method.timer_with), is freed on deletion:
+///
+///- `Wait` - Once function returns.
+///- `NoWait` - On separate thread, once running callbacks are finished.
+///
+///If deletion fails, e.g. because queue is already deleted, closure is leaked as it might be still in use.
 pub struct QueueTimer {
     queue: HANDLE,
     inner: HANDLE,
+    closure: *mut Closure,
 }
 
 impl QueueTimer {
@@ -237,9 +294,50 @@ impl QueueTimer {
         unsafe { DeleteTimerQueueTimer(self.queue, self.inner, T::handle()) }
     }
 
+    fn delete_closure<T: CompleteEvent>(&mut self) -> c_int {
+        let closure = mem::replace(&mut self.closure, ptr::null_mut());
+        if closure.is_null() {
+            return self.inner_delete::<T>();
+        }
+
+        //Blocks until callbacks are finished.
+        if T::handle() == INVALID_HANDLE_VALUE {
+            let result = self.inner_delete::<T>();
+            if result != 0 {
+                drop(unsafe { Box::from_raw(closure) });
+            }
+            return result;
+        }
+
+        let event = unsafe { CreateEventW(ptr::null_mut(), 1, 0, ptr::null()) };
+        if event.is_null() {
+            return self.inner_delete::<T>();
+        }
+
+        let result = unsafe { DeleteTimerQueueTimer(self.queue, self.inner, event) };
+        //Pending callbacks are not error, as event is signaled once they are finished.
+        if result == 0 && utils::get_last_error().raw_code() != ERROR_IO_PENDING as c_int {
+            unsafe {
+                CloseHandle(event);
+            }
+            return result;
+        }
+
+        //Raw pointers are not Send, but both are owned by thread from now on.
+        let (event, closure) = (event as usize, closure as usize);
+        let _ = thread::Builder::new().name("windows-win-timer-delete".to_owned()).spawn(move || unsafe {
+            WaitForSingleObject(event as HANDLE, INFINITE);
+            CloseHandle(event as HANDLE);
+            drop(Box::from_raw(closure as *mut Closure));
+        });
+
+        1
+    }
+
     ///Cancels timer without consuming it
     ///
-    ///User must ensure that drop is not called by forgetting timer
+    ///User must ensure that drop is not called by forgetting timer.
+    ///Closure of timer, if any, is leaked.
     pub unsafe fn cancel<T: CompleteEvent>(&self, _event: T) -> Result<()> {
         match self.inner_delete::<T>() {
             0 => Err(utils::get_last_error()),
@@ -259,8 +357,13 @@ impl QueueTimer {
     }
 
     ///Deletes timer and consumes it.
-    pub fn delete<T: CompleteEvent>(self, event: T) -> Result<()> {
-        let result = unsafe { self.cancel(event) };
+    ///
+    ///Must not be called with `Wait` from within timer's callback, as it would wait for itself.
+    pub fn delete<T: CompleteEvent>(mut self, _event: T) -> Result<()> {
+        let result = match self.delete_closure::<T>() {
+            0 => Err(utils::get_last_error()),
+            _ => Ok(())
+        };
 
         mem::forget(self);
         result
@@ -269,7 +372,7 @@ impl QueueTimer {
 
 impl Drop for QueueTimer {
     fn drop(&mut self) {
-        let _ = self.inner_delete::<NoWait>();
+        let _ = self.delete_closure::<NoWait>();
     }
 }
 
@@ -2,12 +2,16 @@
 
 use std::ffi;
 use std::os::windows::ffi::OsStrExt;
-use core::ptr;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use std::thread;
+use core::{ptr, cmp, fmt, mem};
 
 use crate::sys::SetLastErrorEx;
 
 use crate::sys::*;
-use crate::utils::{self, Result};
+use crate::utils::{self, Result, ErrorCode};
+use crate::win_event::{WinEventBuilder, WinEventKind};
 
 ///Determines if window is visible.
 ///
@@ -39,43 +43,186 @@ pub fn is_visible(window: HWND) -> bool {
 ///* ```Ok``` - Contains name of class.
 ///* ```Err``` - Error reason.
 pub fn get_class(window: HWND) -> Result<String> {
-    const BUF_SIZE: usize = 512;
-    let mut buff: [u16; BUF_SIZE] = [0; BUF_SIZE];
+    //Class names are limited to 256 characters, but it is not documented for RealGetWindowClass.
+    //Spare slot is required to tell that name fits.
+    let mut buff: Vec<u16> = vec![0; 258];
 
-    let writ_chars = unsafe { RealGetWindowClassW(window,
-                                                  buff.as_mut_ptr(),
-                                                  BUF_SIZE as u32) };
+    loop {
+        let writ_chars = unsafe { RealGetWindowClassW(window,
+                                                      buff.as_mut_ptr(),
+                                                      buff.len() as UINT) } as usize;
 
-    if writ_chars == 0 {
-        return Err(utils::get_last_error());
-    }
+        if writ_chars == 0 {
+            return Err(utils::get_last_error());
+        }
+        else if writ_chars < buff.len() - 1 {
+            return Ok(String::from_utf16_lossy(&buff[..writ_chars]));
+        }
 
-    Ok(String::from_utf16_lossy(&buff[0..writ_chars as usize]))
+        //Possibly truncated
+        let new_len = buff.len() * 2;
+        buff.resize(new_len, 0);
+    }
 }
 
 ///Retrieves window's title.
 ///
+///# Note
+///
+///For windows of other processes it retrieves caption only, while controls' text can be retrieved
+///only by [get_text_with()](fn.get_text_with.html).
+///
 ///# Parameters
 ///
 ///* ```window``` - A handle to the window to be tested.
 ///
 ///# Return
 ///
-///* ```Ok``` - Contains name of class.
-///* ```Err``` - Error reason.
+///* ```Ok``` - Contains title.
+///* ```Err``` - Error reason. Window without title is reported as error with code `0`.
 pub fn get_text(window: HWND) -> Result<String> {
-    const BUF_SIZE: usize = 512;
-    let mut buff: [u16; BUF_SIZE] = [0; BUF_SIZE];
+    unsafe { SetLastErrorEx(0, 0) };
+    let text_len = unsafe { GetWindowTextLengthW(window) };
+    if text_len == 0 {
+        return Err(utils::get_last_error());
+    }
 
-    let writ_chars = unsafe { GetWindowTextW(window,
-                                             buff.as_mut_ptr(),
-                                             BUF_SIZE as i32) };
+    //Length can be larger than actual text, but never smaller unless title is changed meanwhile.
+    //Spare slot is required to tell that text fits.
+    let mut buff: Vec<u16> = vec![0; text_len as usize + 2];
 
-    if writ_chars == 0 {
-        return Err(utils::get_last_error());
+    loop {
+        unsafe { SetLastErrorEx(0, 0) };
+        let writ_chars = unsafe { GetWindowTextW(window,
+                                                 buff.as_mut_ptr(),
+                                                 buff.len() as c_int) } as usize;
+
+        if writ_chars == 0 {
+            return Err(utils::get_last_error());
+        }
+        else if writ_chars < buff.len() - 1 {
+            return Ok(String::from_utf16_lossy(&buff[..writ_chars]));
+        }
+
+        let new_len = buff.len() * 2;
+        buff.resize(new_len, 0);
     }
+}
 
-    Ok(String::from_utf16_lossy(&buff[0..writ_chars as usize]))
+///Options of [get_text_with()](fn.get_text_with.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TextOptions {
+    ///Timeout in milliseconds for each message sent to window.
+    ///
+    ///If `None`, waits indefinitely for unresponsive window. Default is `1000`.
+    pub timeout: Option<UINT>,
+    ///Maximum number of UTF-16 characters to retrieve.
+    ///
+    ///Longer text is truncated. Default is `None`.
+    pub max_len: Option<usize>,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Some(1000),
+            max_len: None,
+        }
+    }
+}
+
+///Retrieves window's text using `WM_GETTEXTLENGTH` and `WM_GETTEXT`.
+///
+///Unlike [get_text()](fn.get_text.html) it works with controls of other processes.
+///
+///# Parameters
+///
+///* ```window``` - Handle to the window for which to send.
+///* ```options``` - Text retrieval options.
+///
+///# Return
+///
+///* ```Ok(Some)``` - Window's text.
+///* ```Ok(None)``` - If there is no text.
+///* ```Err``` - `SendError::Timeout` if window didn't respond in time, which usually means it is hung, otherwise error reason.
+pub fn get_text_with(window: HWND, options: &TextOptions) -> core::result::Result<Option<String>, SendError> {
+    let send = |msg_type, w_param, l_param| match options.timeout {
+        Some(timeout) => send_message_timeout(window, msg_type, w_param, l_param, timeout, SendMessageFlags::new().block()),
+        None => Ok(unsafe { SendMessageW(window, msg_type, w_param, l_param) }),
+    };
+
+    //Does not include null char
+    let text_len = send(WM_GETTEXTLENGTH, 0, 0)? as usize;
+    if text_len == 0 {
+        return Ok(None);
+    }
+
+    let max_len = options.max_len.unwrap_or(usize::max_value());
+    if max_len == 0 {
+        return Ok(Some(String::new()));
+    }
+
+    //Length can be larger than actual text, but never smaller unless text is changed meanwhile.
+    //Spare slot is required to tell that text fits, unless it is truncated anyway.
+    let mut buff: Vec<u16> = vec![0; cmp::min(text_len.saturating_add(1), max_len).saturating_add(1)];
+
+    loop {
+        let text_ptr = buff.as_mut_ptr() as LPARAM;
+        //Does not include null char
+        let writ_chars = send(WM_GETTEXT, buff.len() as WPARAM, text_ptr)? as usize;
+        let writ_chars = cmp::min(writ_chars, buff.len() - 1);
+
+        if writ_chars == 0 {
+            return Ok(None);
+        }
+        //Either it fits or it is enough
+        else if writ_chars < buff.len() - 1 || writ_chars >= max_len {
+            return Ok(Some(String::from_utf16_lossy(&buff[..writ_chars])));
+        }
+
+        let new_len = cmp::min(buff.len() * 2, max_len.saturating_add(1));
+        buff.resize(new_len, 0);
+    }
+}
+
+///Retrieves window's rectangle in screen coordinates.
+///
+///# Parameters
+///
+///* ```window``` - A handle to the window.
+///
+///# Return
+///
+///* ```Ok``` - Window's rectangle.
+///* ```Err``` - Error reason.
+pub fn get_rect(window: HWND) -> Result<RECT> {
+    let mut rect = RECT {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    };
+
+    match unsafe { GetWindowRect(window, &mut rect) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(rect),
+    }
+}
+
+#[inline]
+///Retrieves window's style.
+///
+///Returns `0` if window is invalid.
+pub fn get_style(window: HWND) -> DWORD {
+    unsafe { GetWindowLongW(window, GWL_STYLE) as DWORD }
+}
+
+#[inline]
+///Retrieves window's extended style.
+///
+///Returns `0` if window is invalid.
+pub fn get_ex_style(window: HWND) -> DWORD {
+    unsafe { GetWindowLongW(window, GWL_EXSTYLE) as DWORD }
 }
 
 unsafe extern "system" fn callback_enum_windows<T: FnMut(HWND)>(window: HWND, param: LPARAM) -> i32 {
@@ -201,6 +348,181 @@ pub fn get_by_pid(pid: u32) -> Result<Option<HWND>> {
 
 }
 
+///Retrieves handles of all windows that belong to the process.
+///
+///By default only top-level windows are retrieved.
+///Owned windows, like dialogs or tool palettes, are top-level too and therefore always included.
+pub struct ProcessWindows {
+    pid: u32,
+    is_children: bool,
+    is_descendants: bool,
+}
+
+impl ProcessWindows {
+    ///Creates new instance for process with specified pid.
+    pub fn new(pid: u32) -> Self {
+        Self {
+            pid,
+            is_children: false,
+            is_descendants: false,
+        }
+    }
+
+    ///Includes child windows, i.e. controls, of each top-level window.
+    pub fn children(mut self) -> Self {
+        self.is_children = true;
+        self
+    }
+
+    ///Includes windows of descendant processes.
+    ///
+    ///For details see [descendants()](../process/fn.descendants.html).
+    pub fn descendants(mut self) -> Self {
+        self.is_descendants = true;
+        self
+    }
+
+    ///Retrieves handles.
+    ///
+    ///# Return
+    ///
+    ///* ```Ok``` - Vector of handles, where each top-level window is followed by its children.
+    ///* ```Err``` - Error reason.
+    pub fn get(&self) -> Result<Vec<HWND>> {
+        let mut pids = vec![self.pid];
+        if self.is_descendants {
+            pids.extend(super::process::descendants(self.pid)?);
+        }
+
+        let mut top_windows: Vec<HWND> = vec![];
+        enum_by(None, |handle: HWND| {
+            let (process_pid, _) = get_thread_process_id(handle);
+            if pids.contains(&process_pid) {
+                top_windows.push(handle);
+            }
+        })?;
+
+        if !self.is_children {
+            return Ok(top_windows);
+        }
+
+        let mut found_windows = Vec::with_capacity(top_windows.len());
+        for window in top_windows {
+            found_windows.push(window);
+            //Window can be destroyed in meantime, in which case there is nothing to enumerate.
+            let _ = enum_by(Some(window), |handle: HWND| found_windows.push(handle));
+        }
+
+        Ok(found_windows)
+    }
+}
+
+///Retrieves handles of all top-level windows that belong to the process.
+///
+///# Parameters
+///
+///* ```pid``` - Pid of the process
+///
+///# Return
+///
+///* ```Ok``` - Vector of handles.
+///* ```Err``` - Error reason.
+pub fn get_all_by_pid(pid: u32) -> Result<Vec<HWND>> {
+    ProcessWindows::new(pid).get()
+}
+
+///Waits for top-level window that satisfies predicate.
+///
+///Windows are checked each time window is created, shown or renamed,
+///as reported by [WinEventWatcher](../../win_event/struct.WinEventWatcher.html).
+///In addition windows are polled with increasing delay, which is the only way to detect window
+///if event hooks cannot be installed.
+///
+///# Parameters
+///
+///* ```predicate``` - Callback that will be called on each window. Returns `true` for matching window.
+///* ```timeout``` - Timeout in milliseconds. If `None`, waits indefinitely.
+///
+///# Return
+///
+///* ```Ok(Some)``` - Handle of the first matching window.
+///* ```Ok(None)``` - Timeout expired.
+///* ```Err``` - Error reason.
+pub fn wait_by<T: FnMut(HWND) -> bool>(predicate: T, timeout: Option<c_ulong>) -> Result<Option<HWND>> {
+    wait_with(WinEventBuilder::new(), predicate, timeout)
+}
+
+///Waits for visible top-level window of the process.
+///
+///See [wait_by()](fn.wait_by.html) for details.
+///
+///# Parameters
+///
+///* ```pid``` - Pid of the process
+///* ```timeout``` - Timeout in milliseconds. If `None`, waits indefinitely.
+///
+///# Return
+///
+///* ```Ok(Some)``` - Handle of the process's window.
+///* ```Ok(None)``` - Timeout expired.
+///* ```Err``` - Error reason.
+pub fn wait_by_pid(pid: u32, timeout: Option<c_ulong>) -> Result<Option<HWND>> {
+    wait_with(WinEventBuilder::new().process(pid), |handle| {
+        let (process_pid, _) = get_thread_process_id(handle);
+        process_pid == pid && is_visible(handle)
+    }, timeout)
+}
+
+fn wait_with<T: FnMut(HWND) -> bool>(events: WinEventBuilder, mut predicate: T, timeout: Option<c_ulong>) -> Result<Option<HWND>> {
+    const MIN_DELAY: Duration = Duration::from_millis(10);
+    const MAX_DELAY: Duration = Duration::from_millis(250);
+
+    let deadline = timeout.map(|timeout| Instant::now() + Duration::from_millis(timeout.into()));
+
+    let (sender, receiver) = mpsc::channel();
+    //Watcher must be installed before first look up, otherwise window can be missed.
+    let _watcher = events.created()
+                         .event(WinEventKind::Other(EVENT_OBJECT_SHOW))
+                         .name_changed()
+                         .spawn(move |event| if event.is_window() {
+                             let _ = sender.send(());
+                         });
+
+    let mut delay = MIN_DELAY;
+    loop {
+        let mut found_window = None;
+        enum_by_until(None, |handle| match predicate(handle) {
+            true => {
+                found_window = Some(handle);
+                0
+            },
+            false => 1,
+        })?;
+
+        if found_window.is_some() {
+            return Ok(found_window);
+        }
+
+        let wait = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(left) if left > Duration::from_millis(0) => cmp::min(delay, left),
+                _ => return Ok(None),
+            },
+            None => delay,
+        };
+
+        match receiver.recv_timeout(wait) {
+            Ok(()) => while receiver.try_recv().is_ok() {},
+            Err(mpsc::RecvTimeoutError::Timeout) => delay = cmp::min(delay * 2, MAX_DELAY),
+            //No hooks, only polling is possible.
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                thread::sleep(wait);
+                delay = cmp::min(delay * 2, MAX_DELAY);
+            },
+        }
+    }
+}
+
 ///Retrieves list of handles to specific window class
 ///
 ///# Parameters
@@ -358,12 +680,134 @@ pub fn find_child<T: AsRef<ffi::OsStr>>(class_name: T,
     Ok(result)
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Describes flags of `SendMessageTimeout`
+pub struct SendMessageFlags {
+    inner: UINT
+}
+
+impl SendMessageFlags {
+    ///Creates new instance with `SMTO_NORMAL`.
+    ///
+    ///Calling thread can process other requests while waiting.
+    pub fn new() -> Self {
+        Self {
+            inner: SMTO_NORMAL
+        }
+    }
+
+    ///Prevents calling thread from processing any other requests while waiting. `SMTO_BLOCK`
+    pub fn block(mut self) -> Self {
+        self.inner |= SMTO_BLOCK;
+        self
+    }
+
+    ///Returns without waiting for timeout, if window's thread appears to be hung. `SMTO_ABORTIFHUNG`
+    pub fn abort_if_hung(mut self) -> Self {
+        self.inner |= SMTO_ABORTIFHUNG;
+        self
+    }
+
+    ///Timeout doesn't expire as long as window's thread is not hung. `SMTO_NOTIMEOUTIFNOTHUNG`
+    pub fn no_timeout_if_not_hung(mut self) -> Self {
+        self.inner |= SMTO_NOTIMEOUTIFNOTHUNG;
+        self
+    }
+
+    ///Returns error if window is destroyed or its thread terminated while waiting. `SMTO_ERRORONEXIT`
+    pub fn error_on_exit(mut self) -> Self {
+        self.inner |= SMTO_ERRORONEXIT;
+        self
+    }
+
+    #[inline]
+    ///Returns raw flags.
+    pub fn raw(&self) -> UINT {
+        self.inner
+    }
+}
+
+impl Default for SendMessageFlags {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Error of [send_message_timeout()](fn.send_message_timeout.html).
+pub enum SendError {
+    ///Window didn't respond in time or is hung.
+    Timeout,
+    ///Other error, e.g. invalid window.
+    Os(ErrorCode),
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendError::Timeout => fmt.write_str("Window didn't respond in time"),
+            SendError::Os(error) => fmt::Display::fmt(error, fmt),
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
+
+impl From<SendError> for ErrorCode {
+    fn from(error: SendError) -> Self {
+        match error {
+            SendError::Timeout => ErrorCode::new_system(ERROR_TIMEOUT as _),
+            SendError::Os(error) => error,
+        }
+    }
+}
+
+///Sends message to a window, waiting no longer than specified timeout.
+///
+///# Parameters
+///
+///* ```window``` - Handle to the window for which to send.
+///* ```msg_type``` - Type of message. See WinAPI docs.
+///* ```wParam``` - Additional message specific parameter.
+///* ```lParam``` - Additional message specific parameter.
+///* ```timeout``` - Timeout in milliseconds.
+///* ```flags``` - Flags of how to send message.
+///
+///# Return
+///
+///* ```Ok``` - Result of message processing.
+///* ```Err``` - `SendError::Timeout` if window didn't respond in time, otherwise error reason.
+pub fn send_message_timeout(window: HWND,
+                            msg_type: UINT,
+                            w_param: WPARAM,
+                            l_param: LPARAM,
+                            timeout: UINT,
+                            flags: SendMessageFlags) -> core::result::Result<LRESULT, SendError> {
+    let mut result: ULONG_PTR = 0;
+
+    unsafe {
+        SetLastErrorEx(0, 0);
+        if SendMessageTimeoutW(window, msg_type, w_param, l_param, flags.raw(), timeout, &mut result as PDWORD_PTR) == 0 {
+            let error = utils::get_last_error();
+            //Hung window is not always reported as error.
+            return match error.raw_code() as DWORD {
+                0 | ERROR_TIMEOUT => Err(SendError::Timeout),
+                _ => Err(SendError::Os(error)),
+            };
+        }
+    }
+
+    Ok(result as LRESULT)
+}
+
 ///Sends message to a window.
 ///
 ///# Note
 ///All messages that this function sends are blocking.
 ///
-///You can specify timeout for how long to block.
+///You can specify timeout for how long to block, in which case message is sent with `SMTO_BLOCK`.
+///Use [send_message_timeout()](fn.send_message_timeout.html) to specify other flags.
 ///
 ///# Parameters
 ///
@@ -376,21 +820,14 @@ pub fn find_child<T: AsRef<ffi::OsStr>>(class_name: T,
 ///# Return
 ///
 ///* ```Ok``` - Message has been sent  successfully.
-///* ```Err``` - Error reason. Relevant only to message with timeout.
+///* ```Err``` - Error reason. Relevant only to message with timeout. `ERROR_TIMEOUT` if window didn't respond in time.
 pub fn send_message(window: HWND,
                     msg_type: UINT,
                     w_param: WPARAM,
                     l_param: LPARAM,
                     timeout: Option<UINT>) -> Result<LRESULT> {
     if let Some(timeout) = timeout {
-        unsafe {
-            let mut result: ULONG_PTR = 0;
-            let result_ptr = &mut result as PDWORD_PTR;
-            if SendMessageTimeoutW(window, msg_type, w_param, l_param, SMTO_BLOCK, timeout, result_ptr) == 0 {
-                return Err(utils::get_last_error());
-            }
-            Ok(result as LRESULT)
-        }
+        send_message_timeout(window, msg_type, w_param, l_param, timeout, SendMessageFlags::new().block()).map_err(ErrorCode::from)
     }
     else {
         unsafe {
@@ -399,6 +836,198 @@ pub fn send_message(window: HWND,
     }
 }
 
+#[inline]
+///Determines whether window is not responding.
+///
+///Window is considered hung if it doesn't process input for more than 5 seconds.
+pub fn is_hung(window: HWND) -> bool {
+    unsafe {
+        IsHungAppWindow(window) != 0
+    }
+}
+
+fn add_ex_style(window: HWND, style: DWORD) -> Result<()> {
+    let current = get_ex_style(window);
+    if current & style == style {
+        return Ok(());
+    }
+
+    //Previous style of 0 cannot be distinguished from error otherwise.
+    unsafe { SetLastErrorEx(0, 0) };
+    match unsafe { SetWindowLongW(window, GWL_EXSTYLE, (current | style) as LONG) } {
+        0 => match utils::get_last_error() {
+            error if error.raw_code() != 0 => Err(error),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+///Sets attributes of layered window.
+///
+///Window is made layered, if it is not yet.
+///
+///# Parameters
+///
+///* ```window``` - A handle to the window.
+///* ```color_key``` - Color that becomes fully transparent, if any. Use [RGB](../../sys/fn.RGB.html) to create it.
+///* ```alpha``` - Opacity of whole window, if any. `0` is fully transparent and `255` is opaque.
+///
+///# Return
+///
+///* ```Ok``` - Success.
+///* ```Err``` - Error reason.
+pub fn set_layered_attributes(window: HWND, color_key: Option<COLORREF>, alpha: Option<BYTE>) -> Result<()> {
+    add_ex_style(window, WS_EX_LAYERED)?;
+
+    let mut flags = 0;
+    if color_key.is_some() {
+        flags |= LWA_COLORKEY;
+    }
+    if alpha.is_some() {
+        flags |= LWA_ALPHA;
+    }
+
+    match unsafe { SetLayeredWindowAttributes(window, color_key.unwrap_or(0), alpha.unwrap_or(255), flags) } {
+        0 => Err(utils::get_last_error()),
+        _ => Ok(()),
+    }
+}
+
+#[inline]
+///Sets opacity of window, making it layered.
+///
+///`0` is fully transparent and `255` is opaque.
+///
+///Color key, if any, is reset. Use [set_layered_attributes](fn.set_layered_attributes.html) to set both.
+pub fn set_opacity(window: HWND, alpha: BYTE) -> Result<()> {
+    set_layered_attributes(window, None, Some(alpha))
+}
+
+#[inline]
+///Makes pixels of specified color fully transparent, making window layered.
+///
+///Opacity, if any, is reset. Use [set_layered_attributes](fn.set_layered_attributes.html) to set both.
+pub fn set_color_key(window: HWND, color_key: COLORREF) -> Result<()> {
+    set_layered_attributes(window, Some(color_key), None)
+}
+
+///Retrieves opacity of layered window.
+///
+///# Return
+///
+///* ```Ok(Some)``` - Opacity.
+///* ```Ok(None)``` - Window is not layered or has no opacity set.
+///* ```Err``` - Error reason.
+pub fn get_opacity(window: HWND) -> Result<Option<BYTE>> {
+    if get_ex_style(window) & WS_EX_LAYERED == 0 {
+        return Ok(None);
+    }
+
+    let mut key = 0;
+    let mut alpha = 0;
+    let mut flags = 0;
+
+    match unsafe { GetLayeredWindowAttributes(window, &mut key, &mut alpha, &mut flags) } {
+        0 => Err(utils::get_last_error()),
+        _ if flags & LWA_ALPHA == 0 => Ok(None),
+        _ => Ok(Some(alpha)),
+    }
+}
+
+fn dwm_get_attribute<T: Copy>(window: HWND, attribute: DWORD, mut value: T) -> Result<T> {
+    match unsafe { DwmGetWindowAttribute(window, attribute, &mut value as *mut T as PVOID, mem::size_of::<T>() as DWORD) } {
+        S_OK => Ok(value),
+        error => Err(utils::from_hresult(error)),
+    }
+}
+
+fn dwm_set_attribute<T: Copy>(window: HWND, attribute: DWORD, value: T) -> Result<()> {
+    match unsafe { DwmSetWindowAttribute(window, attribute, &value as *const T as LPCVOID, mem::size_of::<T>() as DWORD) } {
+        S_OK => Ok(()),
+        error => Err(utils::from_hresult(error)),
+    }
+}
+
+///Retrieves window's rectangle as rendered by DWM.
+///
+///Unlike [get_rect](fn.get_rect.html), it excludes invisible resize borders.
+pub fn get_extended_frame_bounds(window: HWND) -> Result<RECT> {
+    dwm_get_attribute(window, DWMWA_EXTENDED_FRAME_BOUNDS, RECT {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    })
+}
+
+///Retrieves reason why window is cloaked, i.e. hidden by DWM while remaining visible.
+///
+///# Return
+///
+///* ```Ok``` - Combination of `DWM_CLOAKED_*` flags, `0` if window is not cloaked.
+///* ```Err``` - Error reason.
+pub fn get_cloaked(window: HWND) -> Result<DWORD> {
+    dwm_get_attribute(window, DWMWA_CLOAKED, 0 as DWORD)
+}
+
+#[inline]
+///Determines whether window is cloaked, e.g. suspended UWP application or window on another virtual desktop.
+///
+///Returns `false` if DWM cannot report it.
+pub fn is_cloaked(window: HWND) -> bool {
+    get_cloaked(window).map(|cloaked| cloaked != 0).unwrap_or(false)
+}
+
+#[inline]
+///Determines whether window is actually shown to user.
+///
+///Suitable as filter for [enum_by](fn.enum_by.html) results, as cloaked windows are reported as visible.
+pub fn is_visible_and_not_cloaked(window: HWND) -> bool {
+    is_visible(window) && !is_cloaked(window)
+}
+
+#[inline]
+///Enables or disables dark mode of window's title bar.
+///
+///Supported since Windows 10 20H1.
+pub fn set_dark_mode(window: HWND, is_enabled: bool) -> Result<()> {
+    dwm_set_attribute(window, DWMWA_USE_IMMERSIVE_DARK_MODE, is_enabled as BOOL)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Preference of rounding window's corners.
+pub enum CornerPreference {
+    ///Let system decide.
+    Default,
+    ///Never round.
+    DoNotRound,
+    ///Round if appropriate.
+    Round,
+    ///Round with small radius if appropriate.
+    RoundSmall,
+}
+
+impl CornerPreference {
+    ///Returns raw `DWMWCP_*` value.
+    pub fn raw(&self) -> DWORD {
+        match self {
+            CornerPreference::Default => DWMWCP_DEFAULT,
+            CornerPreference::DoNotRound => DWMWCP_DONOTROUND,
+            CornerPreference::Round => DWMWCP_ROUND,
+            CornerPreference::RoundSmall => DWMWCP_ROUNDSMALL,
+        }
+    }
+}
+
+#[inline]
+///Sets preference of rounding window's corners.
+///
+///Supported since Windows 11.
+pub fn set_corner_preference(window: HWND, preference: CornerPreference) -> Result<()> {
+    dwm_set_attribute(window, DWMWA_WINDOW_CORNER_PREFERENCE, preference.raw())
+}
+
 ///Button click message type
 const BM_CLICK: c_uint = 0x00F5;
 
@@ -434,6 +1063,9 @@ pub fn send_set_text<T: AsRef<ffi::OsStr>>(window: HWND, text: T) -> bool {
 
 ///Sends get text message to a window
 ///
+///Uses default [TextOptions](struct.TextOptions.html).
+///For details see [get_text_with()](fn.get_text_with.html)
+///
 ///# Parameters
 ///
 ///* ```window``` - Handle to the window for which to send.
@@ -441,23 +1073,9 @@ pub fn send_set_text<T: AsRef<ffi::OsStr>>(window: HWND, text: T) -> bool {
 ///# Return
 ///
 ///* ```String``` - Window's text.
-///* ```None``` - If there is no text.
+///* ```None``` - If there is no text or text cannot be retrieved.
 pub fn send_get_text(window: HWND) -> Option<String> {
-    //Does not include null char
-    let buf_len = send_message(window, WM_GETTEXTLENGTH, 0, 0, None).unwrap();
-
-    if buf_len == 0 {
-        return None
-    }
-
-    let buf_len = buf_len + 1;
-
-    let text: Vec<u16> = vec![0; buf_len as usize];
-    let text_ptr = text.as_ptr() as LPARAM;
-    //Does not include null char
-    let buf_len = send_message(window, WM_GETTEXT, buf_len as WPARAM, text_ptr, None).unwrap() as usize;
-
-    Some(String::from_utf16_lossy(&text[..buf_len]))
+    get_text_with(window, &TextOptions::default()).unwrap_or(None)
 }
 
 ///Sends sys command to a window.
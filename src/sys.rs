@@ -4,12 +4,26 @@
 pub use core::ffi::*;
 
 pub type BYTE = c_uchar;
+pub type WORD = c_ushort;
+pub type SHORT = c_short;
 pub type BOOL = c_int;
 pub type BOOLEAN = BYTE;
 pub type PVOID = *mut c_void;
 pub type HANDLE = *mut c_void;
 pub type PHANDLE = *mut HANDLE;
 pub type HMENU = *mut c_void;
+pub type HBITMAP = *mut c_void;
+pub type HDC = *mut c_void;
+pub type HGDIOBJ = *mut c_void;
+pub type HMONITOR = *mut c_void;
+pub type DPI_AWARENESS_CONTEXT = *mut c_void;
+pub type HRESULT = c_long;
+pub type HICON = *mut c_void;
+pub type LPCSTR = *const c_char;
+pub type FARPROC = *mut c_void;
+pub type COLORREF = DWORD;
+pub type HHOOK = *mut c_void;
+pub type HWINEVENTHOOK = *mut c_void;
 pub type HWND = *mut c_void;
 pub type HINSTANCE = *mut c_void;
 pub type HMODULE = HINSTANCE;
@@ -29,6 +43,7 @@ pub type LPVOID = *mut c_void;
 pub type LPCVOID = *const c_void;
 
 pub type ULONG_PTR = usize;
+pub type LONG_PTR = isize;
 pub type PDWORD_PTR = *mut ULONG_PTR;
 pub type SIZE_T = ULONG_PTR;
 
@@ -124,8 +139,64 @@ pub const MB_ICONMASK: UINT = 0x000000F0;
 pub const MB_DEFMASK: UINT = 0x00000F00;
 pub const MB_MODEMASK: UINT = 0x00003000;
 pub const MB_MISCMASK: UINT = 0x0000C000;
+pub const MB_TIMEDOUT: c_int = 32000;
 
+pub const IDOK: c_int = 1;
+pub const IDCANCEL: c_int = 2;
+pub const IDABORT: c_int = 3;
+pub const IDRETRY: c_int = 4;
+pub const IDIGNORE: c_int = 5;
+pub const IDYES: c_int = 6;
+pub const IDNO: c_int = 7;
+pub const IDHELP: c_int = 9;
+pub const IDTRYAGAIN: c_int = 10;
+pub const IDCONTINUE: c_int = 11;
+
+pub const SMTO_NORMAL: UINT = 0x0000;
 pub const SMTO_BLOCK: UINT = 0x0001;
+pub const SMTO_ABORTIFHUNG: UINT = 0x0002;
+pub const SMTO_NOTIMEOUTIFNOTHUNG: UINT = 0x0008;
+pub const SMTO_ERRORONEXIT: UINT = 0x0020;
+
+pub const ERROR_INVALID_HANDLE: DWORD = 6;
+pub const ERROR_INVALID_PARAMETER: DWORD = 87;
+pub const ERROR_IO_PENDING: DWORD = 997;
+pub const ERROR_TIMEOUT: DWORD = 1460;
+pub const PM_NOREMOVE: UINT = 0x0000;
+pub const PM_REMOVE: UINT = 0x0001;
+
+pub const GWL_STYLE: c_int = -16;
+pub const GWL_EXSTYLE: c_int = -20;
+
+pub const WS_EX_LAYERED: DWORD = 0x00080000;
+pub const LWA_COLORKEY: DWORD = 0x00000001;
+pub const LWA_ALPHA: DWORD = 0x00000002;
+
+pub const DWMWA_EXTENDED_FRAME_BOUNDS: DWORD = 9;
+pub const DWMWA_CLOAKED: DWORD = 14;
+pub const DWMWA_USE_IMMERSIVE_DARK_MODE: DWORD = 20;
+pub const DWMWA_WINDOW_CORNER_PREFERENCE: DWORD = 33;
+pub const DWM_CLOAKED_APP: DWORD = 0x00000001;
+pub const DWM_CLOAKED_SHELL: DWORD = 0x00000002;
+pub const DWM_CLOAKED_INHERITED: DWORD = 0x00000004;
+pub const DWMWCP_DEFAULT: DWORD = 0;
+pub const DWMWCP_DONOTROUND: DWORD = 1;
+pub const DWMWCP_ROUND: DWORD = 2;
+pub const DWMWCP_ROUNDSMALL: DWORD = 3;
+
+#[inline]
+pub const fn RGB(red: BYTE, green: BYTE, blue: BYTE) -> COLORREF {
+    red as COLORREF | (green as COLORREF) << 8 | (blue as COLORREF) << 16
+}
+
+pub const GW_HWNDNEXT: UINT = 2;
+pub const GW_CHILD: UINT = 5;
+
+pub const INFINITE: DWORD = 0xFFFFFFFF;
+pub const WAIT_TIMEOUT: DWORD = 258;
+pub const WAIT_FAILED: DWORD = 0xFFFFFFFF;
+pub const WM_QUIT: UINT = 0x0012;
+pub const WM_COMMAND: UINT = 0x0111;
 pub const WM_SYSCOMMAND: UINT = 0x0112;
 pub const WM_GETTEXT: UINT = 0x000D;
 pub const WM_GETTEXTLENGTH: UINT = 0x000E;
@@ -226,6 +297,170 @@ pub const WM_PENWINFIRST: UINT = 0x0380;
 pub const WM_PENWINLAST: UINT = 0x038F;
 pub const WM_APP: UINT = 0x8000;
 pub const WM_USER: UINT = 0x0400;
+pub const MOD_ALT: UINT = 0x0001;
+pub const MOD_CONTROL: UINT = 0x0002;
+pub const MOD_SHIFT: UINT = 0x0004;
+pub const MOD_WIN: UINT = 0x0008;
+pub const MOD_NOREPEAT: UINT = 0x4000;
+
+pub const VK_LBUTTON: UINT = 0x01;
+pub const VK_RBUTTON: UINT = 0x02;
+pub const VK_CANCEL: UINT = 0x03;
+pub const VK_MBUTTON: UINT = 0x04;
+pub const VK_XBUTTON1: UINT = 0x05;
+pub const VK_XBUTTON2: UINT = 0x06;
+pub const VK_BACK: UINT = 0x08;
+pub const VK_TAB: UINT = 0x09;
+pub const VK_CLEAR: UINT = 0x0C;
+pub const VK_RETURN: UINT = 0x0D;
+pub const VK_SHIFT: UINT = 0x10;
+pub const VK_CONTROL: UINT = 0x11;
+pub const VK_MENU: UINT = 0x12;
+pub const VK_PAUSE: UINT = 0x13;
+pub const VK_CAPITAL: UINT = 0x14;
+pub const VK_ESCAPE: UINT = 0x1B;
+pub const VK_SPACE: UINT = 0x20;
+pub const VK_PRIOR: UINT = 0x21;
+pub const VK_NEXT: UINT = 0x22;
+pub const VK_END: UINT = 0x23;
+pub const VK_HOME: UINT = 0x24;
+pub const VK_LEFT: UINT = 0x25;
+pub const VK_UP: UINT = 0x26;
+pub const VK_RIGHT: UINT = 0x27;
+pub const VK_DOWN: UINT = 0x28;
+pub const VK_SNAPSHOT: UINT = 0x2C;
+pub const VK_INSERT: UINT = 0x2D;
+pub const VK_DELETE: UINT = 0x2E;
+pub const VK_LWIN: UINT = 0x5B;
+pub const VK_RWIN: UINT = 0x5C;
+pub const VK_APPS: UINT = 0x5D;
+pub const VK_NUMPAD0: UINT = 0x60;
+pub const VK_MULTIPLY: UINT = 0x6A;
+pub const VK_ADD: UINT = 0x6B;
+pub const VK_SEPARATOR: UINT = 0x6C;
+pub const VK_SUBTRACT: UINT = 0x6D;
+pub const VK_DECIMAL: UINT = 0x6E;
+pub const VK_DIVIDE: UINT = 0x6F;
+pub const VK_F1: UINT = 0x70;
+pub const VK_F24: UINT = 0x87;
+pub const VK_NUMLOCK: UINT = 0x90;
+pub const VK_SCROLL: UINT = 0x91;
+pub const VK_LSHIFT: UINT = 0xA0;
+pub const VK_RSHIFT: UINT = 0xA1;
+pub const VK_LCONTROL: UINT = 0xA2;
+pub const VK_RCONTROL: UINT = 0xA3;
+pub const VK_LMENU: UINT = 0xA4;
+pub const VK_RMENU: UINT = 0xA5;
+pub const VK_VOLUME_MUTE: UINT = 0xAD;
+pub const VK_VOLUME_DOWN: UINT = 0xAE;
+pub const VK_VOLUME_UP: UINT = 0xAF;
+pub const VK_MEDIA_NEXT_TRACK: UINT = 0xB0;
+pub const VK_MEDIA_PREV_TRACK: UINT = 0xB1;
+pub const VK_MEDIA_STOP: UINT = 0xB2;
+pub const VK_MEDIA_PLAY_PAUSE: UINT = 0xB3;
+pub const VK_OEM_1: UINT = 0xBA;
+pub const VK_OEM_PLUS: UINT = 0xBB;
+pub const VK_OEM_COMMA: UINT = 0xBC;
+pub const VK_OEM_MINUS: UINT = 0xBD;
+pub const VK_OEM_PERIOD: UINT = 0xBE;
+pub const VK_OEM_2: UINT = 0xBF;
+pub const VK_OEM_3: UINT = 0xC0;
+pub const VK_OEM_4: UINT = 0xDB;
+pub const VK_OEM_5: UINT = 0xDC;
+pub const VK_OEM_6: UINT = 0xDD;
+pub const VK_OEM_7: UINT = 0xDE;
+
+pub const INPUT_MOUSE: DWORD = 0;
+pub const INPUT_KEYBOARD: DWORD = 1;
+pub const INPUT_HARDWARE: DWORD = 2;
+
+pub const KEYEVENTF_EXTENDEDKEY: DWORD = 0x0001;
+pub const KEYEVENTF_KEYUP: DWORD = 0x0002;
+pub const KEYEVENTF_UNICODE: DWORD = 0x0004;
+pub const KEYEVENTF_SCANCODE: DWORD = 0x0008;
+
+pub const MOUSEEVENTF_MOVE: DWORD = 0x0001;
+pub const MOUSEEVENTF_LEFTDOWN: DWORD = 0x0002;
+pub const MOUSEEVENTF_LEFTUP: DWORD = 0x0004;
+pub const MOUSEEVENTF_RIGHTDOWN: DWORD = 0x0008;
+pub const MOUSEEVENTF_RIGHTUP: DWORD = 0x0010;
+pub const MOUSEEVENTF_MIDDLEDOWN: DWORD = 0x0020;
+pub const MOUSEEVENTF_MIDDLEUP: DWORD = 0x0040;
+pub const MOUSEEVENTF_XDOWN: DWORD = 0x0080;
+pub const MOUSEEVENTF_XUP: DWORD = 0x0100;
+pub const MOUSEEVENTF_WHEEL: DWORD = 0x0800;
+pub const MOUSEEVENTF_HWHEEL: DWORD = 0x01000;
+pub const MOUSEEVENTF_MOVE_NOCOALESCE: DWORD = 0x2000;
+pub const MOUSEEVENTF_VIRTUALDESK: DWORD = 0x4000;
+pub const MOUSEEVENTF_ABSOLUTE: DWORD = 0x8000;
+
+pub const WHEEL_DELTA: c_int = 120;
+pub const XBUTTON1: WORD = 0x0001;
+pub const XBUTTON2: WORD = 0x0002;
+
+pub const SM_CXSCREEN: c_int = 0;
+pub const SM_CYSCREEN: c_int = 1;
+pub const SM_XVIRTUALSCREEN: c_int = 76;
+pub const SM_YVIRTUALSCREEN: c_int = 77;
+pub const SM_CXVIRTUALSCREEN: c_int = 78;
+pub const SM_CYVIRTUALSCREEN: c_int = 79;
+
+pub const WH_CBT: c_int = 5;
+pub const HCBT_ACTIVATE: c_int = 5;
+pub const WH_KEYBOARD_LL: c_int = 13;
+pub const WH_MOUSE_LL: c_int = 14;
+pub const HC_ACTION: c_int = 0;
+
+pub const LLKHF_EXTENDED: DWORD = 0x00000001;
+pub const LLKHF_LOWER_IL_INJECTED: DWORD = 0x00000002;
+pub const LLKHF_INJECTED: DWORD = 0x00000010;
+pub const LLKHF_ALTDOWN: DWORD = 0x00000020;
+pub const LLKHF_UP: DWORD = 0x00000080;
+pub const LLMHF_INJECTED: DWORD = 0x00000001;
+pub const LLMHF_LOWER_IL_INJECTED: DWORD = 0x00000002;
+
+pub const WM_KEYDOWN: UINT = 0x0100;
+pub const WM_KEYUP: UINT = 0x0101;
+pub const WM_CHAR: UINT = 0x0102;
+pub const WM_SYSKEYDOWN: UINT = 0x0104;
+pub const WM_SYSKEYUP: UINT = 0x0105;
+pub const WM_MOUSEMOVE: UINT = 0x0200;
+pub const WM_LBUTTONDOWN: UINT = 0x0201;
+pub const WM_LBUTTONUP: UINT = 0x0202;
+pub const WM_LBUTTONDBLCLK: UINT = 0x0203;
+pub const WM_RBUTTONDOWN: UINT = 0x0204;
+pub const WM_RBUTTONUP: UINT = 0x0205;
+pub const WM_RBUTTONDBLCLK: UINT = 0x0206;
+pub const WM_MBUTTONDOWN: UINT = 0x0207;
+pub const WM_MBUTTONUP: UINT = 0x0208;
+pub const WM_MBUTTONDBLCLK: UINT = 0x0209;
+pub const WM_MOUSEWHEEL: UINT = 0x020A;
+pub const WM_XBUTTONDOWN: UINT = 0x020B;
+pub const WM_XBUTTONUP: UINT = 0x020C;
+pub const WM_XBUTTONDBLCLK: UINT = 0x020D;
+pub const WM_MOUSEHWHEEL: UINT = 0x020E;
+
+pub const EVENT_MIN: DWORD = 0x00000001;
+pub const EVENT_MAX: DWORD = 0x7FFFFFFF;
+pub const EVENT_SYSTEM_FOREGROUND: DWORD = 0x0003;
+pub const EVENT_SYSTEM_MINIMIZESTART: DWORD = 0x0016;
+pub const EVENT_SYSTEM_MINIMIZEEND: DWORD = 0x0017;
+pub const EVENT_OBJECT_CREATE: DWORD = 0x8000;
+pub const EVENT_OBJECT_DESTROY: DWORD = 0x8001;
+pub const EVENT_OBJECT_SHOW: DWORD = 0x8002;
+pub const EVENT_OBJECT_HIDE: DWORD = 0x8003;
+pub const EVENT_OBJECT_LOCATIONCHANGE: DWORD = 0x800B;
+pub const EVENT_OBJECT_NAMECHANGE: DWORD = 0x800C;
+pub const WINEVENT_OUTOFCONTEXT: DWORD = 0x0000;
+pub const WINEVENT_SKIPOWNTHREAD: DWORD = 0x0001;
+pub const WINEVENT_SKIPOWNPROCESS: DWORD = 0x0002;
+pub const WINEVENT_INCONTEXT: DWORD = 0x0004;
+pub const OBJID_WINDOW: LONG = 0x00000000;
+pub const OBJID_CURSOR: LONG = -9;
+pub const OBJID_CARET: LONG = -8;
+pub const OBJID_CLIENT: LONG = -4;
+pub const CHILDID_SELF: LONG = 0;
+
 pub const CW_USEDEFAULT: c_int = -2147483648i32;
 
 pub const HWND_MESSAGE: HWND = -3isize as HWND;
@@ -236,6 +471,45 @@ pub const FILE_ATTRIBUTE_READONLY: DWORD = 0x00000001;
 pub const MEM_COMMIT: DWORD = 0x1000;
 pub const MEM_FREE: DWORD = 0x10000;
 pub const MEM_RESERVE: DWORD = 0x2000;
+pub const MEM_RELEASE: DWORD = 0x8000;
+
+pub const PAGE_READWRITE: DWORD = 0x04;
+
+pub const PROCESS_VM_OPERATION: DWORD = 0x0008;
+pub const PROCESS_VM_READ: DWORD = 0x0010;
+pub const PROCESS_VM_WRITE: DWORD = 0x0020;
+pub const PROCESS_QUERY_LIMITED_INFORMATION: DWORD = 0x1000;
+
+pub const LVM_FIRST: UINT = 0x1000;
+pub const LVM_GETITEMCOUNT: UINT = LVM_FIRST + 4;
+pub const LVM_GETCOLUMNW: UINT = LVM_FIRST + 95;
+pub const LVM_GETITEMTEXTW: UINT = LVM_FIRST + 115;
+pub const LVIF_TEXT: UINT = 0x0001;
+pub const LVCF_WIDTH: UINT = 0x0002;
+pub const LVCF_TEXT: UINT = 0x0004;
+
+pub const TV_FIRST: UINT = 0x1100;
+pub const TVM_GETCOUNT: UINT = TV_FIRST + 5;
+pub const TVM_GETNEXTITEM: UINT = TV_FIRST + 10;
+pub const TVM_GETITEMW: UINT = TV_FIRST + 62;
+pub const TVGN_ROOT: WPARAM = 0x0000;
+pub const TVGN_NEXT: WPARAM = 0x0001;
+pub const TVGN_CHILD: WPARAM = 0x0004;
+pub const TVIF_TEXT: UINT = 0x0001;
+pub const TVIF_HANDLE: UINT = 0x0010;
+pub const TVIF_CHILDREN: UINT = 0x0040;
+
+pub const LB_ERR: LRESULT = -1;
+pub const LB_GETTEXT: UINT = 0x0189;
+pub const LB_GETTEXTLEN: UINT = 0x018A;
+pub const LB_GETCOUNT: UINT = 0x018B;
+pub const LB_GETCURSEL: UINT = 0x0188;
+
+pub const CB_ERR: LRESULT = -1;
+pub const CB_GETCOUNT: UINT = 0x0146;
+pub const CB_GETCURSEL: UINT = 0x0147;
+pub const CB_GETLBTEXT: UINT = 0x0148;
+pub const CB_GETLBTEXTLEN: UINT = 0x0149;
 
 pub const WT_EXECUTEINTIMERTHREAD: ULONG = 0x00000020;
 pub const WT_EXECUTEINPERSISTENTTHREAD: ULONG = 0x00000080;
@@ -244,7 +518,12 @@ pub const WT_EXECUTEONLYONCE: ULONG = 0x00000008;
 pub const WT_TRANSFER_IMPERSONATION: ULONG = 0x00000100;
 
 pub type WAITORTIMERCALLBACK = Option<unsafe extern "system" fn(_: PVOID, _: BOOLEAN)>;
+pub type HOOKPROC = Option<unsafe extern "system" fn(_: c_int, _: WPARAM, _: LPARAM) -> LRESULT>;
+pub type WINEVENTPROC = Option<unsafe extern "system" fn(_: HWINEVENTHOOK, _: DWORD, _: HWND, _: LONG, _: LONG, _: DWORD, _: DWORD)>;
 pub type WNDENUMPROC = Option<unsafe extern "system" fn(_: HWND, _: LPARAM) -> BOOL>;
+pub type PFTASKDIALOGCALLBACK = Option<unsafe extern "system" fn(_: HWND, _: UINT, _: WPARAM, _: LPARAM, _: LONG_PTR) -> HRESULT>;
+pub type BFFCALLBACK = Option<unsafe extern "system" fn(_: HWND, _: UINT, _: LPARAM, _: LPARAM) -> c_int>;
+pub type MONITORENUMPROC = Option<unsafe extern "system" fn(_: HMONITOR, _: HDC, _: *mut RECT, _: LPARAM) -> BOOL>;
 
 pub const TOKEN_QUERY: DWORD = 0x0008;
 
@@ -271,6 +550,15 @@ pub struct POINT {
     pub y: LONG,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RECT {
+    pub left: LONG,
+    pub top: LONG,
+    pub right: LONG,
+    pub bottom: LONG,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct MSG {
@@ -304,6 +592,531 @@ pub struct WIN32_FIND_DATAW {
     pub cAlternateFileName: [WCHAR; 14],
 }
 
+pub const MIIM_STATE: UINT = 0x00000001;
+pub const MIIM_ID: UINT = 0x00000002;
+pub const MIIM_SUBMENU: UINT = 0x00000004;
+pub const MIIM_STRING: UINT = 0x00000040;
+pub const MIIM_FTYPE: UINT = 0x00000100;
+
+pub const MFT_STRING: UINT = 0x00000000;
+pub const MFT_BITMAP: UINT = 0x00000004;
+pub const MFT_OWNERDRAW: UINT = 0x00000100;
+pub const MFT_SEPARATOR: UINT = 0x00000800;
+
+pub const MF_GRAYED: UINT = 0x00000001;
+pub const MF_DISABLED: UINT = 0x00000002;
+pub const MFS_CHECKED: UINT = 0x00000008;
+pub const MFS_HILITE: UINT = 0x00000080;
+pub const MFS_DEFAULT: UINT = 0x00001000;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MENUITEMINFOW {
+    pub cbSize: UINT,
+    pub fMask: UINT,
+    pub fType: UINT,
+    pub fState: UINT,
+    pub wID: UINT,
+    pub hSubMenu: HMENU,
+    pub hbmpChecked: HBITMAP,
+    pub hbmpUnchecked: HBITMAP,
+    pub dwItemData: ULONG_PTR,
+    pub dwTypeData: LPWSTR,
+    pub cch: UINT,
+    pub hbmpItem: HBITMAP,
+}
+
+pub const SRCCOPY: DWORD = 0x00CC0020;
+pub const CAPTUREBLT: DWORD = 0x40000000;
+pub const PW_CLIENTONLY: UINT = 0x00000001;
+pub const PW_RENDERFULLCONTENT: UINT = 0x00000002;
+pub const BI_RGB: DWORD = 0;
+pub const DIB_RGB_COLORS: UINT = 0;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BITMAPINFOHEADER {
+    pub biSize: DWORD,
+    pub biWidth: LONG,
+    pub biHeight: LONG,
+    pub biPlanes: WORD,
+    pub biBitCount: WORD,
+    pub biCompression: DWORD,
+    pub biSizeImage: DWORD,
+    pub biXPelsPerMeter: LONG,
+    pub biYPelsPerMeter: LONG,
+    pub biClrUsed: DWORD,
+    pub biClrImportant: DWORD,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RGBQUAD {
+    pub rgbBlue: u8,
+    pub rgbGreen: u8,
+    pub rgbRed: u8,
+    pub rgbReserved: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BITMAPINFO {
+    pub bmiHeader: BITMAPINFOHEADER,
+    pub bmiColors: [RGBQUAD; 1],
+}
+
+pub const S_OK: HRESULT = 0;
+pub const S_FALSE: HRESULT = 1;
+
+pub const USER_DEFAULT_SCREEN_DPI: UINT = 96;
+pub const MDT_EFFECTIVE_DPI: c_int = 0;
+pub const MONITOR_DEFAULTTONULL: DWORD = 0x00000000;
+pub const MONITOR_DEFAULTTOPRIMARY: DWORD = 0x00000001;
+pub const MONITOR_DEFAULTTONEAREST: DWORD = 0x00000002;
+pub const MONITORINFOF_PRIMARY: DWORD = 0x00000001;
+pub const CCHDEVICENAME: usize = 32;
+
+pub const DPI_AWARENESS_CONTEXT_UNAWARE: DPI_AWARENESS_CONTEXT = -1isize as DPI_AWARENESS_CONTEXT;
+pub const DPI_AWARENESS_CONTEXT_SYSTEM_AWARE: DPI_AWARENESS_CONTEXT = -2isize as DPI_AWARENESS_CONTEXT;
+pub const DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE: DPI_AWARENESS_CONTEXT = -3isize as DPI_AWARENESS_CONTEXT;
+pub const DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2: DPI_AWARENESS_CONTEXT = -4isize as DPI_AWARENESS_CONTEXT;
+pub const DPI_AWARENESS_CONTEXT_UNAWARE_GDISCALED: DPI_AWARENESS_CONTEXT = -5isize as DPI_AWARENESS_CONTEXT;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MONITORINFOEXW {
+    pub cbSize: DWORD,
+    pub rcMonitor: RECT,
+    pub rcWork: RECT,
+    pub dwFlags: DWORD,
+    pub szDevice: [WCHAR; CCHDEVICENAME],
+}
+
+pub const TDF_ENABLE_HYPERLINKS: c_int = 0x0001;
+pub const TDF_USE_HICON_MAIN: c_int = 0x0002;
+pub const TDF_USE_HICON_FOOTER: c_int = 0x0004;
+pub const TDF_ALLOW_DIALOG_CANCELLATION: c_int = 0x0008;
+pub const TDF_USE_COMMAND_LINKS: c_int = 0x0010;
+pub const TDF_USE_COMMAND_LINKS_NO_ICON: c_int = 0x0020;
+pub const TDF_EXPAND_FOOTER_AREA: c_int = 0x0040;
+pub const TDF_EXPANDED_BY_DEFAULT: c_int = 0x0080;
+pub const TDF_VERIFICATION_FLAG_CHECKED: c_int = 0x0100;
+pub const TDF_SHOW_PROGRESS_BAR: c_int = 0x0200;
+pub const TDF_SHOW_MARQUEE_PROGRESS_BAR: c_int = 0x0400;
+pub const TDF_CALLBACK_TIMER: c_int = 0x0800;
+pub const TDF_POSITION_RELATIVE_TO_WINDOW: c_int = 0x1000;
+pub const TDF_RTL_LAYOUT: c_int = 0x2000;
+pub const TDF_NO_DEFAULT_RADIO_BUTTON: c_int = 0x4000;
+pub const TDF_CAN_BE_MINIMIZED: c_int = 0x8000;
+pub const TDF_SIZE_TO_CONTENT: c_int = 0x01000000;
+
+pub const TDCBF_OK_BUTTON: c_int = 0x0001;
+pub const TDCBF_YES_BUTTON: c_int = 0x0002;
+pub const TDCBF_NO_BUTTON: c_int = 0x0004;
+pub const TDCBF_CANCEL_BUTTON: c_int = 0x0008;
+pub const TDCBF_RETRY_BUTTON: c_int = 0x0010;
+pub const TDCBF_CLOSE_BUTTON: c_int = 0x0020;
+
+pub const TD_WARNING_ICON: LPCWSTR = 0xFFFF as LPCWSTR;
+pub const TD_ERROR_ICON: LPCWSTR = 0xFFFE as LPCWSTR;
+pub const TD_INFORMATION_ICON: LPCWSTR = 0xFFFD as LPCWSTR;
+pub const TD_SHIELD_ICON: LPCWSTR = 0xFFFC as LPCWSTR;
+
+pub const TDN_CREATED: UINT = 0;
+pub const TDN_NAVIGATED: UINT = 1;
+pub const TDN_BUTTON_CLICKED: UINT = 2;
+pub const TDN_HYPERLINK_CLICKED: UINT = 3;
+pub const TDN_TIMER: UINT = 4;
+pub const TDN_DESTROYED: UINT = 5;
+pub const TDN_RADIO_BUTTON_CLICKED: UINT = 6;
+pub const TDN_DIALOG_CONSTRUCTED: UINT = 7;
+pub const TDN_VERIFICATION_CLICKED: UINT = 8;
+pub const TDN_HELP: UINT = 9;
+pub const TDN_EXPANDO_BUTTON_CLICKED: UINT = 10;
+
+pub const TDM_CLICK_BUTTON: UINT = WM_USER + 102;
+pub const TDM_SET_PROGRESS_BAR_STATE: UINT = WM_USER + 104;
+pub const TDM_SET_PROGRESS_BAR_RANGE: UINT = WM_USER + 105;
+pub const TDM_SET_PROGRESS_BAR_POS: UINT = WM_USER + 106;
+pub const TDM_SET_PROGRESS_BAR_MARQUEE: UINT = WM_USER + 107;
+pub const TDM_SET_ELEMENT_TEXT: UINT = WM_USER + 108;
+pub const TDM_ENABLE_BUTTON: UINT = WM_USER + 111;
+pub const TDM_ENABLE_RADIO_BUTTON: UINT = WM_USER + 112;
+
+pub const TDE_CONTENT: WPARAM = 0;
+pub const TDE_EXPANDED_INFORMATION: WPARAM = 1;
+pub const TDE_FOOTER: WPARAM = 2;
+pub const TDE_MAIN_INSTRUCTION: WPARAM = 3;
+
+pub const PBST_NORMAL: WPARAM = 0x0001;
+pub const PBST_ERROR: WPARAM = 0x0002;
+pub const PBST_PAUSED: WPARAM = 0x0003;
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct TASKDIALOG_BUTTON {
+    pub nButtonID: c_int,
+    pub pszButtonText: LPCWSTR,
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct TASKDIALOGCONFIG {
+    pub cbSize: UINT,
+    pub hwndParent: HWND,
+    pub hInstance: HINSTANCE,
+    pub dwFlags: c_int,
+    pub dwCommonButtons: c_int,
+    pub pszWindowTitle: LPCWSTR,
+    //Union with hMainIcon
+    pub pszMainIcon: LPCWSTR,
+    pub pszMainInstruction: LPCWSTR,
+    pub pszContent: LPCWSTR,
+    pub cButtons: UINT,
+    pub pButtons: *const TASKDIALOG_BUTTON,
+    pub nDefaultButton: c_int,
+    pub cRadioButtons: UINT,
+    pub pRadioButtons: *const TASKDIALOG_BUTTON,
+    pub nDefaultRadioButton: c_int,
+    pub pszVerificationText: LPCWSTR,
+    pub pszExpandedInformation: LPCWSTR,
+    pub pszExpandedControlText: LPCWSTR,
+    pub pszCollapsedControlText: LPCWSTR,
+    //Union with hFooterIcon
+    pub pszFooterIcon: LPCWSTR,
+    pub pszFooter: LPCWSTR,
+    pub pfCallback: PFTASKDIALOGCALLBACK,
+    pub lpCallbackData: LONG_PTR,
+    pub cxWidth: UINT,
+}
+
+//Exported only by Common Controls v6, which is not loaded unless application manifest requires it.
+pub type TaskDialogIndirectFn = unsafe extern "system" fn(pTaskConfig: *const TASKDIALOGCONFIG, pnButton: *mut c_int, pnRadioButton: *mut c_int, pfVerificationFlagChecked: *mut BOOL) -> HRESULT;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ACTCTXW {
+    pub cbSize: ULONG,
+    pub dwFlags: DWORD,
+    pub lpSource: LPCWSTR,
+    pub wProcessorArchitecture: WORD,
+    pub wLangId: WORD,
+    pub lpAssemblyDirectory: LPCWSTR,
+    pub lpResourceName: LPCWSTR,
+    pub lpApplicationName: LPCWSTR,
+    pub hModule: HMODULE,
+}
+
+pub const OFN_OVERWRITEPROMPT: DWORD = 0x00000002;
+pub const OFN_HIDEREADONLY: DWORD = 0x00000004;
+pub const OFN_NOCHANGEDIR: DWORD = 0x00000008;
+pub const OFN_ALLOWMULTISELECT: DWORD = 0x00000200;
+pub const OFN_PATHMUSTEXIST: DWORD = 0x00000800;
+pub const OFN_FILEMUSTEXIST: DWORD = 0x00001000;
+pub const OFN_EXPLORER: DWORD = 0x00080000;
+pub const FNERR_BUFFERTOOSMALL: DWORD = 0x3003;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct OPENFILENAMEW {
+    pub lStructSize: DWORD,
+    pub hwndOwner: HWND,
+    pub hInstance: HINSTANCE,
+    pub lpstrFilter: LPCWSTR,
+    pub lpstrCustomFilter: LPWSTR,
+    pub nMaxCustFilter: DWORD,
+    pub nFilterIndex: DWORD,
+    pub lpstrFile: LPWSTR,
+    pub nMaxFile: DWORD,
+    pub lpstrFileTitle: LPWSTR,
+    pub nMaxFileTitle: DWORD,
+    pub lpstrInitialDir: LPCWSTR,
+    pub lpstrTitle: LPCWSTR,
+    pub Flags: DWORD,
+    pub nFileOffset: WORD,
+    pub nFileExtension: WORD,
+    pub lpstrDefExt: LPCWSTR,
+    pub lCustData: LPARAM,
+    pub lpfnHook: LPVOID,
+    pub lpTemplateName: LPCWSTR,
+    pub pvReserved: LPVOID,
+    pub dwReserved: DWORD,
+    pub FlagsEx: DWORD,
+}
+
+pub const BIF_RETURNONLYFSDIRS: UINT = 0x00000001;
+pub const BIF_EDITBOX: UINT = 0x00000010;
+pub const BIF_NEWDIALOGSTYLE: UINT = 0x00000040;
+pub const BFFM_INITIALIZED: UINT = 1;
+pub const BFFM_SETSELECTIONW: UINT = WM_USER + 103;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BROWSEINFOW {
+    pub hwndOwner: HWND,
+    pub pidlRoot: LPCVOID,
+    pub pszDisplayName: LPWSTR,
+    pub lpszTitle: LPCWSTR,
+    pub ulFlags: UINT,
+    pub lpfn: BFFCALLBACK,
+    pub lParam: LPARAM,
+    pub iImage: c_int,
+}
+
+pub const COINIT_APARTMENTTHREADED: DWORD = 0x2;
+
+pub type WNDPROC = Option<unsafe extern "system" fn(_: HWND, _: UINT, _: WPARAM, _: LPARAM) -> LRESULT>;
+pub type HCURSOR = *mut c_void;
+pub type HBRUSH = *mut c_void;
+pub type ATOM = WORD;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct WNDCLASSEXW {
+    pub cbSize: UINT,
+    pub style: UINT,
+    pub lpfnWndProc: WNDPROC,
+    pub cbClsExtra: c_int,
+    pub cbWndExtra: c_int,
+    pub hInstance: HINSTANCE,
+    pub hIcon: HICON,
+    pub hCursor: HCURSOR,
+    pub hbrBackground: HBRUSH,
+    pub lpszMenuName: LPCWSTR,
+    pub lpszClassName: LPCWSTR,
+    pub hIconSm: HICON,
+}
+
+pub const ERROR_CLASS_ALREADY_EXISTS: DWORD = 1410;
+pub const WM_NULL: UINT = 0x0000;
+pub const WM_CONTEXTMENU: UINT = 0x007B;
+pub const MSGFLT_ALLOW: DWORD = 1;
+
+pub const IMAGE_ICON: UINT = 1;
+pub const LR_LOADFROMFILE: UINT = 0x00000010;
+pub const SM_CXSMICON: c_int = 49;
+pub const SM_CYSMICON: c_int = 50;
+pub const IDI_APPLICATION: LPCWSTR = 32512 as LPCWSTR;
+
+pub const MF_STRING: UINT = 0x00000000;
+pub const MF_CHECKED: UINT = 0x00000008;
+pub const MF_SEPARATOR: UINT = 0x00000800;
+pub const TPM_RIGHTBUTTON: UINT = 0x0002;
+pub const TPM_NONOTIFY: UINT = 0x0080;
+pub const TPM_RETURNCMD: UINT = 0x0100;
+
+pub const NIM_ADD: DWORD = 0x00000000;
+pub const NIM_MODIFY: DWORD = 0x00000001;
+pub const NIM_DELETE: DWORD = 0x00000002;
+pub const NIM_SETVERSION: DWORD = 0x00000004;
+pub const NIF_MESSAGE: UINT = 0x00000001;
+pub const NIF_ICON: UINT = 0x00000002;
+pub const NIF_TIP: UINT = 0x00000004;
+pub const NIF_INFO: UINT = 0x00000010;
+pub const NIF_SHOWTIP: UINT = 0x00000080;
+pub const NIIF_NONE: DWORD = 0x00000000;
+pub const NIIF_INFO: DWORD = 0x00000001;
+pub const NIIF_WARNING: DWORD = 0x00000002;
+pub const NIIF_ERROR: DWORD = 0x00000003;
+pub const NIIF_NOSOUND: DWORD = 0x00000010;
+pub const NOTIFYICON_VERSION_4: UINT = 4;
+pub const NIN_SELECT: UINT = WM_USER;
+pub const NIN_KEYSELECT: UINT = WM_USER + 1;
+pub const NIN_BALLOONTIMEOUT: UINT = WM_USER + 4;
+pub const NIN_BALLOONUSERCLICK: UINT = WM_USER + 5;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct GUID {
+    pub Data1: c_ulong,
+    pub Data2: c_ushort,
+    pub Data3: c_ushort,
+    pub Data4: [c_uchar; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct NOTIFYICONDATAW {
+    pub cbSize: DWORD,
+    pub hWnd: HWND,
+    pub uID: UINT,
+    pub uFlags: UINT,
+    pub uCallbackMessage: UINT,
+    pub hIcon: HICON,
+    pub szTip: [WCHAR; 128],
+    pub dwState: DWORD,
+    pub dwStateMask: DWORD,
+    pub szInfo: [WCHAR; 256],
+    //Union with uTimeout, which is deprecated.
+    pub uVersion: UINT,
+    pub szInfoTitle: [WCHAR; 64],
+    pub dwInfoFlags: DWORD,
+    pub guidItem: GUID,
+    pub hBalloonIcon: HICON,
+}
+
+pub const STD_INPUT_HANDLE: DWORD = -10i32 as DWORD;
+pub const STD_OUTPUT_HANDLE: DWORD = -11i32 as DWORD;
+pub const STD_ERROR_HANDLE: DWORD = -12i32 as DWORD;
+pub const ATTACH_PARENT_PROCESS: DWORD = -1i32 as DWORD;
+pub const ERROR_ALREADY_EXISTS: DWORD = 183;
+
+pub const ENABLE_PROCESSED_INPUT: DWORD = 0x0001;
+pub const ENABLE_LINE_INPUT: DWORD = 0x0002;
+pub const ENABLE_ECHO_INPUT: DWORD = 0x0004;
+pub const ENABLE_WINDOW_INPUT: DWORD = 0x0008;
+pub const ENABLE_MOUSE_INPUT: DWORD = 0x0010;
+pub const ENABLE_INSERT_MODE: DWORD = 0x0020;
+pub const ENABLE_QUICK_EDIT_MODE: DWORD = 0x0040;
+pub const ENABLE_EXTENDED_FLAGS: DWORD = 0x0080;
+pub const ENABLE_VIRTUAL_TERMINAL_INPUT: DWORD = 0x0200;
+pub const ENABLE_PROCESSED_OUTPUT: DWORD = 0x0001;
+pub const ENABLE_WRAP_AT_EOL_OUTPUT: DWORD = 0x0002;
+pub const ENABLE_VIRTUAL_TERMINAL_PROCESSING: DWORD = 0x0004;
+pub const DISABLE_NEWLINE_AUTO_RETURN: DWORD = 0x0008;
+
+pub const FOREGROUND_BLUE: WORD = 0x0001;
+pub const FOREGROUND_GREEN: WORD = 0x0002;
+pub const FOREGROUND_RED: WORD = 0x0004;
+pub const FOREGROUND_INTENSITY: WORD = 0x0008;
+pub const BACKGROUND_BLUE: WORD = 0x0010;
+pub const BACKGROUND_GREEN: WORD = 0x0020;
+pub const BACKGROUND_RED: WORD = 0x0040;
+pub const BACKGROUND_INTENSITY: WORD = 0x0080;
+pub const COMMON_LVB_REVERSE_VIDEO: WORD = 0x4000;
+pub const COMMON_LVB_UNDERSCORE: WORD = 0x8000;
+
+pub const KEY_EVENT: WORD = 0x0001;
+pub const MOUSE_EVENT: WORD = 0x0002;
+pub const WINDOW_BUFFER_SIZE_EVENT: WORD = 0x0004;
+pub const MENU_EVENT: WORD = 0x0008;
+pub const FOCUS_EVENT: WORD = 0x0010;
+
+pub const RIGHT_ALT_PRESSED: DWORD = 0x0001;
+pub const LEFT_ALT_PRESSED: DWORD = 0x0002;
+pub const RIGHT_CTRL_PRESSED: DWORD = 0x0004;
+pub const LEFT_CTRL_PRESSED: DWORD = 0x0008;
+pub const SHIFT_PRESSED: DWORD = 0x0010;
+pub const NUMLOCK_ON: DWORD = 0x0020;
+pub const SCROLLLOCK_ON: DWORD = 0x0040;
+pub const CAPSLOCK_ON: DWORD = 0x0080;
+pub const ENHANCED_KEY: DWORD = 0x0100;
+
+pub const FROM_LEFT_1ST_BUTTON_PRESSED: DWORD = 0x0001;
+pub const RIGHTMOST_BUTTON_PRESSED: DWORD = 0x0002;
+pub const FROM_LEFT_2ND_BUTTON_PRESSED: DWORD = 0x0004;
+pub const MOUSE_MOVED: DWORD = 0x0001;
+pub const DOUBLE_CLICK: DWORD = 0x0002;
+pub const MOUSE_WHEELED: DWORD = 0x0004;
+pub const MOUSE_HWHEELED: DWORD = 0x0008;
+
+pub const CTRL_C_EVENT: DWORD = 0;
+pub const CTRL_BREAK_EVENT: DWORD = 1;
+pub const CTRL_CLOSE_EVENT: DWORD = 2;
+pub const CTRL_LOGOFF_EVENT: DWORD = 5;
+pub const CTRL_SHUTDOWN_EVENT: DWORD = 6;
+
+pub type PHANDLER_ROUTINE = Option<unsafe extern "system" fn(_: DWORD) -> BOOL>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct COORD {
+    pub X: SHORT,
+    pub Y: SHORT,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SMALL_RECT {
+    pub Left: SHORT,
+    pub Top: SHORT,
+    pub Right: SHORT,
+    pub Bottom: SHORT,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CONSOLE_SCREEN_BUFFER_INFO {
+    pub dwSize: COORD,
+    pub dwCursorPosition: COORD,
+    pub wAttributes: WORD,
+    pub srWindow: SMALL_RECT,
+    pub dwMaximumWindowSize: COORD,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct KEY_EVENT_RECORD {
+    pub bKeyDown: BOOL,
+    pub wRepeatCount: WORD,
+    pub wVirtualKeyCode: WORD,
+    pub wVirtualScanCode: WORD,
+    //Union with AsciiChar.
+    pub UnicodeChar: WCHAR,
+    pub dwControlKeyState: DWORD,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MOUSE_EVENT_RECORD {
+    pub dwMousePosition: COORD,
+    pub dwButtonState: DWORD,
+    pub dwControlKeyState: DWORD,
+    pub dwEventFlags: DWORD,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct WINDOW_BUFFER_SIZE_RECORD {
+    pub dwSize: COORD,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MENU_EVENT_RECORD {
+    pub dwCommandId: UINT,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FOCUS_EVENT_RECORD {
+    pub bSetFocus: BOOL,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union INPUT_RECORD_u {
+    pub KeyEvent: KEY_EVENT_RECORD,
+    pub MouseEvent: MOUSE_EVENT_RECORD,
+    pub WindowBufferSizeEvent: WINDOW_BUFFER_SIZE_RECORD,
+    pub MenuEvent: MENU_EVENT_RECORD,
+    pub FocusEvent: FOCUS_EVENT_RECORD,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct INPUT_RECORD {
+    pub EventType: WORD,
+    pub Event: INPUT_RECORD_u,
+}
+
+pub const TH32CS_SNAPPROCESS: DWORD = 0x00000002;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PROCESSENTRY32W {
+    pub dwSize: DWORD,
+    pub cntUsage: DWORD,
+    pub th32ProcessID: DWORD,
+    pub th32DefaultHeapID: ULONG_PTR,
+    pub th32ModuleID: DWORD,
+    pub cntThreads: DWORD,
+    pub th32ParentProcessID: DWORD,
+    pub pcPriClassBase: LONG,
+    pub dwFlags: DWORD,
+    pub szExeFile: [WCHAR; MAX_PATH],
+}
+
 pub type PMEMORY_BASIC_INFORMATION = *mut MEMORY_BASIC_INFORMATION;
 #[repr(C)]
 pub struct MEMORY_BASIC_INFORMATION {
@@ -362,13 +1175,80 @@ pub struct CREATESTRUCTW {
     pub dwExStyle: DWORD,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MOUSEINPUT {
+    pub dx: LONG,
+    pub dy: LONG,
+    pub mouseData: DWORD,
+    pub dwFlags: DWORD,
+    pub time: DWORD,
+    pub dwExtraInfo: ULONG_PTR,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct KEYBDINPUT {
+    pub wVk: WORD,
+    pub wScan: WORD,
+    pub dwFlags: DWORD,
+    pub time: DWORD,
+    pub dwExtraInfo: ULONG_PTR,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct HARDWAREINPUT {
+    pub uMsg: DWORD,
+    pub wParamL: WORD,
+    pub wParamH: WORD,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union INPUT_u {
+    pub mi: MOUSEINPUT,
+    pub ki: KEYBDINPUT,
+    pub hi: HARDWAREINPUT,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct INPUT {
+    pub type_: DWORD,
+    pub u: INPUT_u,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct KBDLLHOOKSTRUCT {
+    pub vkCode: DWORD,
+    pub scanCode: DWORD,
+    pub flags: DWORD,
+    pub time: DWORD,
+    pub dwExtraInfo: ULONG_PTR,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MSLLHOOKSTRUCT {
+    pub pt: POINT,
+    pub mouseData: DWORD,
+    pub flags: DWORD,
+    pub time: DWORD,
+    pub dwExtraInfo: ULONG_PTR,
+}
+
 //Functions
+#[cfg(windows)]
 #[link(name = "user32", kind = "dylib")]
 extern "system" {
     pub fn FindWindowW(lpClassName: LPCWSTR, lpWindowName: LPCWSTR) -> HWND;
     pub fn FindWindowExW(hWndParent: HWND, hWndChildAfter: HWND, lpszClass: LPCWSTR, lpszWindow: LPCWSTR) -> HWND;
     pub fn IsWindowVisible(hWnd: HWND) -> BOOL;
     pub fn GetWindowTextW(hWnd: HWND, lpString: LPWSTR, nMaxCount: c_int) -> c_int;
+    pub fn GetWindowTextLengthW(hWnd: HWND) -> c_int;
+    pub fn IsHungAppWindow(hwnd: HWND) -> BOOL;
     pub fn SendMessageW(hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM) -> LRESULT;
     pub fn SendMessageTimeoutW(hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM, fuFlags: UINT, uTimeout: UINT, lpdwResult: PDWORD_PTR) -> LRESULT;
     pub fn RealGetWindowClassW(hwnd: HWND, ptszClassName: LPWSTR, cchClassNameMax: UINT) -> UINT;
@@ -384,12 +1264,64 @@ extern "system" {
     pub fn DestroyWindow(hWnd: HWND) -> BOOL;
     pub fn ShowWindow(hWnd: HWND, nCmdShow: c_int) -> BOOL;
     pub fn MessageBoxW(hWnd: HWND, lpText: LPCWSTR, lpCaption: LPCWSTR, uType: UINT) -> c_int;
+    //Undocumented, but available since Windows XP.
+    pub fn MessageBoxTimeoutW(hWnd: HWND, lpText: LPCWSTR, lpCaption: LPCWSTR, uType: UINT, wLanguageId: WORD, dwMilliseconds: DWORD) -> c_int;
+    pub fn SetDlgItemTextW(hDlg: HWND, nIDDlgItem: c_int, lpString: LPCWSTR) -> BOOL;
     pub fn SetLastErrorEx(dwErrCode: DWORD, dwType: DWORD);
     pub fn AddClipboardFormatListener(hWnd: HWND) -> BOOL;
     pub fn RemoveClipboardFormatListener(hWnd: HWND) -> BOOL;
+    pub fn GetClipboardSequenceNumber() -> DWORD;
+    pub fn RegisterHotKey(hWnd: HWND, id: c_int, fsModifiers: UINT, vk: UINT) -> BOOL;
+    pub fn UnregisterHotKey(hWnd: HWND, id: c_int) -> BOOL;
+    pub fn SendInput(cInputs: UINT, pInputs: *mut INPUT, cbSize: c_int) -> UINT;
+    pub fn GetSystemMetrics(nIndex: c_int) -> c_int;
+    pub fn SetWindowsHookExW(idHook: c_int, lpfn: HOOKPROC, hmod: HINSTANCE, dwThreadId: DWORD) -> HHOOK;
+    pub fn UnhookWindowsHookEx(hhk: HHOOK) -> BOOL;
+    pub fn SetWinEventHook(eventMin: DWORD, eventMax: DWORD, hmodWinEventProc: HMODULE, pfnWinEventProc: WINEVENTPROC, idProcess: DWORD, idThread: DWORD, dwFlags: DWORD) -> HWINEVENTHOOK;
+    pub fn UnhookWinEvent(hWinEventHook: HWINEVENTHOOK) -> BOOL;
+    pub fn CallNextHookEx(hhk: HHOOK, nCode: c_int, wParam: WPARAM, lParam: LPARAM) -> LRESULT;
     pub fn PostMessageW(hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM) -> BOOL;
+    pub fn PostThreadMessageW(idThread: DWORD, Msg: UINT, wParam: WPARAM, lParam: LPARAM) -> BOOL;
+    pub fn GetWindowRect(hWnd: HWND, lpRect: *mut RECT) -> BOOL;
+    pub fn GetWindowLongW(hWnd: HWND, nIndex: c_int) -> LONG;
+    pub fn SetWindowLongW(hWnd: HWND, nIndex: c_int, dwNewLong: LONG) -> LONG;
+    pub fn SetLayeredWindowAttributes(hwnd: HWND, crKey: COLORREF, bAlpha: BYTE, dwFlags: DWORD) -> BOOL;
+    pub fn GetLayeredWindowAttributes(hwnd: HWND, pcrKey: *mut COLORREF, pbAlpha: *mut BYTE, pdwFlags: *mut DWORD) -> BOOL;
+    pub fn GetWindow(hWnd: HWND, uCmd: UINT) -> HWND;
+    pub fn WindowFromPoint(Point: POINT) -> HWND;
+    pub fn EnumDisplayMonitors(hdc: HDC, lprcClip: *const RECT, lpfnEnum: MONITORENUMPROC, dwData: LPARAM) -> BOOL;
+    pub fn GetMonitorInfoW(hMonitor: HMONITOR, lpmi: *mut MONITORINFOEXW) -> BOOL;
+    pub fn MonitorFromWindow(hwnd: HWND, dwFlags: DWORD) -> HMONITOR;
+    pub fn MonitorFromPoint(pt: POINT, dwFlags: DWORD) -> HMONITOR;
+    pub fn GetDpiForWindow(hwnd: HWND) -> UINT;
+    pub fn GetDpiForSystem() -> UINT;
+    pub fn SetProcessDpiAwarenessContext(value: DPI_AWARENESS_CONTEXT) -> BOOL;
+    pub fn SetThreadDpiAwarenessContext(dpiContext: DPI_AWARENESS_CONTEXT) -> DPI_AWARENESS_CONTEXT;
+    pub fn GetMenu(hWnd: HWND) -> HMENU;
+    pub fn GetSystemMenu(hWnd: HWND, bRevert: BOOL) -> HMENU;
+    pub fn GetMenuItemCount(hMenu: HMENU) -> c_int;
+    pub fn GetMenuItemInfoW(hmenu: HMENU, item: UINT, fByPosition: BOOL, lpmii: *mut MENUITEMINFOW) -> BOOL;
+    pub fn GetDC(hWnd: HWND) -> HDC;
+    pub fn GetWindowDC(hWnd: HWND) -> HDC;
+    pub fn ReleaseDC(hWnd: HWND, hDC: HDC) -> c_int;
+    pub fn PrintWindow(hwnd: HWND, hdcBlt: HDC, nFlags: UINT) -> BOOL;
+    pub fn WaitForInputIdle(hProcess: HANDLE, dwMilliseconds: DWORD) -> DWORD;
+    pub fn RegisterClassExW(lpWndClass: *const WNDCLASSEXW) -> ATOM;
+    pub fn DefWindowProcW(hWnd: HWND, Msg: UINT, wParam: WPARAM, lParam: LPARAM) -> LRESULT;
+    pub fn RegisterWindowMessageW(lpString: LPCWSTR) -> UINT;
+    pub fn ChangeWindowMessageFilterEx(hwnd: HWND, message: UINT, action: DWORD, pChangeFilterStruct: LPVOID) -> BOOL;
+    pub fn LoadImageW(hInst: HINSTANCE, name: LPCWSTR, type_: UINT, cx: c_int, cy: c_int, fuLoad: UINT) -> HANDLE;
+    pub fn LoadIconW(hInstance: HINSTANCE, lpIconName: LPCWSTR) -> HICON;
+    pub fn DestroyIcon(hIcon: HICON) -> BOOL;
+    pub fn CreatePopupMenu() -> HMENU;
+    pub fn AppendMenuW(hMenu: HMENU, uFlags: UINT, uIDNewItem: ULONG_PTR, lpNewItem: LPCWSTR) -> BOOL;
+    pub fn DestroyMenu(hMenu: HMENU) -> BOOL;
+    pub fn TrackPopupMenu(hMenu: HMENU, uFlags: UINT, x: c_int, y: c_int, nReserved: c_int, hWnd: HWND, prcRect: *const RECT) -> BOOL;
+    pub fn GetCursorPos(lpPoint: *mut POINT) -> BOOL;
+    pub fn SetForegroundWindow(hWnd: HWND) -> BOOL;
 }
 
+#[cfg(windows)]
 #[link(name = "kernel32", kind = "dylib")]
 extern "system" {
     pub fn OpenProcess(dwDesiredAccess: DWORD, bInheritHandle: BOOL, dwProcessId: DWORD) -> HANDLE;
@@ -401,17 +1333,47 @@ extern "system" {
     pub fn ReadProcessMemory(hProcess: HANDLE, lpBaseAddress: LPCVOID, lpBuffer: LPVOID, nSize: SIZE_T, lpNumberOfBytesRead: *mut SIZE_T) -> BOOL;
     pub fn WriteProcessMemory(hProcess: HANDLE, lpBaseAddress: LPVOID, lpBuffer: LPCVOID, nSize: SIZE_T, lpNumberOfBytesWritten: *mut SIZE_T) -> BOOL;
 
+    pub fn CreateToolhelp32Snapshot(dwFlags: DWORD, th32ProcessID: DWORD) -> HANDLE;
+    pub fn Process32FirstW(hSnapshot: HANDLE, lppe: *mut PROCESSENTRY32W) -> BOOL;
+    pub fn Process32NextW(hSnapshot: HANDLE, lppe: *mut PROCESSENTRY32W) -> BOOL;
+
     pub fn QueryFullProcessImageNameW(hProcess: HANDLE, dwFlags: DWORD, lpExeName: LPWSTR, lpdwSize: PDWORD) -> BOOL;
 
     pub fn GetConsoleWindow() -> HWND;
+    pub fn AllocConsole() -> BOOL;
+    pub fn AttachConsole(dwProcessId: DWORD) -> BOOL;
+    pub fn FreeConsole() -> BOOL;
+    pub fn GetStdHandle(nStdHandle: DWORD) -> HANDLE;
+    pub fn GetConsoleMode(hConsoleHandle: HANDLE, lpMode: LPDWORD) -> BOOL;
+    pub fn SetConsoleMode(hConsoleHandle: HANDLE, dwMode: DWORD) -> BOOL;
+    pub fn SetConsoleTitleW(lpConsoleTitle: LPCWSTR) -> BOOL;
+    pub fn SetConsoleTextAttribute(hConsoleOutput: HANDLE, wAttributes: WORD) -> BOOL;
+    pub fn SetConsoleCursorPosition(hConsoleOutput: HANDLE, dwCursorPosition: COORD) -> BOOL;
+    pub fn GetConsoleScreenBufferInfo(hConsoleOutput: HANDLE, lpConsoleScreenBufferInfo: *mut CONSOLE_SCREEN_BUFFER_INFO) -> BOOL;
+    pub fn SetConsoleScreenBufferSize(hConsoleOutput: HANDLE, dwSize: COORD) -> BOOL;
+    pub fn SetConsoleWindowInfo(hConsoleOutput: HANDLE, bAbsolute: BOOL, lpConsoleWindow: *const SMALL_RECT) -> BOOL;
+    pub fn ReadConsoleInputW(hConsoleInput: HANDLE, lpBuffer: *mut INPUT_RECORD, nLength: DWORD, lpNumberOfEventsRead: LPDWORD) -> BOOL;
+    pub fn GetNumberOfConsoleInputEvents(hConsoleInput: HANDLE, lpNumberOfEvents: LPDWORD) -> BOOL;
+    pub fn FlushConsoleInputBuffer(hConsoleInput: HANDLE) -> BOOL;
+    pub fn SetConsoleCtrlHandler(HandlerRoutine: PHANDLER_ROUTINE, Add: BOOL) -> BOOL;
+
+    pub fn GetCurrentThreadId() -> DWORD;
 
     pub fn FindFirstFileExW(lpFileName: LPCWSTR, fInfoLevelId: FINDEX_INFO_LEVELS, lpFindFileData: LPVOID, fSearchOp: FINDEX_SEARCH_OPS, lpSearchFilter: LPVOID, dwAdditionalFlags: DWORD) -> HANDLE;
     pub fn FindNextFileW(hFindFile: HANDLE, lpFindFileData: LPWIN32_FIND_DATAW) -> BOOL;
     pub fn FindClose(hFindFile: HANDLE) -> BOOL;
 
     pub fn VirtualQueryEx(hProcess: HANDLE, lpAddress: LPCVOID, lpBuffer: PMEMORY_BASIC_INFORMATION, dwLength: SIZE_T) -> SIZE_T;
+    pub fn VirtualAllocEx(hProcess: HANDLE, lpAddress: LPVOID, dwSize: SIZE_T, flAllocationType: DWORD, flProtect: DWORD) -> LPVOID;
+    pub fn VirtualFreeEx(hProcess: HANDLE, lpAddress: LPVOID, dwSize: SIZE_T, dwFreeType: DWORD) -> BOOL;
+
+    pub fn IsWow64Process(hProcess: HANDLE, Wow64Process: *mut BOOL) -> BOOL;
 
     pub fn GetModuleHandleExW(dwFlags: DWORD, lpModuleName: LPCWSTR, phModule: *mut HMODULE) -> BOOL;
+    pub fn GetModuleHandleW(lpModuleName: LPCWSTR) -> HMODULE;
+    pub fn LoadLibraryW(lpLibFileName: LPCWSTR) -> HMODULE;
+    pub fn FreeLibrary(hLibModule: HMODULE) -> BOOL;
+    pub fn GetProcAddress(hModule: HMODULE, lpProcName: LPCSTR) -> FARPROC;
     pub fn GetModuleFileNameW(hModule: HMODULE, lpFilename: LPWSTR, nSize: DWORD) -> DWORD;
 
     pub fn QueryPerformanceFrequency(lpFrequency: *mut LARGE_INTEGER) -> BOOL;
@@ -421,9 +1383,66 @@ extern "system" {
     pub fn DeleteTimerQueueEx(TimerQueue: HANDLE, CompletionEvent: HANDLE) -> BOOL;
     pub fn CreateTimerQueueTimer(phNewTimer: PHANDLE, TimerQueue: HANDLE, Callback: WAITORTIMERCALLBACK, Parameter: PVOID, DueTime: DWORD, Period: DWORD, Flags: ULONG) -> BOOL;
     pub fn DeleteTimerQueueTimer(TimerQueue: HANDLE, Timer: HANDLE, CompletionEvent: HANDLE) -> BOOL;
+    pub fn CreateEventW(lpEventAttributes: LPVOID, bManualReset: BOOL, bInitialState: BOOL, lpName: LPCWSTR) -> HANDLE;
+    pub fn WaitForSingleObject(hHandle: HANDLE, dwMilliseconds: DWORD) -> DWORD;
     pub fn ChangeTimerQueueTimer(TimerQueue: HANDLE, Timer: HANDLE, DueTime: ULONG, Period: ULONG) -> BOOL;
+
+    pub fn CreateActCtxW(pActCtx: *const ACTCTXW) -> HANDLE;
+    pub fn ActivateActCtx(hActCtx: HANDLE, lpCookie: *mut ULONG_PTR) -> BOOL;
+    pub fn DeactivateActCtx(dwFlags: DWORD, ulCookie: ULONG_PTR) -> BOOL;
+    pub fn ReleaseActCtx(hActCtx: HANDLE);
+}
+
+#[cfg(windows)]
+#[link(name = "gdi32", kind = "dylib")]
+extern "system" {
+    pub fn CreateCompatibleDC(hdc: HDC) -> HDC;
+    pub fn CreateCompatibleBitmap(hdc: HDC, cx: c_int, cy: c_int) -> HBITMAP;
+    pub fn SelectObject(hdc: HDC, h: HGDIOBJ) -> HGDIOBJ;
+    pub fn DeleteObject(ho: HGDIOBJ) -> BOOL;
+    pub fn DeleteDC(hdc: HDC) -> BOOL;
+    pub fn BitBlt(hdc: HDC, x: c_int, y: c_int, cx: c_int, cy: c_int, hdcSrc: HDC, x1: c_int, y1: c_int, rop: DWORD) -> BOOL;
+    pub fn GetDIBits(hdc: HDC, hbm: HBITMAP, start: UINT, cLines: UINT, lpvBits: LPVOID, lpbmi: *mut BITMAPINFO, usage: UINT) -> c_int;
+}
+
+#[cfg(windows)]
+#[link(name = "shcore", kind = "dylib")]
+extern "system" {
+    pub fn GetDpiForMonitor(hmonitor: HMONITOR, dpiType: c_int, dpiX: *mut UINT, dpiY: *mut UINT) -> HRESULT;
+}
+
+#[cfg(windows)]
+#[link(name = "dwmapi", kind = "dylib")]
+extern "system" {
+    pub fn DwmGetWindowAttribute(hwnd: HWND, dwAttribute: DWORD, pvAttribute: PVOID, cbAttribute: DWORD) -> HRESULT;
+    pub fn DwmSetWindowAttribute(hwnd: HWND, dwAttribute: DWORD, pvAttribute: LPCVOID, cbAttribute: DWORD) -> HRESULT;
+}
+
+#[cfg(windows)]
+#[link(name = "comdlg32", kind = "dylib")]
+extern "system" {
+    pub fn GetOpenFileNameW(lpofn: *mut OPENFILENAMEW) -> BOOL;
+    pub fn GetSaveFileNameW(lpofn: *mut OPENFILENAMEW) -> BOOL;
+    pub fn CommDlgExtendedError() -> DWORD;
+}
+
+#[cfg(windows)]
+#[link(name = "shell32", kind = "dylib")]
+extern "system" {
+    pub fn SHBrowseForFolderW(lpbi: *mut BROWSEINFOW) -> LPVOID;
+    pub fn SHGetPathFromIDListW(pidl: LPCVOID, pszPath: LPWSTR) -> BOOL;
+    pub fn Shell_NotifyIconW(dwMessage: DWORD, lpData: *mut NOTIFYICONDATAW) -> BOOL;
+}
+
+#[cfg(windows)]
+#[link(name = "ole32", kind = "dylib")]
+extern "system" {
+    pub fn CoInitializeEx(pvReserved: LPVOID, dwCoInit: DWORD) -> HRESULT;
+    pub fn CoUninitialize();
+    pub fn CoTaskMemFree(pv: LPVOID);
 }
 
+#[cfg(windows)]
 #[link(name = "advapi32", kind = "dylib")]
 extern "system" {
     pub fn OpenProcessToken(ProcessHandle: HANDLE, DesiredAccess: DWORD, TokenHandle: PHANDLE) -> BOOL;
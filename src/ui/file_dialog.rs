@@ -0,0 +1,577 @@
+//! File and folder dialogs APIs
+//!
+//! Filters are written as `Name|Patterns` pairs separated by `|`, with patterns separated by `;`,
+//! e.g. `Logs (*.log)|*.log|Images|*.png;*.jpg`.
+//!
+//! Parsing and encoding of filters, as well as decoding of selected files, is platform independent.
+
+use core::fmt;
+
+#[cfg(windows)]
+use crate::sys::*;
+#[cfg(windows)]
+use crate::utils::{ErrorCode, Result};
+#[cfg(windows)]
+use super::{to_wide, as_ptr};
+#[cfg(windows)]
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+#[cfg(windows)]
+use std::{ffi, mem, path, ptr};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+///Filter of file dialog.
+pub struct Filter {
+    ///Name displayed to user, e.g. `Logs (*.log)`.
+    pub name: String,
+    ///Patterns, e.g. `*.log`.
+    pub patterns: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+///Error of parsing filters.
+pub enum ParseFilterError {
+    ///Filter has empty name, with index of filter.
+    EmptyName(usize),
+    ///Filter has no patterns, with its name.
+    MissingPatterns(String),
+}
+
+impl fmt::Display for ParseFilterError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseFilterError::EmptyName(idx) => write!(fmt, "Filter #{} has empty name", idx),
+            ParseFilterError::MissingPatterns(name) => write!(fmt, "Filter '{}' has no patterns", name),
+        }
+    }
+}
+
+impl std::error::Error for ParseFilterError {}
+
+error_code::define_category!(
+    ///Error of common dialog, as returned by `CommDlgExtendedError`.
+    ///
+    ///These codes are not system error codes, so they are reported within own category.
+    pub enum CommDlgError {
+        ///Dialog box could not be created
+        DialogFailure = 0xFFFF,
+        ///Invalid size of dialog structure
+        StructSize = 0x0001,
+        ///Dialog failed during initialization
+        Initialization = 0x0002,
+        ///Dialog template is not found
+        NoTemplate = 0x0003,
+        ///Instance handle is not provided
+        NoHInstance = 0x0004,
+        ///Failed to load string
+        LoadStrFailure = 0x0005,
+        ///Failed to find resource
+        FindResFailure = 0x0006,
+        ///Failed to load resource
+        LoadResFailure = 0x0007,
+        ///Failed to lock resource
+        LockResFailure = 0x0008,
+        ///Failed to allocate memory
+        MemAllocFailure = 0x0009,
+        ///Failed to lock memory
+        MemLockFailure = 0x000A,
+        ///Hook procedure is not provided
+        NoHook = 0x000B,
+        ///Failed to register message
+        RegisterMsgFail = 0x000C,
+        ///Failed to subclass list box
+        SubclassFailure = 0x3001,
+        ///Invalid file name
+        InvalidFileName = 0x3002,
+        ///Buffer is too small for selected files
+        BufferTooSmall = 0x3003,
+    }
+);
+
+impl Filter {
+    ///Creates new filter.
+    pub fn new<T: AsRef<str>>(name: &str, patterns: &[T]) -> Self {
+        Self {
+            name: name.to_owned(),
+            patterns: patterns.iter().map(|pattern| pattern.as_ref().to_owned()).collect(),
+        }
+    }
+
+    ///Parses list of filters, e.g. `Logs (*.log)|*.log|All files|*.*`.
+    ///
+    ///Whitespaces around names and patterns are ignored, as well as empty patterns.
+    pub fn parse(text: &str) -> core::result::Result<Vec<Self>, ParseFilterError> {
+        let mut result = Vec::new();
+
+        if text.trim().is_empty() {
+            return Ok(result);
+        }
+
+        let mut parts = text.split('|');
+        while let Some(name) = parts.next() {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(ParseFilterError::EmptyName(result.len()));
+            }
+
+            let patterns: Vec<String> = match parts.next() {
+                Some(patterns) => patterns.split(';').map(str::trim).filter(|pattern| !pattern.is_empty()).map(str::to_owned).collect(),
+                None => Vec::new(),
+            };
+
+            if patterns.is_empty() {
+                return Err(ParseFilterError::MissingPatterns(name.to_owned()));
+            }
+
+            result.push(Self {
+                name: name.to_owned(),
+                patterns,
+            });
+        }
+
+        Ok(result)
+    }
+
+    ///Encodes list of filters into format of `OPENFILENAMEW::lpstrFilter`.
+    ///
+    ///Each name and its patterns are terminated by null character, while list is terminated by additional one.
+    pub fn encode(filters: &[Self]) -> Vec<u16> {
+        let mut result = Vec::new();
+
+        for filter in filters {
+            result.extend(filter.name.encode_utf16());
+            result.push(0);
+            result.extend(filter.patterns.join(";").encode_utf16());
+            result.push(0);
+        }
+
+        //Empty list must be terminated by two null characters too.
+        if result.is_empty() {
+            result.push(0);
+        }
+        result.push(0);
+
+        result
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}|{}", self.name, self.patterns.join(";"))
+    }
+}
+
+///Splits buffer, filled by open file dialog, into paths of selected files.
+///
+///Multiple files are written as directory followed by file names, each terminated by null character,
+///while single file is written as full path. They are distinguished by character preceding file name,
+///which is null only in the former case.
+///
+///# Parameters
+///
+///* ```buffer``` - Buffer of dialog, which can contain leftovers of initial file name after written paths.
+///* ```file_offset``` - Offset of the first file name, as returned in `nFileOffset`.
+///
+///# Return
+///
+///Paths as UTF-16 strings without null character.
+pub fn split_files(buffer: &[u16], file_offset: usize) -> Vec<Vec<u16>> {
+    const SEPARATOR: u16 = b'\\' as u16;
+
+    let is_multi = file_offset > 0 && file_offset <= buffer.len() && buffer[file_offset - 1] == 0;
+    if !is_multi {
+        let len = buffer.iter().position(|ch| *ch == 0).unwrap_or(buffer.len());
+        return match len {
+            0 => Vec::new(),
+            len => vec![buffer[..len].to_vec()],
+        };
+    }
+
+    let dir = &buffer[..file_offset - 1];
+    //Root directory already ends with separator, e.g. `C:\`.
+    let is_separated = dir.last().map(|ch| *ch == SEPARATOR || *ch == b'/' as u16).unwrap_or(true);
+
+    let mut files = Vec::new();
+    for name in buffer[file_offset..].split(|ch| *ch == 0).take_while(|name| !name.is_empty()) {
+        let mut path = dir.to_vec();
+        if !is_separated {
+            path.push(SEPARATOR);
+        }
+        path.extend_from_slice(name);
+        files.push(path);
+    }
+
+    match files.is_empty() {
+        true => vec![dir.to_vec()],
+        false => files,
+    }
+}
+
+#[cfg(windows)]
+fn from_wide(text: &[u16]) -> path::PathBuf {
+    ffi::OsString::from_wide(text).into()
+}
+
+#[cfg(windows)]
+//Long paths are limited to 32767 characters.
+const FILE_BUFFER_LEN: usize = 32768;
+#[cfg(windows)]
+const MULTI_FILE_BUFFER_LEN: usize = 65536;
+
+#[cfg(windows)]
+//Common parameters of open and save dialogs.
+struct FileDialog {
+    parent: HWND,
+    title: Option<Vec<u16>>,
+    initial_dir: Option<Vec<u16>>,
+    file_name: Vec<u16>,
+    default_extension: Option<Vec<u16>>,
+    filters: Vec<u16>,
+    filter_index: DWORD,
+}
+
+#[cfg(windows)]
+impl FileDialog {
+    fn new() -> Self {
+        Self {
+            parent: ptr::null_mut(),
+            title: None,
+            initial_dir: None,
+            file_name: Vec::new(),
+            default_extension: None,
+            filters: Vec::new(),
+            filter_index: 0,
+        }
+    }
+
+    fn set_filters(&mut self, filters: &[Filter]) {
+        self.filters = match filters.is_empty() {
+            true => Vec::new(),
+            false => Filter::encode(filters),
+        };
+        self.filter_index = match filters.is_empty() {
+            true => 0,
+            false => 1,
+        };
+    }
+
+    //Returns buffer with selected files and offset of the first file name, or `None` if dialog is cancelled.
+    fn show(&self, flags: DWORD, buffer_len: usize, is_save: bool) -> Result<Option<(Vec<u16>, usize)>> {
+        let mut buffer = vec![0u16; buffer_len];
+        let len = core::cmp::min(self.file_name.len(), buffer_len - 1);
+        buffer[..len].copy_from_slice(&self.file_name[..len]);
+
+        let mut config: OPENFILENAMEW = unsafe { mem::zeroed() };
+        config.lStructSize = mem::size_of::<OPENFILENAMEW>() as DWORD;
+        config.hwndOwner = self.parent;
+        config.lpstrFilter = match self.filters.is_empty() {
+            true => ptr::null(),
+            false => self.filters.as_ptr(),
+        };
+        config.nFilterIndex = self.filter_index;
+        config.lpstrFile = buffer.as_mut_ptr();
+        config.nMaxFile = buffer.len() as DWORD;
+        config.lpstrInitialDir = as_ptr(&self.initial_dir);
+        config.lpstrTitle = as_ptr(&self.title);
+        config.Flags = flags | OFN_EXPLORER | OFN_NOCHANGEDIR;
+        config.lpstrDefExt = as_ptr(&self.default_extension);
+
+        let result = unsafe {
+            match is_save {
+                true => GetSaveFileNameW(&mut config),
+                false => GetOpenFileNameW(&mut config),
+            }
+        };
+
+        match result {
+            0 => match unsafe { CommDlgExtendedError() } {
+                0 => Ok(None),
+                error => Err(ErrorCode::new(error as c_int, CommDlgError::category())),
+            },
+            _ => Ok(Some((buffer, config.nFileOffset.into()))),
+        }
+    }
+}
+
+#[cfg(windows)]
+///Dialog to select existing files.
+///
+///Selected files must exist.
+pub struct OpenFileDialog {
+    inner: FileDialog,
+    is_multi_select: bool,
+}
+
+#[cfg(windows)]
+impl OpenFileDialog {
+    ///Creates new instance.
+    pub fn new() -> Self {
+        Self {
+            inner: FileDialog::new(),
+            is_multi_select: false,
+        }
+    }
+
+    ///Sets parent's window handle, e.g. [Window::inner()](../../struct.Window.html#method.inner).
+    pub fn parent(&mut self, parent: HWND) -> &mut Self {
+        self.inner.parent = parent;
+        self
+    }
+
+    ///Sets title of dialog.
+    pub fn title<T: AsRef<ffi::OsStr>>(&mut self, title: T) -> &mut Self {
+        self.inner.title = Some(to_wide(title.as_ref()));
+        self
+    }
+
+    ///Sets directory that is shown initially.
+    pub fn initial_dir<T: AsRef<path::Path>>(&mut self, dir: T) -> &mut Self {
+        self.inner.initial_dir = Some(to_wide(dir.as_ref().as_os_str()));
+        self
+    }
+
+    ///Sets file name that is shown initially.
+    pub fn default_name<T: AsRef<ffi::OsStr>>(&mut self, name: T) -> &mut Self {
+        self.inner.file_name = name.as_ref().encode_wide().collect();
+        self
+    }
+
+    ///Sets filters, first of which is selected initially.
+    pub fn filters(&mut self, filters: &[Filter]) -> &mut Self {
+        self.inner.set_filters(filters);
+        self
+    }
+
+    ///Allows to select multiple files.
+    pub fn multi_select(&mut self) -> &mut Self {
+        self.is_multi_select = true;
+        self
+    }
+
+    ///Shows dialog and returns once user closes it.
+    ///
+    ///# Return
+    ///
+    ///* ```Ok``` - Selected files, empty if dialog is cancelled.
+    ///* ```Err``` - Error reason, code of [CommDlgError](enum.CommDlgError.html).
+    pub fn show(&self) -> Result<Vec<path::PathBuf>> {
+        let mut flags = OFN_FILEMUSTEXIST | OFN_PATHMUSTEXIST | OFN_HIDEREADONLY;
+        let mut buffer_len = FILE_BUFFER_LEN;
+        if self.is_multi_select {
+            flags |= OFN_ALLOWMULTISELECT;
+            buffer_len = MULTI_FILE_BUFFER_LEN;
+        }
+
+        match self.inner.show(flags, buffer_len, false)? {
+            Some((buffer, file_offset)) => Ok(split_files(&buffer, file_offset).iter().map(|file| from_wide(file)).collect()),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Default for OpenFileDialog {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+///Dialog to select file to save.
+///
+///User is asked to confirm overwrite of existing file.
+pub struct SaveFileDialog {
+    inner: FileDialog,
+}
+
+#[cfg(windows)]
+impl SaveFileDialog {
+    ///Creates new instance.
+    pub fn new() -> Self {
+        Self {
+            inner: FileDialog::new(),
+        }
+    }
+
+    ///Sets parent's window handle, e.g. [Window::inner()](../../struct.Window.html#method.inner).
+    pub fn parent(&mut self, parent: HWND) -> &mut Self {
+        self.inner.parent = parent;
+        self
+    }
+
+    ///Sets title of dialog.
+    pub fn title<T: AsRef<ffi::OsStr>>(&mut self, title: T) -> &mut Self {
+        self.inner.title = Some(to_wide(title.as_ref()));
+        self
+    }
+
+    ///Sets directory that is shown initially.
+    pub fn initial_dir<T: AsRef<path::Path>>(&mut self, dir: T) -> &mut Self {
+        self.inner.initial_dir = Some(to_wide(dir.as_ref().as_os_str()));
+        self
+    }
+
+    ///Sets file name that is shown initially.
+    pub fn default_name<T: AsRef<ffi::OsStr>>(&mut self, name: T) -> &mut Self {
+        self.inner.file_name = name.as_ref().encode_wide().collect();
+        self
+    }
+
+    ///Sets extension, without dot, that is appended if user types name without one.
+    pub fn default_extension<T: AsRef<ffi::OsStr>>(&mut self, extension: T) -> &mut Self {
+        self.inner.default_extension = Some(to_wide(extension.as_ref()));
+        self
+    }
+
+    ///Sets filters, first of which is selected initially.
+    pub fn filters(&mut self, filters: &[Filter]) -> &mut Self {
+        self.inner.set_filters(filters);
+        self
+    }
+
+    ///Shows dialog and returns once user closes it.
+    ///
+    ///# Return
+    ///
+    ///* ```Ok(Some)``` - Selected file.
+    ///* ```Ok(None)``` - Dialog is cancelled.
+    ///* ```Err``` - Error reason, code of [CommDlgError](enum.CommDlgError.html).
+    pub fn show(&self) -> Result<Option<path::PathBuf>> {
+        let flags = OFN_OVERWRITEPROMPT | OFN_PATHMUSTEXIST | OFN_HIDEREADONLY;
+
+        Ok(self.inner.show(flags, FILE_BUFFER_LEN, true)?.map(|(buffer, _)| {
+            let len = buffer.iter().position(|ch| *ch == 0).unwrap_or(buffer.len());
+            from_wide(&buffer[..len])
+        }))
+    }
+}
+
+#[cfg(windows)]
+impl Default for SaveFileDialog {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn browse_callback(window: HWND, msg: UINT, _: LPARAM, data: LPARAM) -> c_int {
+    if msg == BFFM_INITIALIZED && data != 0 {
+        SendMessageW(window, BFFM_SETSELECTIONW, 1, data);
+    }
+
+    0
+}
+
+#[cfg(windows)]
+//Initializes COM for current thread, as required by new style of folder dialog.
+struct ComGuard {
+    is_initialized: bool,
+}
+
+#[cfg(windows)]
+impl ComGuard {
+    fn new() -> Self {
+        //Fails if COM is already initialized with another concurrency model, which is fine.
+        let result = unsafe { CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED) };
+        Self {
+            is_initialized: result >= 0,
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for ComGuard {
+    fn drop(&mut self) {
+        if self.is_initialized {
+            unsafe {
+                CoUninitialize();
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+///Dialog to select folder.
+pub struct FolderDialog {
+    parent: HWND,
+    title: Option<Vec<u16>>,
+    initial_dir: Option<Vec<u16>>,
+}
+
+#[cfg(windows)]
+impl FolderDialog {
+    ///Creates new instance.
+    pub fn new() -> Self {
+        Self {
+            parent: ptr::null_mut(),
+            title: None,
+            initial_dir: None,
+        }
+    }
+
+    ///Sets parent's window handle, e.g. [Window::inner()](../../struct.Window.html#method.inner).
+    pub fn parent(&mut self, parent: HWND) -> &mut Self {
+        self.parent = parent;
+        self
+    }
+
+    ///Sets text displayed above folder tree.
+    pub fn title<T: AsRef<ffi::OsStr>>(&mut self, title: T) -> &mut Self {
+        self.title = Some(to_wide(title.as_ref()));
+        self
+    }
+
+    ///Sets folder that is selected initially.
+    pub fn initial_dir<T: AsRef<path::Path>>(&mut self, dir: T) -> &mut Self {
+        self.initial_dir = Some(to_wide(dir.as_ref().as_os_str()));
+        self
+    }
+
+    ///Shows dialog and returns once user closes it.
+    ///
+    ///# Return
+    ///
+    ///* ```Ok(Some)``` - Selected folder.
+    ///* ```Ok(None)``` - Dialog is cancelled or virtual folder, e.g. `Control Panel`, is selected.
+    ///* ```Err``` - Error reason.
+    pub fn show(&self) -> Result<Option<path::PathBuf>> {
+        let _com = ComGuard::new();
+        let mut display_name = vec![0u16; MAX_PATH];
+
+        let mut config: BROWSEINFOW = unsafe { mem::zeroed() };
+        config.hwndOwner = self.parent;
+        config.pszDisplayName = display_name.as_mut_ptr();
+        config.lpszTitle = as_ptr(&self.title);
+        config.ulFlags = BIF_RETURNONLYFSDIRS | BIF_NEWDIALOGSTYLE | BIF_EDITBOX;
+        config.lpfn = Some(browse_callback);
+        config.lParam = as_ptr(&self.initial_dir) as LPARAM;
+
+        let list = unsafe { SHBrowseForFolderW(&mut config) };
+        if list.is_null() {
+            return Ok(None);
+        }
+
+        let mut path = vec![0u16; MAX_PATH];
+        let result = unsafe { SHGetPathFromIDListW(list, path.as_mut_ptr()) };
+        unsafe {
+            CoTaskMemFree(list);
+        }
+
+        match result {
+            0 => Ok(None),
+            _ => {
+                let len = path.iter().position(|ch| *ch == 0).unwrap_or(path.len());
+                Ok(Some(from_wide(&path[..len])))
+            },
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Default for FolderDialog {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,4 +1,33 @@
 //! UI-related APIs
+//!
+//! On other platforms only composition of message box style and parsing of file dialog filters is available.
 
 pub mod msg_box;
-pub use self::msg_box::{MessageBox, MsgBoxResult};
+#[cfg(windows)]
+pub mod task_dialog;
+#[cfg(windows)]
+pub mod tray;
+pub mod file_dialog;
+#[cfg(windows)]
+pub use self::msg_box::MessageBox;
+pub use self::msg_box::MsgBoxResult;
+#[cfg(windows)]
+pub use self::task_dialog::TaskDialog;
+#[cfg(windows)]
+pub use self::tray::TrayIcon;
+
+#[cfg(windows)]
+//Converts text into null-terminated wide string.
+pub(crate) fn to_wide(text: &std::ffi::OsStr) -> Vec<u16> {
+    use std::os::windows::ffi::OsStrExt;
+
+    let mut result: Vec<u16> = text.encode_wide().collect();
+    result.push(0);
+    result
+}
+
+#[cfg(windows)]
+//Returns pointer to optional wide string, which is null if there is no string.
+pub(crate) fn as_ptr(text: &Option<Vec<u16>>) -> crate::sys::LPCWSTR {
+    text.as_ref().map(|text| text.as_ptr()).unwrap_or_else(core::ptr::null)
+}
@@ -1,13 +1,67 @@
 //! Message boxes APIs
+//!
+//! Composition of message box [Style](struct.Style.html) is platform independent.
 
-use crate::sys::{HWND, MessageBoxW};
-use crate::utils::Result;
+use crate::sys::{
+    MB_TIMEDOUT,
+    MB_TYPEMASK,
+    MB_ICONMASK,
+    MB_DEFMASK,
+    MB_MODEMASK,
+    MB_HELP,
+    MB_DEFBUTTON1,
+    MB_DEFBUTTON2,
+    MB_DEFBUTTON3,
+    MB_DEFBUTTON4,
+    IDOK,
+    IDCANCEL,
+    IDABORT,
+    IDRETRY,
+    IDIGNORE,
+    IDYES,
+    IDNO,
+    IDTRYAGAIN,
+    IDCONTINUE,
+};
+#[cfg(windows)]
+use crate::sys::{
+    HWND,
+    HHOOK,
+    WPARAM,
+    LPARAM,
+    LRESULT,
+    DWORD,
+    INFINITE,
+    WH_CBT,
+    HCBT_ACTIVATE,
+    ERROR_INVALID_PARAMETER,
+    MessageBoxW,
+    MessageBoxTimeoutW,
+    SetDlgItemTextW,
+    SetWindowsHookExW,
+    UnhookWindowsHookEx,
+    CallNextHookEx,
+    GetCurrentThreadId,
+};
+#[cfg(windows)]
+use crate::utils::{ErrorCode, Result};
 
+#[cfg(windows)]
 use std::os::windows::ffi::OsStrExt;
+#[cfg(windows)]
 use std::ffi;
+#[cfg(windows)]
 use std::ptr;
+#[cfg(windows)]
+use std::thread;
+#[cfg(windows)]
+use std::time::Duration;
+#[cfg(windows)]
+use std::cell::RefCell;
 use std::os::raw::{c_int, c_uint};
+use core::fmt;
 
+#[cfg(windows)]
 use crate::utils;
 
 ///Re-export WinAPI flags for `MessageBox`
@@ -38,7 +92,7 @@ pub mod flags {
     };
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 ///Result of user's interaction with message box
 pub enum MsgBoxResult {
     ///Abort button is selected
@@ -59,6 +113,8 @@ pub enum MsgBoxResult {
     TryAgain,
     ///Yes button is selected
     Yes,
+    ///Message box is closed due to timeout
+    Timeout,
     ///Unknown result code. Non zero
     Ext(c_int),
 }
@@ -66,32 +122,511 @@ pub enum MsgBoxResult {
 impl From<c_int> for MsgBoxResult {
     fn from(value: c_int) -> MsgBoxResult {
         match value {
-            1 => MsgBoxResult::Ok,
-            2 => MsgBoxResult::Cancel,
-            3 => MsgBoxResult::Abort,
-            4 => MsgBoxResult::Retry,
-            5 => MsgBoxResult::Ignore,
-            6 => MsgBoxResult::Yes,
-            7 => MsgBoxResult::No,
-            10 => MsgBoxResult::TryAgain,
-            11 => MsgBoxResult::Continue,
+            IDOK => MsgBoxResult::Ok,
+            IDCANCEL => MsgBoxResult::Cancel,
+            IDABORT => MsgBoxResult::Abort,
+            IDRETRY => MsgBoxResult::Retry,
+            IDIGNORE => MsgBoxResult::Ignore,
+            IDYES => MsgBoxResult::Yes,
+            IDNO => MsgBoxResult::No,
+            IDTRYAGAIN => MsgBoxResult::TryAgain,
+            IDCONTINUE => MsgBoxResult::Continue,
+            MB_TIMEDOUT => MsgBoxResult::Timeout,
             value => MsgBoxResult::Ext(value),
         }
     }
 }
 
+impl From<MsgBoxResult> for c_int {
+    fn from(value: MsgBoxResult) -> c_int {
+        match value {
+            MsgBoxResult::Ok => IDOK,
+            MsgBoxResult::Cancel => IDCANCEL,
+            MsgBoxResult::Abort => IDABORT,
+            MsgBoxResult::Retry => IDRETRY,
+            MsgBoxResult::Ignore => IDIGNORE,
+            MsgBoxResult::Yes => IDYES,
+            MsgBoxResult::No => IDNO,
+            MsgBoxResult::TryAgain => IDTRYAGAIN,
+            MsgBoxResult::Continue => IDCONTINUE,
+            MsgBoxResult::Timeout => MB_TIMEDOUT,
+            MsgBoxResult::Ext(value) => value,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Set of buttons
+pub enum Buttons {
+    ///Ok
+    Ok,
+    ///Ok and Cancel
+    OkCancel,
+    ///Abort, Retry and Ignore
+    AbortRetryIgnore,
+    ///Yes, No and Cancel
+    YesNoCancel,
+    ///Yes and No
+    YesNo,
+    ///Retry and Cancel
+    RetryCancel,
+    ///Cancel, Try Again and Continue
+    CancelTryContinue,
+}
+
+impl Buttons {
+    ///Creates instance from `MB_TYPEMASK` bits of flags.
+    pub fn from_raw(flags: c_uint) -> Option<Self> {
+        match flags & MB_TYPEMASK {
+            flags::MB_OK => Some(Buttons::Ok),
+            flags::MB_OKCANCEL => Some(Buttons::OkCancel),
+            flags::MB_ABORTRETRYIGNORE => Some(Buttons::AbortRetryIgnore),
+            flags::MB_YESNOCANCEL => Some(Buttons::YesNoCancel),
+            flags::MB_YESNO => Some(Buttons::YesNo),
+            flags::MB_RETRYCANCEL => Some(Buttons::RetryCancel),
+            flags::MB_CANCELTRYCONTINUE => Some(Buttons::CancelTryContinue),
+            _ => None,
+        }
+    }
+
+    ///Returns raw flags.
+    pub fn raw(self) -> c_uint {
+        match self {
+            Buttons::Ok => flags::MB_OK,
+            Buttons::OkCancel => flags::MB_OKCANCEL,
+            Buttons::AbortRetryIgnore => flags::MB_ABORTRETRYIGNORE,
+            Buttons::YesNoCancel => flags::MB_YESNOCANCEL,
+            Buttons::YesNo => flags::MB_YESNO,
+            Buttons::RetryCancel => flags::MB_RETRYCANCEL,
+            Buttons::CancelTryContinue => flags::MB_CANCELTRYCONTINUE,
+        }
+    }
+
+    ///Returns results of selecting buttons, in order of buttons.
+    ///
+    ///Closing message box, if allowed, results in `Cancel` or, when there is only `Ok` button, in `Ok`.
+    pub fn results(self) -> &'static [MsgBoxResult] {
+        match self {
+            Buttons::Ok => &[MsgBoxResult::Ok],
+            Buttons::OkCancel => &[MsgBoxResult::Ok, MsgBoxResult::Cancel],
+            Buttons::AbortRetryIgnore => &[MsgBoxResult::Abort, MsgBoxResult::Retry, MsgBoxResult::Ignore],
+            Buttons::YesNoCancel => &[MsgBoxResult::Yes, MsgBoxResult::No, MsgBoxResult::Cancel],
+            Buttons::YesNo => &[MsgBoxResult::Yes, MsgBoxResult::No],
+            Buttons::RetryCancel => &[MsgBoxResult::Retry, MsgBoxResult::Cancel],
+            Buttons::CancelTryContinue => &[MsgBoxResult::Cancel, MsgBoxResult::TryAgain, MsgBoxResult::Continue],
+        }
+    }
+
+    #[inline]
+    ///Returns number of buttons.
+    pub fn len(self) -> usize {
+        self.results().len()
+    }
+
+    ///Maps result code of message box with this set of buttons.
+    ///
+    ///Codes of buttons outside of set are reported as `MsgBoxResult::Ext`.
+    pub fn result(self, code: c_int) -> MsgBoxResult {
+        match MsgBoxResult::from(code) {
+            MsgBoxResult::Timeout => MsgBoxResult::Timeout,
+            result if self.results().contains(&result) => result,
+            _ => MsgBoxResult::Ext(code),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Icon of message box
+pub enum Icon {
+    ///Stop sign
+    Error,
+    ///Question mark
+    Question,
+    ///Exclamation point
+    Warning,
+    ///Lowercase letter `i` in a circle
+    Information,
+}
+
+impl Icon {
+    ///Creates instance from `MB_ICONMASK` bits of flags.
+    ///
+    ///Returns `Ok(None)` if there is no icon, and `Err` if bits do not correspond to single icon.
+    pub fn from_raw(flags: c_uint) -> core::result::Result<Option<Self>, StyleError> {
+        match flags & MB_ICONMASK {
+            0 => Ok(None),
+            flags::MB_ICONERROR => Ok(Some(Icon::Error)),
+            flags::MB_ICONQUESTION => Ok(Some(Icon::Question)),
+            flags::MB_ICONWARNING => Ok(Some(Icon::Warning)),
+            flags::MB_ICONINFORMATION => Ok(Some(Icon::Information)),
+            icon => Err(StyleError::InvalidIcon(icon)),
+        }
+    }
+
+    ///Returns raw flags.
+    pub fn raw(self) -> c_uint {
+        match self {
+            Icon::Error => flags::MB_ICONERROR,
+            Icon::Question => flags::MB_ICONQUESTION,
+            Icon::Warning => flags::MB_ICONWARNING,
+            Icon::Information => flags::MB_ICONINFORMATION,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Button focused initially, counting `Help` button.
+pub enum DefaultButton {
+    ///First button
+    First,
+    ///Second button
+    Second,
+    ///Third button
+    Third,
+    ///Fourth button
+    Fourth,
+}
+
+impl DefaultButton {
+    ///Creates instance from `MB_DEFMASK` bits of flags.
+    pub fn from_raw(flags: c_uint) -> Option<Self> {
+        match flags & MB_DEFMASK {
+            MB_DEFBUTTON1 => Some(DefaultButton::First),
+            MB_DEFBUTTON2 => Some(DefaultButton::Second),
+            MB_DEFBUTTON3 => Some(DefaultButton::Third),
+            MB_DEFBUTTON4 => Some(DefaultButton::Fourth),
+            _ => None,
+        }
+    }
+
+    ///Returns raw flags.
+    pub fn raw(self) -> c_uint {
+        match self {
+            DefaultButton::First => MB_DEFBUTTON1,
+            DefaultButton::Second => MB_DEFBUTTON2,
+            DefaultButton::Third => MB_DEFBUTTON3,
+            DefaultButton::Fourth => MB_DEFBUTTON4,
+        }
+    }
+
+    #[inline]
+    ///Returns zero based index of button.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Modality of message box
+pub enum Modality {
+    ///Parent window is disabled until message box is closed.
+    Application,
+    ///Same as `Application`, but message box is top-most.
+    System,
+    ///All top-level windows of current thread are disabled, even if there is no parent.
+    Task,
+}
+
+impl Modality {
+    ///Creates instance from `MB_MODEMASK` bits of flags.
+    pub fn from_raw(flags: c_uint) -> Option<Self> {
+        match flags & MB_MODEMASK {
+            flags::MB_APPLMODAL => Some(Modality::Application),
+            flags::MB_SYSTEMMODAL => Some(Modality::System),
+            flags::MB_TASKMODAL => Some(Modality::Task),
+            _ => None,
+        }
+    }
+
+    ///Returns raw flags.
+    pub fn raw(self) -> c_uint {
+        match self {
+            Modality::Application => flags::MB_APPLMODAL,
+            Modality::System => flags::MB_SYSTEMMODAL,
+            Modality::Task => flags::MB_TASKMODAL,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Invalid combination of message box flags
+pub enum StyleError {
+    ///Button bits do not correspond to set of buttons.
+    InvalidButtons(c_uint),
+    ///Icon bits do not correspond to single icon.
+    InvalidIcon(c_uint),
+    ///Default button bits are out of range.
+    InvalidDefaultButton(c_uint),
+    ///Modality bits do not correspond to single modality.
+    InvalidModality(c_uint),
+    ///Default button doesn't exist.
+    MissingDefaultButton {
+        ///Default button.
+        default: DefaultButton,
+        ///Number of buttons, including `Help`.
+        count: usize,
+    },
+}
+
+impl fmt::Display for StyleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StyleError::InvalidButtons(flags) => write!(f, "Invalid buttons 0x{:x}", flags),
+            StyleError::InvalidIcon(flags) => write!(f, "Invalid icon 0x{:x}", flags),
+            StyleError::InvalidDefaultButton(flags) => write!(f, "Invalid default button 0x{:x}", flags),
+            StyleError::InvalidModality(flags) => write!(f, "Invalid modality 0x{:x}", flags),
+            StyleError::MissingDefaultButton { default, count } => write!(f, "Default button #{} is out of {} buttons", default.index() + 1, count),
+        }
+    }
+}
+
+impl std::error::Error for StyleError {}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Typed style of message box, which cannot contain conflicting flags.
+pub struct Style {
+    buttons: Buttons,
+    icon: Option<Icon>,
+    default_button: DefaultButton,
+    modality: Modality,
+    is_help: bool,
+}
+
+impl Style {
+    ///Creates new style with specified buttons, without icon, and with first button as default.
+    pub const fn new(buttons: Buttons) -> Self {
+        Self {
+            buttons,
+            icon: None,
+            default_button: DefaultButton::First,
+            modality: Modality::Application,
+            is_help: false,
+        }
+    }
+
+    ///Parses style from raw flags.
+    ///
+    ///Flags outside of buttons, icon, default button, modality and `MB_HELP` are ignored.
+    pub fn from_raw(flags: c_uint) -> core::result::Result<Self, StyleError> {
+        let buttons = match Buttons::from_raw(flags) {
+            Some(buttons) => buttons,
+            None => return Err(StyleError::InvalidButtons(flags & MB_TYPEMASK)),
+        };
+        let icon = Icon::from_raw(flags)?;
+        let default_button = match DefaultButton::from_raw(flags) {
+            Some(default_button) => default_button,
+            None => return Err(StyleError::InvalidDefaultButton(flags & MB_DEFMASK)),
+        };
+        let modality = match Modality::from_raw(flags) {
+            Some(modality) => modality,
+            None => return Err(StyleError::InvalidModality(flags & MB_MODEMASK)),
+        };
+
+        Ok(Self {
+            buttons,
+            icon,
+            default_button,
+            modality,
+            is_help: flags & MB_HELP != 0,
+        })
+    }
+
+    ///Returns raw flags.
+    pub fn raw(&self) -> c_uint {
+        let mut result = self.buttons.raw() | self.default_button.raw() | self.modality.raw();
+        if let Some(icon) = self.icon {
+            result |= icon.raw();
+        }
+        if self.is_help {
+            result |= MB_HELP;
+        }
+        result
+    }
+
+    ///Sets buttons.
+    pub fn buttons(mut self, buttons: Buttons) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    ///Sets icon.
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    ///Removes icon.
+    pub fn no_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    ///Sets button focused initially.
+    pub fn default_button(mut self, default_button: DefaultButton) -> Self {
+        self.default_button = default_button;
+        self
+    }
+
+    ///Sets modality.
+    pub fn modality(mut self, modality: Modality) -> Self {
+        self.modality = modality;
+        self
+    }
+
+    ///Adds `Help` button after other buttons, which sends `WM_HELP` to parent window.
+    pub fn help(mut self) -> Self {
+        self.is_help = true;
+        self
+    }
+
+    #[inline]
+    ///Returns buttons.
+    pub fn get_buttons(&self) -> Buttons {
+        self.buttons
+    }
+
+    #[inline]
+    ///Returns icon.
+    pub fn get_icon(&self) -> Option<Icon> {
+        self.icon
+    }
+
+    #[inline]
+    ///Returns default button.
+    pub fn get_default_button(&self) -> DefaultButton {
+        self.default_button
+    }
+
+    #[inline]
+    ///Returns modality.
+    pub fn get_modality(&self) -> Modality {
+        self.modality
+    }
+
+    #[inline]
+    ///Returns whether `Help` button is present.
+    pub fn is_help(&self) -> bool {
+        self.is_help
+    }
+
+    #[inline]
+    ///Returns number of buttons, including `Help`.
+    pub fn button_count(&self) -> usize {
+        self.buttons.len() + self.is_help as usize
+    }
+
+    ///Checks that default button exists.
+    pub fn validate(&self) -> core::result::Result<(), StyleError> {
+        match self.default_button.index() < self.button_count() {
+            true => Ok(()),
+            false => Err(StyleError::MissingDefaultButton {
+                default: self.default_button,
+                count: self.button_count(),
+            }),
+        }
+    }
+
+    #[inline]
+    ///Maps result code of message box with this style.
+    pub fn result(&self, code: c_int) -> MsgBoxResult {
+        self.buttons.result(code)
+    }
+}
+
+impl Default for Style {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Buttons::Ok)
+    }
+}
+
+#[cfg(windows)]
+type Labels = Vec<(c_int, Vec<u16>)>;
+
+#[cfg(windows)]
+thread_local! {
+    static LABELS: RefCell<Option<Labels>> = const { RefCell::new(None) };
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn relabel_hook(code: c_int, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    //First window activated is message box itself.
+    if code == HCBT_ACTIVATE {
+        if let Some(labels) = LABELS.with(|labels| labels.borrow_mut().take()) {
+            let dialog = w_param as HWND;
+            for (id, text) in labels.iter() {
+                SetDlgItemTextW(dialog, *id, text.as_ptr());
+            }
+        }
+    }
+
+    CallNextHookEx(ptr::null_mut(), code, w_param, l_param)
+}
+
+#[cfg(windows)]
+//Relabels buttons of next message box shown on current thread.
+struct RelabelHook {
+    hook: HHOOK,
+}
+
+#[cfg(windows)]
+impl RelabelHook {
+    fn install(labels: &Labels) -> Result<Option<Self>> {
+        if labels.is_empty() {
+            return Ok(None);
+        }
+
+        LABELS.with(|cell| *cell.borrow_mut() = Some(labels.clone()));
+
+        match unsafe { SetWindowsHookExW(WH_CBT, Some(relabel_hook), ptr::null_mut(), GetCurrentThreadId()) } {
+            hook if hook.is_null() => {
+                LABELS.with(|cell| cell.borrow_mut().take());
+                Err(utils::get_last_error())
+            },
+            hook => Ok(Some(Self {
+                hook
+            })),
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for RelabelHook {
+    fn drop(&mut self) {
+        unsafe {
+            UnhookWindowsHookEx(self.hook);
+        }
+        LABELS.with(|cell| cell.borrow_mut().take());
+    }
+}
+
+#[cfg(windows)]
 ///Message box modal dialogue
 ///
 ///If title is not specified, then Default is `Error`
 ///
 ///The default type is `flags::MB_OK`
+///
+///Style is validated before showing only if it is configured through typed setters,
+///e.g. [buttons()](#method.buttons), as raw flags are passed to system as they are.
+#[derive(Clone)]
 pub struct MessageBox {
     parent: HWND,
     text: Vec<u16>,
     caption: Option<Vec<u16>>,
     flags: c_uint,
+    //Whether style is set through typed setters.
+    is_typed: bool,
+    labels: Labels,
 }
 
+#[cfg(windows)]
+//Window handles can be used from any thread.
+struct SendMessageBox(MessageBox);
+#[cfg(windows)]
+unsafe impl Send for SendMessageBox {}
+
+#[cfg(windows)]
 impl MessageBox {
     ///Creates new instance with provided text message.
     ///
@@ -105,6 +640,8 @@ impl MessageBox {
             text,
             caption: None,
             flags: flags::MB_OK,
+            is_typed: false,
+            labels: Vec::new(),
         }
     }
 
@@ -130,18 +667,67 @@ impl MessageBox {
         self
     }
 
-    ///Sets flags value.
+    ///Sets flags value, which are passed to system without validation.
     pub fn set_flags(&mut self, flags: c_uint) -> &mut Self {
         self.flags = flags;
+        self.is_typed = false;
         self
     }
 
     ///Adds flags to existing ones.
+    ///
+    ///If flags change style, e.g. buttons, then it is passed to system without validation, same as with `set_flags`.
     pub fn flags(&mut self, flags: c_uint) -> &mut Self {
         self.flags |= flags;
+        if flags & (MB_TYPEMASK | MB_ICONMASK | MB_DEFMASK | MB_MODEMASK | MB_HELP) != 0 {
+            self.is_typed = false;
+        }
+        self
+    }
+
+    ///Sets typed style, keeping other flags, e.g. `MB_TOPMOST`.
+    pub fn style(&mut self, style: Style) -> &mut Self {
+        self.flags &= !(MB_TYPEMASK | MB_ICONMASK | MB_DEFMASK | MB_MODEMASK | MB_HELP);
+        self.flags |= style.raw();
+        self.is_typed = true;
+        self
+    }
+
+    ///Sets buttons, replacing existing ones.
+    pub fn buttons(&mut self, buttons: Buttons) -> &mut Self {
+        self.flags = (self.flags & !MB_TYPEMASK) | buttons.raw();
+        self.is_typed = true;
+        self
+    }
+
+    ///Sets icon, replacing existing one.
+    pub fn icon(&mut self, icon: Icon) -> &mut Self {
+        self.flags = (self.flags & !MB_ICONMASK) | icon.raw();
+        self.is_typed = true;
         self
     }
 
+    ///Sets button focused initially.
+    pub fn default_button(&mut self, default_button: DefaultButton) -> &mut Self {
+        self.flags = (self.flags & !MB_DEFMASK) | default_button.raw();
+        self.is_typed = true;
+        self
+    }
+
+    ///Sets modality.
+    pub fn modality(&mut self, modality: Modality) -> &mut Self {
+        self.flags = (self.flags & !MB_MODEMASK) | modality.raw();
+        self.is_typed = true;
+        self
+    }
+
+    ///Returns typed style, if flags are valid.
+    pub fn get_style(&self) -> core::result::Result<Style, StyleError> {
+        let style = Style::from_raw(self.flags)?;
+        style.validate()?;
+        Ok(style)
+    }
+
     ///Sets new text of message box
     pub fn text<T: AsRef<ffi::OsStr>>(&mut self, text: T) -> &mut Self {
         let text = text.as_ref();
@@ -178,13 +764,75 @@ impl MessageBox {
         self
     }
 
-    ///Shows message box and returns once user closes it
-    pub fn show(&self) -> Result<MsgBoxResult> {
+    ///Sets label of button, replacing default one.
+    ///
+    ///# Parameters
+    ///
+    ///* ```button``` - Button, identified by result of selecting it, e.g. `MsgBoxResult::Retry`.
+    ///* ```text``` - New label. Use `&` to mark accelerator key.
+    pub fn label<T: AsRef<ffi::OsStr>>(&mut self, button: MsgBoxResult, text: T) -> &mut Self {
+        let id = c_int::from(button);
+        let mut text: Vec<u16> = text.as_ref().encode_wide().collect();
+        text.push(0);
+
+        match self.labels.iter_mut().find(|(label_id, _)| *label_id == id) {
+            Some(label) => label.1 = text,
+            None => self.labels.push((id, text)),
+        }
+
+        self
+    }
+
+    fn show_inner(&self, timeout: Option<DWORD>) -> Result<MsgBoxResult> {
+        let style = match self.is_typed {
+            true => match self.get_style() {
+                Ok(style) => Some(style),
+                Err(_) => return Err(ErrorCode::new_system(ERROR_INVALID_PARAMETER as c_int)),
+            },
+            false => None,
+        };
         let caption = self.caption.as_ref().map(|caption| caption.as_ptr()).unwrap_or_else(|| ptr::null());
+        let _hook = RelabelHook::install(&self.labels)?;
 
-        match unsafe { MessageBoxW(self.parent, self.text.as_ptr(), caption, self.flags) } {
+        let result = unsafe {
+            match timeout {
+                Some(timeout) => MessageBoxTimeoutW(self.parent, self.text.as_ptr(), caption, self.flags, 0, timeout),
+                None => MessageBoxW(self.parent, self.text.as_ptr(), caption, self.flags),
+            }
+        };
+
+        match result {
             0 => Err(utils::get_last_error()),
-            n => Ok(MsgBoxResult::from(n)),
+            n => Ok(match style {
+                Some(style) => style.result(n),
+                None => MsgBoxResult::from(n),
+            }),
         }
     }
+
+    #[inline]
+    ///Shows message box and returns once user closes it
+    ///
+    ///Fails with `ERROR_INVALID_PARAMETER` if typed style is invalid, see [get_style()](#method.get_style).
+    pub fn show(&self) -> Result<MsgBoxResult> {
+        self.show_inner(None)
+    }
+
+    ///Shows message box and returns once user closes it or timeout expires.
+    ///
+    ///Returns `MsgBoxResult::Timeout` if message box is closed due to timeout.
+    pub fn show_with_timeout(&self, timeout: Duration) -> Result<MsgBoxResult> {
+        //INFINITE is reserved.
+        let timeout = core::cmp::min(timeout.as_millis(), (INFINITE - 1) as u128) as DWORD;
+        self.show_inner(Some(timeout))
+    }
+
+    ///Shows message box on separate thread, without blocking current one.
+    ///
+    ///Result can be retrieved by joining returned thread.
+    pub fn show_async(&self) -> thread::JoinHandle<Result<MsgBoxResult>> {
+        let msg_box = SendMessageBox(self.clone());
+
+        thread::spawn(move || msg_box.0.show())
+    }
 }
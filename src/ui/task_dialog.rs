@@ -0,0 +1,531 @@
+//! Task dialog APIs
+//!
+//! Task dialog requires Common Controls v6, which is used only if application's manifest
+//! declares dependency on `Microsoft.Windows.Common-Controls` version `6.0.0.0`.
+//! Otherwise [show](struct.TaskDialog.html#method.show) fails with `ERROR_PROC_NOT_FOUND`.
+
+use crate::sys::*;
+use crate::utils::{self, Result};
+use super::{to_wide, as_ptr};
+
+use std::ffi;
+use std::ptr;
+use std::mem;
+use std::any::Any;
+use std::panic;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Standard icon of task dialog.
+pub enum TaskDialogIcon {
+    ///Exclamation point.
+    Warning,
+    ///Stop sign.
+    Error,
+    ///Letter `i` in circle.
+    Information,
+    ///Security shield.
+    Shield,
+}
+
+impl TaskDialogIcon {
+    fn raw(self) -> LPCWSTR {
+        match self {
+            TaskDialogIcon::Warning => TD_WARNING_ICON,
+            TaskDialogIcon::Error => TD_ERROR_ICON,
+            TaskDialogIcon::Information => TD_INFORMATION_ICON,
+            TaskDialogIcon::Shield => TD_SHIELD_ICON,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Element of task dialog, which text can be changed while it is shown.
+pub enum TaskDialogElement {
+    ///Main instruction.
+    MainInstruction,
+    ///Content.
+    Content,
+    ///Expanded information.
+    ExpandedInformation,
+    ///Footer.
+    Footer,
+}
+
+impl TaskDialogElement {
+    fn raw(self) -> WPARAM {
+        match self {
+            TaskDialogElement::MainInstruction => TDE_MAIN_INSTRUCTION,
+            TaskDialogElement::Content => TDE_CONTENT,
+            TaskDialogElement::ExpandedInformation => TDE_EXPANDED_INFORMATION,
+            TaskDialogElement::Footer => TDE_FOOTER,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///State of progress bar.
+pub enum ProgressState {
+    ///Green.
+    Normal,
+    ///Red.
+    Error,
+    ///Yellow.
+    Paused,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+///Notification of shown task dialog.
+pub enum TaskDialogEvent<'a> {
+    ///Dialog is created, but not yet shown.
+    Created,
+    ///Button is clicked. Return `false` from callback to keep dialog open.
+    ButtonClicked(c_int),
+    ///Radio button is selected.
+    RadioButtonClicked(c_int),
+    ///Hyperlink is clicked, with its `href`.
+    HyperlinkClicked(&'a str),
+    ///Timer, with number of milliseconds since dialog is created or timer is reset.
+    ///
+    ///Sent approximately every 200 milliseconds, if enabled by [timer()](struct.TaskDialog.html#method.timer).
+    Timer(DWORD),
+    ///Verification checkbox is clicked, with its new state.
+    VerificationClicked(bool),
+    ///Expando button is clicked, with whether information is expanded now.
+    ExpandoClicked(bool),
+    ///Help is requested via `F1`.
+    Help,
+    ///Dialog is being destroyed.
+    Destroyed,
+}
+
+///Handle to shown task dialog, passed to callback.
+pub struct TaskDialogController {
+    window: HWND,
+}
+
+impl TaskDialogController {
+    #[inline]
+    ///Returns dialog's window.
+    pub fn window(&self) -> HWND {
+        self.window
+    }
+
+    #[inline]
+    fn send(&self, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+        unsafe { SendMessageW(self.window, msg, w_param, l_param) }
+    }
+
+    #[inline]
+    ///Sets position of progress bar.
+    pub fn set_progress(&self, pos: c_int) {
+        self.send(TDM_SET_PROGRESS_BAR_POS, pos as WPARAM, 0);
+    }
+
+    #[inline]
+    ///Sets range of progress bar. Default is from 0 to 100.
+    pub fn set_progress_range(&self, min: u16, max: u16) {
+        self.send(TDM_SET_PROGRESS_BAR_RANGE, 0, (min as LPARAM) | ((max as LPARAM) << 16));
+    }
+
+    ///Sets state of progress bar.
+    pub fn set_progress_state(&self, state: ProgressState) {
+        let state = match state {
+            ProgressState::Normal => PBST_NORMAL,
+            ProgressState::Error => PBST_ERROR,
+            ProgressState::Paused => PBST_PAUSED,
+        };
+        self.send(TDM_SET_PROGRESS_BAR_STATE, state, 0);
+    }
+
+    #[inline]
+    ///Starts or stops marquee animation of progress bar.
+    ///
+    ///Dialog must be created with marquee progress bar.
+    pub fn set_marquee(&self, is_enabled: bool) {
+        self.send(TDM_SET_PROGRESS_BAR_MARQUEE, is_enabled as WPARAM, 0);
+    }
+
+    ///Changes text of element.
+    ///
+    ///Element must have had text, when dialog is shown.
+    pub fn set_text<T: AsRef<ffi::OsStr>>(&self, element: TaskDialogElement, text: T) {
+        let text = to_wide(text.as_ref());
+        self.send(TDM_SET_ELEMENT_TEXT, element.raw(), text.as_ptr() as LPARAM);
+    }
+
+    #[inline]
+    ///Enables or disables button.
+    pub fn enable_button(&self, id: c_int, is_enabled: bool) {
+        self.send(TDM_ENABLE_BUTTON, id as WPARAM, is_enabled as LPARAM);
+    }
+
+    #[inline]
+    ///Enables or disables radio button.
+    pub fn enable_radio_button(&self, id: c_int, is_enabled: bool) {
+        self.send(TDM_ENABLE_RADIO_BUTTON, id as WPARAM, is_enabled as LPARAM);
+    }
+
+    #[inline]
+    ///Clicks button, as if user did it.
+    pub fn click_button(&self, id: c_int) {
+        self.send(TDM_CLICK_BUTTON, id as WPARAM, 0);
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Result of user's interaction with task dialog.
+pub struct TaskDialogResult {
+    ///Identifier of clicked button, e.g. `IDCANCEL` if dialog is cancelled.
+    pub button: c_int,
+    ///Identifier of selected radio button, if any.
+    pub radio_button: Option<c_int>,
+    ///Whether verification checkbox is checked.
+    pub verification_checked: bool,
+}
+
+struct CallbackState<'a> {
+    callback: &'a mut dyn FnMut(&TaskDialogController, TaskDialogEvent<'_>) -> bool,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+unsafe fn wide_str(text: *const u16) -> String {
+    if text.is_null() {
+        return String::new();
+    }
+
+    let mut len = 0;
+    while *text.add(len) != 0 {
+        len += 1;
+    }
+
+    String::from_utf16_lossy(std::slice::from_raw_parts(text, len))
+}
+
+unsafe extern "system" fn task_dialog_callback(window: HWND, msg: UINT, w_param: WPARAM, l_param: LPARAM, data: LONG_PTR) -> HRESULT {
+    let state = &mut *(data as *mut CallbackState<'_>);
+    let controller = TaskDialogController {
+        window
+    };
+
+    //Dialog is closing due to panic.
+    if state.panic.is_some() {
+        return S_OK;
+    }
+
+    let link;
+    let event = match msg {
+        TDN_CREATED => TaskDialogEvent::Created,
+        TDN_BUTTON_CLICKED => TaskDialogEvent::ButtonClicked(w_param as c_int),
+        TDN_RADIO_BUTTON_CLICKED => TaskDialogEvent::RadioButtonClicked(w_param as c_int),
+        TDN_HYPERLINK_CLICKED => {
+            link = wide_str(l_param as *const u16);
+            TaskDialogEvent::HyperlinkClicked(&link)
+        },
+        TDN_TIMER => TaskDialogEvent::Timer(w_param as DWORD),
+        TDN_VERIFICATION_CLICKED => TaskDialogEvent::VerificationClicked(w_param != 0),
+        TDN_EXPANDO_BUTTON_CLICKED => TaskDialogEvent::ExpandoClicked(w_param != 0),
+        TDN_HELP => TaskDialogEvent::Help,
+        TDN_DESTROYED => TaskDialogEvent::Destroyed,
+        _ => return S_OK,
+    };
+    let is_button = msg == TDN_BUTTON_CLICKED;
+
+    //Panic must not cross FFI boundary, so it is resumed once dialog is closed.
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| (state.callback)(&controller, event))) {
+        Ok(false) if is_button => S_FALSE,
+        Ok(_) => S_OK,
+        Err(error) => {
+            state.panic = Some(error);
+            controller.click_button(IDCANCEL);
+            S_OK
+        }
+    }
+}
+
+//Keeps Common Controls loaded while dialog is shown.
+struct Library(HMODULE);
+
+impl Library {
+    fn load(name: &str) -> Result<Self> {
+        let name = to_wide(name.as_ref());
+        match unsafe { LoadLibraryW(name.as_ptr()) } {
+            module if module.is_null() => Err(utils::get_last_error()),
+            module => Ok(Library(module)),
+        }
+    }
+
+    fn task_dialog_indirect(&self) -> Result<TaskDialogIndirectFn> {
+        match unsafe { GetProcAddress(self.0, b"TaskDialogIndirect\0".as_ptr() as LPCSTR) } {
+            func if func.is_null() => Err(utils::get_last_error()),
+            func => Ok(unsafe { mem::transmute::<FARPROC, TaskDialogIndirectFn>(func) }),
+        }
+    }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        unsafe {
+            FreeLibrary(self.0);
+        }
+    }
+}
+
+///Task dialog, which is more flexible alternative to message box.
+///
+///By default it has only `OK` button.
+pub struct TaskDialog {
+    parent: HWND,
+    title: Option<Vec<u16>>,
+    main_instruction: Option<Vec<u16>>,
+    content: Option<Vec<u16>>,
+    expanded_information: Option<Vec<u16>>,
+    footer: Option<Vec<u16>>,
+    verification: Option<Vec<u16>>,
+    icon: Option<TaskDialogIcon>,
+    footer_icon: Option<TaskDialogIcon>,
+    common_buttons: c_int,
+    buttons: Vec<(c_int, Vec<u16>)>,
+    radio_buttons: Vec<(c_int, Vec<u16>)>,
+    default_button: c_int,
+    default_radio_button: c_int,
+    flags: c_int,
+}
+
+impl TaskDialog {
+    ///Creates new instance with provided main instruction.
+    pub fn new<T: AsRef<ffi::OsStr>>(main_instruction: T) -> Self {
+        Self {
+            parent: ptr::null_mut(),
+            title: None,
+            main_instruction: Some(to_wide(main_instruction.as_ref())),
+            content: None,
+            expanded_information: None,
+            footer: None,
+            verification: None,
+            icon: None,
+            footer_icon: None,
+            common_buttons: 0,
+            buttons: Vec::new(),
+            radio_buttons: Vec::new(),
+            default_button: 0,
+            default_radio_button: 0,
+            flags: TDF_ALLOW_DIALOG_CANCELLATION,
+        }
+    }
+
+    ///Sets parent's window handle.
+    pub fn parent(&mut self, parent: HWND) -> &mut Self {
+        self.parent = parent;
+        self
+    }
+
+    ///Sets title of dialog's window.
+    pub fn title<T: AsRef<ffi::OsStr>>(&mut self, text: T) -> &mut Self {
+        self.title = Some(to_wide(text.as_ref()));
+        self
+    }
+
+    ///Sets main instruction.
+    pub fn main_instruction<T: AsRef<ffi::OsStr>>(&mut self, text: T) -> &mut Self {
+        self.main_instruction = Some(to_wide(text.as_ref()));
+        self
+    }
+
+    ///Sets content, i.e. text below main instruction.
+    ///
+    ///If hyperlinks are enabled, it can contain `<a href="...">text</a>`.
+    pub fn content<T: AsRef<ffi::OsStr>>(&mut self, text: T) -> &mut Self {
+        self.content = Some(to_wide(text.as_ref()));
+        self
+    }
+
+    ///Sets expanded information, which is shown when user clicks expando button.
+    pub fn expanded_information<T: AsRef<ffi::OsStr>>(&mut self, text: T) -> &mut Self {
+        self.expanded_information = Some(to_wide(text.as_ref()));
+        self
+    }
+
+    ///Sets footer.
+    pub fn footer<T: AsRef<ffi::OsStr>>(&mut self, text: T) -> &mut Self {
+        self.footer = Some(to_wide(text.as_ref()));
+        self
+    }
+
+    ///Sets main icon.
+    pub fn icon(&mut self, icon: TaskDialogIcon) -> &mut Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    ///Sets footer icon.
+    pub fn footer_icon(&mut self, icon: TaskDialogIcon) -> &mut Self {
+        self.footer_icon = Some(icon);
+        self
+    }
+
+    ///Adds common buttons, combination of `TDCBF_*` flags.
+    ///
+    ///Their identifiers are `IDOK`, `IDYES` and etc.
+    pub fn common_buttons(&mut self, buttons: c_int) -> &mut Self {
+        self.common_buttons |= buttons;
+        self
+    }
+
+    ///Adds custom button.
+    ///
+    ///Identifier should not clash with identifiers of common buttons.
+    pub fn button<T: AsRef<ffi::OsStr>>(&mut self, id: c_int, text: T) -> &mut Self {
+        self.buttons.push((id, to_wide(text.as_ref())));
+        self
+    }
+
+    ///Adds command link, i.e. custom button displayed as large link.
+    ///
+    ///Text after first new line is displayed as note below.
+    ///Any custom button is displayed as command link, once it is used.
+    pub fn command_link<T: AsRef<ffi::OsStr>>(&mut self, id: c_int, text: T) -> &mut Self {
+        self.flags |= TDF_USE_COMMAND_LINKS;
+        self.button(id, text)
+    }
+
+    ///Adds radio button.
+    pub fn radio_button<T: AsRef<ffi::OsStr>>(&mut self, id: c_int, text: T) -> &mut Self {
+        self.radio_buttons.push((id, to_wide(text.as_ref())));
+        self
+    }
+
+    ///Sets button that is selected by default.
+    pub fn default_button(&mut self, id: c_int) -> &mut Self {
+        self.default_button = id;
+        self
+    }
+
+    ///Sets radio button that is selected by default.
+    ///
+    ///First one is selected, unless specified.
+    pub fn default_radio_button(&mut self, id: c_int) -> &mut Self {
+        self.default_radio_button = id;
+        self
+    }
+
+    ///Adds verification checkbox, e.g. `Do not show again`.
+    pub fn verification<T: AsRef<ffi::OsStr>>(&mut self, text: T, is_checked: bool) -> &mut Self {
+        self.verification = Some(to_wide(text.as_ref()));
+        match is_checked {
+            true => self.flags |= TDF_VERIFICATION_FLAG_CHECKED,
+            false => self.flags &= !TDF_VERIFICATION_FLAG_CHECKED,
+        }
+        self
+    }
+
+    ///Shows progress bar, which can be updated from callback.
+    ///
+    ///If `is_marquee`, progress bar displays animation instead of position.
+    pub fn progress_bar(&mut self, is_marquee: bool) -> &mut Self {
+        match is_marquee {
+            true => self.flags |= TDF_SHOW_MARQUEE_PROGRESS_BAR,
+            false => self.flags |= TDF_SHOW_PROGRESS_BAR,
+        }
+        self
+    }
+
+    ///Enables hyperlinks in content, expanded information and footer.
+    ///
+    ///Clicks are reported to callback and not handled otherwise.
+    pub fn hyperlinks(&mut self) -> &mut Self {
+        self.flags |= TDF_ENABLE_HYPERLINKS;
+        self
+    }
+
+    ///Enables timer notifications of callback.
+    pub fn timer(&mut self) -> &mut Self {
+        self.flags |= TDF_CALLBACK_TIMER;
+        self
+    }
+
+    ///Adds raw `TDF_*` flags.
+    pub fn flags(&mut self, flags: c_int) -> &mut Self {
+        self.flags |= flags;
+        self
+    }
+
+    #[inline]
+    ///Shows task dialog and returns once user closes it.
+    pub fn show(&self) -> Result<TaskDialogResult> {
+        self.show_with(|_, _| true)
+    }
+
+    ///Shows task dialog, calling callback on its notifications, and returns once it is closed.
+    ///
+    ///Callback returns whether to proceed with default action, which can be prevented only for
+    ///[ButtonClicked](enum.TaskDialogEvent.html#variant.ButtonClicked).
+    ///
+    ///If callback panics, dialog is closed and panic is resumed.
+    pub fn show_with<F: FnMut(&TaskDialogController, TaskDialogEvent<'_>) -> bool>(&self, mut callback: F) -> Result<TaskDialogResult> {
+        let library = Library::load("comctl32.dll")?;
+        let task_dialog_indirect = library.task_dialog_indirect()?;
+
+        let buttons: Vec<TASKDIALOG_BUTTON> = self.buttons.iter().map(|(id, text)| TASKDIALOG_BUTTON {
+            nButtonID: *id,
+            pszButtonText: text.as_ptr(),
+        }).collect();
+        let radio_buttons: Vec<TASKDIALOG_BUTTON> = self.radio_buttons.iter().map(|(id, text)| TASKDIALOG_BUTTON {
+            nButtonID: *id,
+            pszButtonText: text.as_ptr(),
+        }).collect();
+
+        let common_buttons = match self.common_buttons == 0 && buttons.is_empty() {
+            true => TDCBF_OK_BUTTON,
+            false => self.common_buttons,
+        };
+
+        let mut state = CallbackState {
+            callback: &mut callback,
+            panic: None,
+        };
+
+        let mut config: TASKDIALOGCONFIG = unsafe { mem::zeroed() };
+        config.cbSize = mem::size_of::<TASKDIALOGCONFIG>() as UINT;
+        config.hwndParent = self.parent;
+        config.dwFlags = self.flags;
+        config.dwCommonButtons = common_buttons;
+        config.pszWindowTitle = as_ptr(&self.title);
+        config.pszMainIcon = self.icon.map(TaskDialogIcon::raw).unwrap_or_else(ptr::null);
+        config.pszMainInstruction = as_ptr(&self.main_instruction);
+        config.pszContent = as_ptr(&self.content);
+        config.cButtons = buttons.len() as UINT;
+        config.pButtons = buttons.as_ptr();
+        config.nDefaultButton = self.default_button;
+        config.cRadioButtons = radio_buttons.len() as UINT;
+        config.pRadioButtons = radio_buttons.as_ptr();
+        config.nDefaultRadioButton = self.default_radio_button;
+        config.pszVerificationText = as_ptr(&self.verification);
+        config.pszExpandedInformation = as_ptr(&self.expanded_information);
+        config.pszFooterIcon = self.footer_icon.map(TaskDialogIcon::raw).unwrap_or_else(ptr::null);
+        config.pszFooter = as_ptr(&self.footer);
+        config.pfCallback = Some(task_dialog_callback);
+        config.lpCallbackData = &mut state as *mut CallbackState<'_> as LONG_PTR;
+
+        let mut button = 0;
+        let mut radio_button = 0;
+        let mut verification_checked = 0;
+
+        let result = unsafe { task_dialog_indirect(&config, &mut button, &mut radio_button, &mut verification_checked) };
+
+        if let Some(error) = state.panic.take() {
+            panic::resume_unwind(error);
+        }
+
+        match result {
+            S_OK => Ok(TaskDialogResult {
+                button,
+                radio_button: match self.radio_buttons.is_empty() || radio_button == 0 {
+                    true => None,
+                    false => Some(radio_button),
+                },
+                verification_checked: verification_checked != 0,
+            }),
+            error => Err(utils::from_hresult(error)),
+        }
+    }
+}
@@ -0,0 +1,611 @@
+//! Notification area icon APIs
+//!
+//! [TrayIcon](struct.TrayIcon.html) creates hidden window that receives icon's notifications.
+//! As notifications are delivered to the thread that created icon, this thread must run message loop.
+//! Use [spawn()](struct.TrayIcon.html#method.spawn) to run icon on dedicated thread.
+//!
+//! Icon is added back automatically when Explorer restarts.
+
+use crate::sys::*;
+use crate::utils::{self, ErrorCode, Result};
+use crate::raw::window::Builder;
+use crate::message_loop::MessageThread;
+use crate::Window;
+use super::to_wide;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::Once;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::{ffi, mem, panic, path, ptr};
+
+const CLASS_NAME: &str = "windows-win-tray-icon";
+const CALLBACK_MSG: UINT = WM_USER + 1;
+const ICON_ID: UINT = 1;
+
+//Copies text into fixed buffer, truncating it if necessary.
+fn copy_text(dest: &mut [WCHAR], text: &str) {
+    let mut len = 0;
+    for (dest, ch) in dest.iter_mut().zip(text.encode_utf16()) {
+        *dest = ch;
+        len += 1;
+    }
+
+    if len == dest.len() {
+        len -= 1;
+        //Do not leave half of surrogate pair.
+        if len > 0 && (0xD800..0xDC00).contains(&dest[len - 1]) {
+            len -= 1;
+        }
+    }
+
+    dest[len] = 0;
+}
+
+///Icon handle.
+///
+///Icons loaded from file or resources are destroyed on drop.
+pub struct Icon {
+    handle: HICON,
+    is_owned: bool,
+}
+
+impl Icon {
+    fn load(module: HINSTANCE, name: LPCWSTR, flags: UINT) -> Result<Self> {
+        let handle = unsafe {
+            LoadImageW(module, name, IMAGE_ICON, GetSystemMetrics(SM_CXSMICON), GetSystemMetrics(SM_CYSMICON), flags)
+        };
+
+        match handle.is_null() {
+            true => Err(utils::get_last_error()),
+            false => Ok(Self {
+                handle,
+                is_owned: true,
+            })
+        }
+    }
+
+    ///Loads icon from `.ico` file, with size of small icon.
+    pub fn from_file<T: AsRef<path::Path>>(path: T) -> Result<Self> {
+        let path = to_wide(path.as_ref().as_os_str());
+        Self::load(ptr::null_mut(), path.as_ptr(), LR_LOADFROMFILE)
+    }
+
+    ///Loads icon from module's resources by its identifier, with size of small icon.
+    ///
+    ///# Parameters:
+    ///
+    ///* ```module``` - Module with resources. If `None`, executable of current process is used.
+    ///* ```id``` - Resource identifier.
+    pub fn from_resource(module: Option<HMODULE>, id: u16) -> Result<Self> {
+        let module = module.unwrap_or_else(|| unsafe { GetModuleHandleW(ptr::null()) });
+        Self::load(module, id as usize as LPCWSTR, 0)
+    }
+
+    ///Loads icon from module's resources by its name, with size of small icon.
+    ///
+    ///# Parameters:
+    ///
+    ///* ```module``` - Module with resources. If `None`, executable of current process is used.
+    ///* ```name``` - Resource name.
+    pub fn from_resource_name(module: Option<HMODULE>, name: &str) -> Result<Self> {
+        let module = module.unwrap_or_else(|| unsafe { GetModuleHandleW(ptr::null()) });
+        let name = to_wide(name.as_ref());
+        Self::load(module, name.as_ptr(), 0)
+    }
+
+    ///Returns system's default application icon.
+    pub fn application() -> Result<Self> {
+        let handle = unsafe { LoadIconW(ptr::null_mut(), IDI_APPLICATION) };
+
+        match handle.is_null() {
+            true => Err(utils::get_last_error()),
+            false => Ok(Self {
+                handle,
+                is_owned: false,
+            })
+        }
+    }
+
+    ///Creates instance from raw handle, which is destroyed on drop.
+    pub fn from_raw(handle: HICON) -> Self {
+        Self {
+            handle,
+            is_owned: true,
+        }
+    }
+
+    #[inline]
+    ///Returns raw handle.
+    pub fn inner(&self) -> HICON {
+        self.handle
+    }
+}
+
+impl Drop for Icon {
+    fn drop(&mut self) {
+        if self.is_owned {
+            unsafe {
+                DestroyIcon(self.handle);
+            }
+        }
+    }
+}
+
+enum MenuEntry {
+    Item {
+        label: Vec<u16>,
+        flags: UINT,
+        cb: Option<Box<dyn FnMut()>>,
+    },
+    Separator,
+}
+
+///Context menu of tray icon.
+///
+///Menu is shown on right click. Callback of item is invoked once it is selected.
+pub struct TrayMenu {
+    entries: Vec<MenuEntry>,
+}
+
+impl TrayMenu {
+    ///Creates empty menu.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    ///Adds item with callback.
+    pub fn item<T: AsRef<ffi::OsStr>, F: FnMut() + 'static>(&mut self, label: T, cb: F) -> &mut Self {
+        self.entries.push(MenuEntry::Item {
+            label: to_wide(label.as_ref()),
+            flags: MF_STRING,
+            cb: Some(Box::new(cb)),
+        });
+        self
+    }
+
+    ///Adds item with check mark.
+    pub fn checked_item<T: AsRef<ffi::OsStr>, F: FnMut() + 'static>(&mut self, label: T, is_checked: bool, cb: F) -> &mut Self {
+        self.entries.push(MenuEntry::Item {
+            label: to_wide(label.as_ref()),
+            flags: match is_checked {
+                true => MF_STRING | MF_CHECKED,
+                false => MF_STRING,
+            },
+            cb: Some(Box::new(cb)),
+        });
+        self
+    }
+
+    ///Adds item that cannot be selected, e.g. status.
+    pub fn disabled_item<T: AsRef<ffi::OsStr>>(&mut self, label: T) -> &mut Self {
+        self.entries.push(MenuEntry::Item {
+            label: to_wide(label.as_ref()),
+            flags: MF_STRING | MF_GRAYED,
+            cb: None,
+        });
+        self
+    }
+
+    ///Adds separator.
+    pub fn separator(&mut self) -> &mut Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    #[inline]
+    ///Returns number of entries, including separators.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    ///Returns whether menu has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    //Shows menu and returns index of selected entry.
+    fn track(&self, window: HWND, x: c_int, y: c_int) -> Result<Option<usize>> {
+        let menu = unsafe { CreatePopupMenu() };
+        if menu.is_null() {
+            return Err(utils::get_last_error());
+        }
+
+        for (idx, entry) in self.entries.iter().enumerate() {
+            let result = match entry {
+                MenuEntry::Item { label, flags, .. } => unsafe { AppendMenuW(menu, *flags, idx + 1, label.as_ptr()) },
+                MenuEntry::Separator => unsafe { AppendMenuW(menu, MF_SEPARATOR, 0, ptr::null()) },
+            };
+
+            if result == 0 {
+                let error = utils::get_last_error();
+                unsafe {
+                    DestroyMenu(menu);
+                }
+                return Err(error);
+            }
+        }
+
+        //Without it menu is not closed when user clicks outside of it.
+        let result = unsafe {
+            SetForegroundWindow(window);
+            let result = TrackPopupMenu(menu, TPM_RIGHTBUTTON | TPM_RETURNCMD | TPM_NONOTIFY, x, y, 0, window, ptr::null());
+            PostMessageW(window, WM_NULL, 0, 0);
+            DestroyMenu(menu);
+            result
+        };
+
+        match result {
+            0 => Ok(None),
+            id => Ok(Some(id as usize - 1)),
+        }
+    }
+
+    fn invoke(&mut self, idx: usize) {
+        if let Some(MenuEntry::Item { cb: Some(cb), .. }) = self.entries.get_mut(idx) {
+            cb();
+        }
+    }
+}
+
+impl Default for TrayMenu {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Icon of balloon notification.
+pub enum BalloonIcon {
+    ///No icon.
+    None,
+    ///Information icon.
+    Info,
+    ///Warning icon.
+    Warning,
+    ///Error icon.
+    Error,
+}
+
+///Balloon notification, shown as toast since Windows 10.
+pub struct Balloon {
+    title: String,
+    text: String,
+    icon: BalloonIcon,
+    is_silent: bool,
+}
+
+impl Balloon {
+    ///Creates new notification.
+    ///
+    ///Title is truncated to 63 characters and text to 255 characters.
+    pub fn new(title: &str, text: &str) -> Self {
+        Self {
+            title: title.to_owned(),
+            text: text.to_owned(),
+            icon: BalloonIcon::None,
+            is_silent: false,
+        }
+    }
+
+    ///Sets icon.
+    pub fn icon(&mut self, icon: BalloonIcon) -> &mut Self {
+        self.icon = icon;
+        self
+    }
+
+    ///Disables sound of notification.
+    pub fn silent(&mut self) -> &mut Self {
+        self.is_silent = true;
+        self
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Event of tray icon.
+pub enum TrayEvent {
+    ///Icon is clicked or selected with keyboard.
+    Click,
+    ///Icon is double clicked.
+    ///
+    ///Preceded by `Click`.
+    DoubleClick,
+    ///Context menu is requested, right before menu is shown.
+    ContextMenu,
+    ///Balloon notification is clicked.
+    BalloonClicked,
+    ///Balloon notification is closed or timed out without click.
+    BalloonTimeout,
+    ///Icon is added back after Explorer restart.
+    Recreated,
+}
+
+type Handler = Box<dyn FnMut(TrayEvent)>;
+
+struct State {
+    data: RefCell<NOTIFYICONDATAW>,
+    icon: RefCell<Icon>,
+    menu: RefCell<Option<TrayMenu>>,
+    handler: RefCell<Option<Handler>>,
+    taskbar_created: UINT,
+    is_added: Cell<bool>,
+}
+
+impl State {
+    fn notify(&self, msg: DWORD) -> Result<()> {
+        let mut data = self.data.borrow_mut();
+        match unsafe { Shell_NotifyIconW(msg, &mut *data) } {
+            //Shell doesn't always set last error, e.g. when taskbar is not running.
+            0 => match utils::get_last_error() {
+                error if error.raw_code() == 0 => Err(ErrorCode::new_system(ERROR_TIMEOUT as c_int)),
+                error => Err(error),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn add(&self) -> Result<()> {
+        self.notify(NIM_ADD)?;
+        self.is_added.set(true);
+
+        self.data.borrow_mut().uVersion = NOTIFYICON_VERSION_4;
+        self.notify(NIM_SETVERSION)
+    }
+
+    fn emit(&self, event: TrayEvent) {
+        //Callback is taken out, so that it can replace itself.
+        let handler = self.handler.borrow_mut().take();
+        if let Some(mut handler) = handler {
+            //Panic must not cross FFI boundary.
+            let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| handler(event)));
+
+            let mut slot = self.handler.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(handler);
+            }
+        }
+    }
+
+    fn show_menu(&self, window: HWND, x: c_int, y: c_int) {
+        let menu = self.menu.borrow_mut().take();
+        if let Some(mut menu) = menu {
+            if let Ok(Some(idx)) = menu.track(window, x, y) {
+                let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| menu.invoke(idx)));
+            }
+
+            let mut slot = self.menu.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(menu);
+            }
+        }
+    }
+
+    fn handle(&self, window: HWND, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> bool {
+        if msg == self.taskbar_created && msg != 0 {
+            if self.add().is_ok() {
+                self.emit(TrayEvent::Recreated);
+            }
+            return true;
+        } else if msg != CALLBACK_MSG {
+            return false;
+        }
+
+        //With version 4 low word is event and high word is icon's identifier.
+        //Anchor's coordinates are passed in wParam.
+        match (l_param & 0xFFFF) as UINT {
+            NIN_SELECT | NIN_KEYSELECT => self.emit(TrayEvent::Click),
+            WM_LBUTTONDBLCLK => self.emit(TrayEvent::DoubleClick),
+            NIN_BALLOONUSERCLICK => self.emit(TrayEvent::BalloonClicked),
+            NIN_BALLOONTIMEOUT => self.emit(TrayEvent::BalloonTimeout),
+            WM_CONTEXTMENU => {
+                self.emit(TrayEvent::ContextMenu);
+                let x = (w_param & 0xFFFF) as u16 as i16 as c_int;
+                let y = ((w_param >> 16) & 0xFFFF) as u16 as i16 as c_int;
+                self.show_menu(window, x, y);
+            },
+            _ => (),
+        }
+
+        true
+    }
+}
+
+thread_local! {
+    static ICONS: RefCell<Vec<(HWND, Rc<State>)>> = const { RefCell::new(Vec::new()) };
+}
+
+unsafe extern "system" fn window_proc(window: HWND, msg: UINT, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    let state = ICONS.with(|icons| icons.borrow().iter().find(|(handle, _)| *handle == window).map(|(_, state)| state.clone()));
+
+    match state {
+        Some(state) if state.handle(window, msg, w_param, l_param) => 0,
+        _ => DefWindowProcW(window, msg, w_param, l_param),
+    }
+}
+
+fn register_class() -> Result<()> {
+    static REGISTER: Once = Once::new();
+    static ERROR: AtomicI32 = AtomicI32::new(0);
+
+    REGISTER.call_once(|| {
+        let class_name = to_wide(CLASS_NAME.as_ref());
+        let mut class: WNDCLASSEXW = unsafe { mem::zeroed() };
+        class.cbSize = mem::size_of::<WNDCLASSEXW>() as UINT;
+        class.lpfnWndProc = Some(window_proc);
+        class.hInstance = unsafe { GetModuleHandleW(ptr::null()) };
+        class.lpszClassName = class_name.as_ptr();
+
+        if unsafe { RegisterClassExW(&class) } == 0 {
+            let error = utils::get_last_error();
+            if error.raw_code() != ERROR_CLASS_ALREADY_EXISTS as c_int {
+                ERROR.store(error.raw_code(), Ordering::Release);
+            }
+        }
+    });
+
+    match ERROR.load(Ordering::Acquire) {
+        0 => Ok(()),
+        error => Err(ErrorCode::new_system(error)),
+    }
+}
+
+///Notification area icon.
+///
+///Icon is removed and its window is destroyed on drop.
+///
+///# Note:
+///
+///Notifications are delivered to the thread that created icon, which must run message loop.
+pub struct TrayIcon {
+    window: Window,
+    state: Rc<State>,
+}
+
+impl TrayIcon {
+    ///Creates hidden window on current thread and adds icon to notification area.
+    ///
+    ///# Parameters:
+    ///
+    ///* ```icon``` - Icon to display.
+    ///* ```tooltip``` - Tooltip text, truncated to 127 characters.
+    pub fn new(icon: Icon, tooltip: &str) -> Result<Self> {
+        register_class()?;
+
+        //Message-only window doesn't receive broadcast of TaskbarCreated.
+        let window = Window::from_builder(Builder::new().class_name(CLASS_NAME).instance(unsafe { GetModuleHandleW(ptr::null()) }))?;
+
+        let taskbar_created = to_wide("TaskbarCreated".as_ref());
+        let taskbar_created = unsafe { RegisterWindowMessageW(taskbar_created.as_ptr()) };
+        if taskbar_created != 0 {
+            //Elevated process doesn't receive it otherwise.
+            unsafe {
+                ChangeWindowMessageFilterEx(window.inner(), taskbar_created, MSGFLT_ALLOW, ptr::null_mut());
+            }
+        }
+
+        let mut data: NOTIFYICONDATAW = unsafe { mem::zeroed() };
+        data.cbSize = mem::size_of::<NOTIFYICONDATAW>() as DWORD;
+        data.hWnd = window.inner();
+        data.uID = ICON_ID;
+        data.uFlags = NIF_MESSAGE | NIF_ICON | NIF_TIP | NIF_SHOWTIP;
+        data.uCallbackMessage = CALLBACK_MSG;
+        data.hIcon = icon.inner();
+        copy_text(&mut data.szTip, tooltip);
+
+        let state = Rc::new(State {
+            data: RefCell::new(data),
+            icon: RefCell::new(icon),
+            menu: RefCell::new(None),
+            handler: RefCell::new(None),
+            taskbar_created,
+            is_added: Cell::new(false),
+        });
+
+        ICONS.with(|icons| icons.borrow_mut().push((window.inner(), state.clone())));
+        let result = Self {
+            window,
+            state,
+        };
+
+        result.state.add()?;
+        Ok(result)
+    }
+
+    ///Spawns message loop thread with icon.
+    ///
+    ///Icon is created by `init` on new thread and removed once returned thread is stopped.
+    pub fn spawn<I: FnOnce() -> Result<Self> + Send + 'static>(init: I) -> Result<MessageThread> {
+        MessageThread::spawn(move || {
+            let icon = init()?;
+
+            Ok(move |_: &MSG| {
+                let _ = &icon;
+            })
+        })
+    }
+
+    #[inline]
+    ///Returns underlying hidden window.
+    pub fn window(&self) -> HWND {
+        self.window.inner()
+    }
+
+    ///Sets callback to be invoked on icon's events.
+    pub fn on_event<F: FnMut(TrayEvent) + 'static>(&self, cb: F) {
+        *self.state.handler.borrow_mut() = Some(Box::new(cb));
+    }
+
+    ///Sets context menu, shown on right click.
+    pub fn set_menu(&self, menu: TrayMenu) {
+        *self.state.menu.borrow_mut() = Some(menu);
+    }
+
+    ///Changes icon.
+    pub fn set_icon(&self, icon: Icon) -> Result<()> {
+        {
+            let mut data = self.state.data.borrow_mut();
+            data.uFlags = NIF_ICON;
+            data.hIcon = icon.inner();
+        }
+        let result = self.state.notify(NIM_MODIFY);
+        self.state.data.borrow_mut().uFlags = NIF_MESSAGE | NIF_ICON | NIF_TIP | NIF_SHOWTIP;
+
+        //Previous icon must be valid until it is replaced.
+        *self.state.icon.borrow_mut() = icon;
+        result
+    }
+
+    ///Changes tooltip, truncated to 127 characters.
+    pub fn set_tooltip(&self, tooltip: &str) -> Result<()> {
+        {
+            let mut data = self.state.data.borrow_mut();
+            data.uFlags = NIF_TIP | NIF_SHOWTIP;
+            copy_text(&mut data.szTip, tooltip);
+        }
+        let result = self.state.notify(NIM_MODIFY);
+        self.state.data.borrow_mut().uFlags = NIF_MESSAGE | NIF_ICON | NIF_TIP | NIF_SHOWTIP;
+        result
+    }
+
+    ///Shows balloon notification.
+    pub fn notify(&self, balloon: &Balloon) -> Result<()> {
+        {
+            let mut data = self.state.data.borrow_mut();
+            data.uFlags = NIF_INFO;
+            copy_text(&mut data.szInfoTitle, &balloon.title);
+            copy_text(&mut data.szInfo, &balloon.text);
+            data.dwInfoFlags = match balloon.icon {
+                BalloonIcon::None => NIIF_NONE,
+                BalloonIcon::Info => NIIF_INFO,
+                BalloonIcon::Warning => NIIF_WARNING,
+                BalloonIcon::Error => NIIF_ERROR,
+            };
+            if balloon.is_silent {
+                data.dwInfoFlags |= NIIF_NOSOUND;
+            }
+        }
+        let result = self.state.notify(NIM_MODIFY);
+
+        //Balloon must not be shown again when icon is re-added.
+        let mut data = self.state.data.borrow_mut();
+        data.uFlags = NIF_MESSAGE | NIF_ICON | NIF_TIP | NIF_SHOWTIP;
+        data.szInfo[0] = 0;
+        data.szInfoTitle[0] = 0;
+        result
+    }
+}
+
+impl Drop for TrayIcon {
+    fn drop(&mut self) {
+        if self.state.is_added.get() {
+            let _ = self.state.notify(NIM_DELETE);
+        }
+
+        let window = self.window.inner();
+        ICONS.with(|icons| icons.borrow_mut().retain(|(handle, _)| *handle != window));
+    }
+}
@@ -1,5 +1,11 @@
 //! Various useful utilities for working with winapi
 pub use error_code::ErrorCode;
+use error_code::{Category, MessageBuf, SYSTEM_CATEGORY};
+
+use crate::sys::{c_int, HRESULT};
+
+use core::ptr;
+
 ///IO result
 pub type Result<T> = core::result::Result<T, ErrorCode>;
 
@@ -8,3 +14,39 @@ pub type Result<T> = core::result::Result<T, ErrorCode>;
 pub fn get_last_error() -> ErrorCode {
     ErrorCode::last_system()
 }
+
+///Category of `HRESULT` errors, which are not Win32 errors.
+///
+///Messages are looked up the same way as for system errors, as system knows common `HRESULT` values.
+pub static HRESULT_CATEGORY: Category = Category {
+    name: "HRESULT",
+    message: hresult_message,
+    equivalent: hresult_equivalent,
+    is_would_block: hresult_is_would_block,
+};
+
+fn hresult_message(code: c_int, out: &mut MessageBuf) -> &str {
+    (SYSTEM_CATEGORY.message)(code, out)
+}
+
+fn hresult_equivalent(code: c_int, other: &ErrorCode) -> bool {
+    ptr::eq(&HRESULT_CATEGORY, other.category()) && code == other.raw_code()
+}
+
+fn hresult_is_would_block(_: c_int) -> bool {
+    false
+}
+
+///Converts failed `HRESULT` into error code.
+///
+///`HRESULT` of `FACILITY_WIN32` is converted into original system error code,
+///while the rest belongs to [HRESULT_CATEGORY](static.HRESULT_CATEGORY.html).
+pub fn from_hresult(result: HRESULT) -> ErrorCode {
+    const FACILITY_WIN32: c_int = 7;
+
+    let code = result as c_int;
+    match (code >> 16) & 0x1FFF {
+        FACILITY_WIN32 => ErrorCode::new_system(code & 0xFFFF),
+        _ => ErrorCode::new(code, &HRESULT_CATEGORY),
+    }
+}
@@ -0,0 +1,306 @@
+//! Accessibility events via `SetWinEventHook`.
+//!
+//! Events are delivered out of context to the thread that installed hook,
+//! which must run message loop.
+//! Use [WinEventBuilder::spawn()](struct.WinEventBuilder.html#method.spawn) to watch events on dedicated thread.
+//!
+//! Conversion of raw events is platform independent.
+
+use crate::sys::{
+    HWND,
+    DWORD,
+    LONG,
+    EVENT_SYSTEM_FOREGROUND,
+    EVENT_OBJECT_CREATE,
+    EVENT_OBJECT_DESTROY,
+    EVENT_OBJECT_NAMECHANGE,
+    EVENT_OBJECT_LOCATIONCHANGE,
+    OBJID_WINDOW,
+    CHILDID_SELF,
+    WINEVENT_SKIPOWNPROCESS,
+    WINEVENT_SKIPOWNTHREAD,
+};
+
+#[cfg(windows)]
+use crate::sys::{
+    MSG,
+    HWINEVENTHOOK,
+    WINEVENT_OUTOFCONTEXT,
+    SetWinEventHook,
+    UnhookWinEvent,
+};
+#[cfg(windows)]
+use crate::message_loop::MessageThread;
+#[cfg(windows)]
+use crate::utils::{self, Result};
+#[cfg(windows)]
+use std::{panic, collections::HashMap, rc::Rc};
+#[cfg(windows)]
+use core::{ptr, cell::RefCell};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Kind of event.
+pub enum WinEventKind {
+    ///Foreground window is changed. `EVENT_SYSTEM_FOREGROUND`
+    ForegroundChanged,
+    ///Object is created. `EVENT_OBJECT_CREATE`
+    ObjectCreated,
+    ///Object is destroyed. `EVENT_OBJECT_DESTROY`
+    ObjectDestroyed,
+    ///Object's name, e.g. window title, is changed. `EVENT_OBJECT_NAMECHANGE`
+    NameChanged,
+    ///Object's position or size is changed. `EVENT_OBJECT_LOCATIONCHANGE`
+    LocationChanged,
+    ///Any other event.
+    Other(DWORD),
+}
+
+impl WinEventKind {
+    ///Creates kind from raw event constant.
+    pub fn from_raw(event: DWORD) -> Self {
+        match event {
+            EVENT_SYSTEM_FOREGROUND => WinEventKind::ForegroundChanged,
+            EVENT_OBJECT_CREATE => WinEventKind::ObjectCreated,
+            EVENT_OBJECT_DESTROY => WinEventKind::ObjectDestroyed,
+            EVENT_OBJECT_NAMECHANGE => WinEventKind::NameChanged,
+            EVENT_OBJECT_LOCATIONCHANGE => WinEventKind::LocationChanged,
+            event => WinEventKind::Other(event),
+        }
+    }
+
+    ///Returns raw event constant.
+    pub fn raw(&self) -> DWORD {
+        match self {
+            WinEventKind::ForegroundChanged => EVENT_SYSTEM_FOREGROUND,
+            WinEventKind::ObjectCreated => EVENT_OBJECT_CREATE,
+            WinEventKind::ObjectDestroyed => EVENT_OBJECT_DESTROY,
+            WinEventKind::NameChanged => EVENT_OBJECT_NAMECHANGE,
+            WinEventKind::LocationChanged => EVENT_OBJECT_LOCATIONCHANGE,
+            WinEventKind::Other(event) => *event,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///Accessibility event.
+pub struct WinEvent {
+    ///Kind of event.
+    pub kind: WinEventKind,
+    ///Window that generated event. Can be null.
+    pub window: HWND,
+    ///Object identifier, e.g. `OBJID_WINDOW`.
+    pub object_id: LONG,
+    ///Child identifier, `CHILDID_SELF` if event is generated by object itself.
+    pub child_id: LONG,
+    ///Identifier of thread that generated event.
+    pub thread_id: DWORD,
+    ///Time stamp in milliseconds.
+    pub time: DWORD,
+}
+
+impl WinEvent {
+    #[inline]
+    ///Returns whether event is generated by window itself, rather than its part like caret or scroll bar.
+    ///
+    ///Object creation/destruction events of windows are only those, for which it is true.
+    pub fn is_window(&self) -> bool {
+        !self.window.is_null() && self.object_id == OBJID_WINDOW && self.child_id == CHILDID_SELF
+    }
+}
+
+#[cfg(windows)]
+type Callback = Rc<RefCell<dyn FnMut(&WinEvent)>>;
+
+#[cfg(windows)]
+thread_local! {
+    static CALLBACKS: RefCell<HashMap<usize, Callback>> = RefCell::new(HashMap::new());
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn win_event_proc(hook: HWINEVENTHOOK, event: DWORD, window: HWND, object_id: LONG, child_id: LONG, thread_id: DWORD, time: DWORD) {
+    let cb = CALLBACKS.with(|callbacks| callbacks.borrow().get(&(hook as usize)).cloned());
+
+    if let Some(cb) = cb {
+        let event = WinEvent {
+            kind: WinEventKind::from_raw(event),
+            window,
+            object_id,
+            child_id,
+            thread_id,
+            time,
+        };
+
+        //Callback can be re-entered if it pumps messages.
+        if let Ok(mut cb) = cb.try_borrow_mut() {
+            //Panic must not cross FFI boundary.
+            let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| (&mut *cb)(&event)));
+        };
+    }
+}
+
+///Builder of [WinEventWatcher](struct.WinEventWatcher.html).
+///
+///By default watches no events in all processes and threads.
+pub struct WinEventBuilder {
+    events: Vec<WinEventKind>,
+    pid: DWORD,
+    tid: DWORD,
+    flags: DWORD,
+}
+
+impl WinEventBuilder {
+    ///Creates new instance.
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            pid: 0,
+            tid: 0,
+            flags: 0,
+        }
+    }
+
+    ///Adds event to watch.
+    pub fn event(mut self, kind: WinEventKind) -> Self {
+        if !self.events.contains(&kind) {
+            self.events.push(kind);
+        }
+        self
+    }
+
+    #[inline]
+    ///Watches `ForegroundChanged`.
+    pub fn foreground(self) -> Self {
+        self.event(WinEventKind::ForegroundChanged)
+    }
+
+    #[inline]
+    ///Watches `ObjectCreated`.
+    pub fn created(self) -> Self {
+        self.event(WinEventKind::ObjectCreated)
+    }
+
+    #[inline]
+    ///Watches `ObjectDestroyed`.
+    pub fn destroyed(self) -> Self {
+        self.event(WinEventKind::ObjectDestroyed)
+    }
+
+    #[inline]
+    ///Watches `NameChanged`.
+    pub fn name_changed(self) -> Self {
+        self.event(WinEventKind::NameChanged)
+    }
+
+    #[inline]
+    ///Watches `LocationChanged`.
+    pub fn location_changed(self) -> Self {
+        self.event(WinEventKind::LocationChanged)
+    }
+
+    ///Watches events of specified process only.
+    pub fn process(mut self, pid: DWORD) -> Self {
+        self.pid = pid;
+        self
+    }
+
+    ///Watches events of specified thread only.
+    pub fn thread(mut self, tid: DWORD) -> Self {
+        self.tid = tid;
+        self
+    }
+
+    ///Skips events generated by the current process.
+    pub fn skip_own_process(mut self) -> Self {
+        self.flags |= WINEVENT_SKIPOWNPROCESS;
+        self
+    }
+
+    ///Skips events generated by the installing thread.
+    pub fn skip_own_thread(mut self) -> Self {
+        self.flags |= WINEVENT_SKIPOWNTHREAD;
+        self
+    }
+
+    #[inline]
+    ///Returns events to watch.
+    pub fn events(&self) -> &[WinEventKind] {
+        &self.events
+    }
+}
+
+#[cfg(windows)]
+impl WinEventBuilder {
+    ///Starts watching on the current thread, which must run message loop.
+    pub fn start<F: FnMut(&WinEvent) + 'static>(self, cb: F) -> Result<WinEventWatcher> {
+        let cb: Callback = Rc::new(RefCell::new(cb));
+        let mut watcher = WinEventWatcher {
+            hooks: Vec::with_capacity(self.events.len())
+        };
+
+        for event in self.events.iter() {
+            let event = event.raw();
+            let hook = unsafe { SetWinEventHook(event, event, ptr::null_mut(), Some(win_event_proc), self.pid, self.tid, WINEVENT_OUTOFCONTEXT | self.flags) };
+            if hook.is_null() {
+                return Err(utils::get_last_error());
+            }
+
+            CALLBACKS.with(|callbacks| callbacks.borrow_mut().insert(hook as usize, cb.clone()));
+            watcher.hooks.push(hook);
+        }
+
+        Ok(watcher)
+    }
+
+    ///Spawns message loop thread that watches events.
+    ///
+    ///Watching is stopped once returned thread is stopped.
+    pub fn spawn<F: FnMut(&WinEvent) + Send + 'static>(self, cb: F) -> Result<MessageThread> {
+        MessageThread::spawn(move || {
+            let watcher = self.start(cb)?;
+
+            Ok(move |_: &MSG| {
+                //Keeps hooks alive as long as loop runs
+                let _ = &watcher;
+            })
+        })
+    }
+}
+
+impl Default for WinEventBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+///Watcher of accessibility events.
+///
+///Hooks are removed on drop, which must happen on the installing thread.
+pub struct WinEventWatcher {
+    hooks: Vec<HWINEVENTHOOK>,
+}
+
+#[cfg(windows)]
+impl WinEventWatcher {
+    #[inline]
+    ///Creates builder.
+    pub fn builder() -> WinEventBuilder {
+        WinEventBuilder::new()
+    }
+}
+
+#[cfg(windows)]
+impl Drop for WinEventWatcher {
+    fn drop(&mut self) {
+        for hook in self.hooks.drain(..) {
+            unsafe {
+                UnhookWinEvent(hook);
+            }
+            //Callback can be removed from within itself, in which case it is dropped with thread.
+            CALLBACKS.with(|callbacks| if let Ok(mut callbacks) = callbacks.try_borrow_mut() {
+                callbacks.remove(&(hook as usize));
+            });
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! Dump of window hierarchy, similar to Spy++.
+//!
+//! Tree consists of plain data, which can be rendered via `Display` or serialized
+//! using `serde`, if corresponding feature is enabled.
+//!
+//! Use [Window::tree()](../struct.Window.html#method.tree) to build tree of window.
+
+use core::fmt;
+
+use crate::sys::{DWORD, LONG};
+
+#[cfg(windows)]
+use crate::sys::{HWND, RECT, GW_CHILD, GW_HWNDNEXT, GetWindow};
+#[cfg(windows)]
+use crate::raw;
+#[cfg(windows)]
+use crate::utils::Result;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Window's rectangle in screen coordinates.
+pub struct Rect {
+    ///X coordinate of upper-left corner.
+    pub left: LONG,
+    ///Y coordinate of upper-left corner.
+    pub top: LONG,
+    ///X coordinate of lower-right corner.
+    pub right: LONG,
+    ///Y coordinate of lower-right corner.
+    pub bottom: LONG,
+}
+
+impl Rect {
+    #[inline]
+    ///Returns width.
+    pub fn width(&self) -> LONG {
+        self.right - self.left
+    }
+
+    #[inline]
+    ///Returns height.
+    pub fn height(&self) -> LONG {
+        self.bottom - self.top
+    }
+}
+
+#[cfg(windows)]
+impl From<RECT> for Rect {
+    fn from(rect: RECT) -> Self {
+        Self {
+            left: rect.left,
+            top: rect.top,
+            right: rect.right,
+            bottom: rect.bottom,
+        }
+    }
+}
+
+impl fmt::Display for Rect {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "({}, {})-({}, {}) {}x{}", self.left, self.top, self.right, self.bottom, self.width(), self.height())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///Node of window tree.
+pub struct WindowNode {
+    ///Window's handle as integer.
+    pub hwnd: usize,
+    ///Window's class.
+    pub class: String,
+    ///Window's title.
+    pub title: String,
+    ///Window's rectangle.
+    pub rect: Rect,
+    ///Window's style. `GWL_STYLE`
+    pub style: DWORD,
+    ///Pid of process that created window.
+    pub pid: u32,
+    ///Id of thread that created window.
+    pub tid: u32,
+    ///Whether window is visible.
+    pub visible: bool,
+    ///Direct children in Z order.
+    pub children: Vec<WindowNode>,
+}
+
+impl WindowNode {
+    ///Returns total number of nodes in tree, including itself.
+    pub fn len(&self) -> usize {
+        self.children.iter().fold(1, |len, child| len + child.len())
+    }
+
+    ///Looks up first node, in depth-first order, that satisfies predicate.
+    pub fn find<F: FnMut(&WindowNode) -> bool>(&self, mut predicate: F) -> Option<&WindowNode> {
+        self.find_inner(&mut predicate)
+    }
+
+    fn find_inner<F: FnMut(&WindowNode) -> bool>(&self, predicate: &mut F) -> Option<&WindowNode> {
+        if predicate(self) {
+            return Some(self);
+        }
+
+        self.children.iter().find_map(|child| child.find_inner(predicate))
+    }
+
+    fn fmt_indent(&self, fmt: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        writeln!(fmt, "{:indent$}{:#010x} {:?} {} {} style={:#010x} pid={} tid={}{}",
+                 "", self.hwnd, self.title, self.class, self.rect, self.style, self.pid, self.tid,
+                 if self.visible { "" } else { " hidden" },
+                 indent = depth * 4)?;
+
+        for child in self.children.iter() {
+            child.fmt_indent(fmt, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+impl WindowNode {
+    ///Builds tree of window and all its children.
+    ///
+    ///Children that are destroyed while tree is built are skipped.
+    pub fn from_hwnd(window: HWND) -> Result<Self> {
+        let class = raw::window::get_class(window)?;
+        let rect = raw::window::get_rect(window)?;
+        let (pid, tid) = raw::window::get_thread_process_id(window);
+
+        let mut children = Vec::new();
+        let mut child = unsafe { GetWindow(window, GW_CHILD) };
+        while !child.is_null() {
+            if let Ok(node) = Self::from_hwnd(child) {
+                children.push(node);
+            }
+            child = unsafe { GetWindow(child, GW_HWNDNEXT) };
+        }
+
+        Ok(Self {
+            hwnd: window as usize,
+            class,
+            //Empty title is reported as error.
+            title: raw::window::get_text(window).unwrap_or_default(),
+            rect: rect.into(),
+            style: raw::window::get_style(window),
+            pid,
+            tid,
+            visible: raw::window::is_visible(window),
+            children,
+        })
+    }
+}
+
+///Renders indented tree, one window per line.
+impl fmt::Display for WindowNode {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indent(fmt, 0)
+    }
+}
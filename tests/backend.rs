@@ -0,0 +1,89 @@
+use windows_win::backend::{WindowBackend, FakeDesktop, FakeWindow, SentMessage};
+use windows_win::sys::{HWND, WM_SYSCOMMAND, WM_USER, SW_HIDE, SW_SHOW};
+
+//Sample of automation logic, which only depends on backend.
+fn close_by_pid<B: WindowBackend>(backend: &B, pid: u32) -> bool {
+    match backend.get_by_pid(pid) {
+        Ok(Some(window)) => backend.send_message(window, WM_SYSCOMMAND, 0xF060, 0, Some(1000)).is_ok() && !backend.is_visible(window),
+        _ => false,
+    }
+}
+
+#[test]
+fn test_fake_desktop_lookup() {
+    let desktop = FakeDesktop::new();
+    let notepad = desktop.add(FakeWindow::new("Notepad").title("Untitled").pid(100).tid(101));
+    let edit = desktop.add_child(notepad, FakeWindow::new("Edit").title("Text").pid(100));
+    let status = desktop.add_child(notepad, FakeWindow::new("msctls_statusbar32").pid(100));
+    let status_text = desktop.add_child(status, FakeWindow::new("Edit").pid(100));
+    let other = desktop.add(FakeWindow::new("Notepad").title("Other").pid(200).visible(false));
+
+    assert_eq!(desktop.enum_windows(None).unwrap(), vec![notepad, other]);
+    assert_eq!(desktop.enum_windows(Some(notepad)).unwrap(), vec![edit, status, status_text]);
+    assert_eq!(desktop.get_by_class("Notepad", None).unwrap(), vec![notepad, other]);
+    assert_eq!(desktop.get_by_class("Edit", Some(notepad)).unwrap(), vec![edit, status_text]);
+    assert_eq!(desktop.get_by_title("Other", None).unwrap(), vec![other]);
+    assert_eq!(desktop.get_by_pid(200).unwrap(), Some(other));
+    assert_eq!(desktop.get_by_pid(300).unwrap(), None);
+
+    assert_eq!(desktop.class(edit).unwrap(), "Edit");
+    assert_eq!(desktop.title(notepad).unwrap(), "Untitled");
+    assert_eq!(desktop.thread_process_id(notepad), (100, 101));
+    assert!(!desktop.is_visible(other));
+
+    assert!(desktop.set_text(edit, "New text"));
+    assert_eq!(desktop.text(edit).unwrap(), "New text");
+
+    assert!(!desktop.show(other, SW_SHOW));
+    assert!(desktop.show(other, SW_HIDE));
+    assert!(!desktop.is_visible(other));
+
+    assert!(desktop.destroy(status));
+    assert_eq!(desktop.enum_windows(Some(notepad)).unwrap(), vec![edit]);
+    assert!(desktop.class(status_text).is_err());
+    assert!(!desktop.destroy(status));
+}
+
+#[test]
+fn test_fake_desktop_messages() {
+    let desktop = FakeDesktop::new();
+    let notepad = desktop.add(FakeWindow::new("Notepad").pid(100));
+    desktop.on_message(notepad, WM_SYSCOMMAND, |window, w_param, _| {
+        if w_param == 0xF060 {
+            window.visible = false;
+        }
+        Ok(0)
+    });
+    desktop.on_message(notepad, WM_USER, |_, w_param, l_param| Ok(w_param as isize + l_param));
+
+    assert_eq!(desktop.send_message(notepad, WM_USER, 2, 40, None).unwrap(), 42);
+    assert_eq!(desktop.send_message(notepad, WM_USER + 1, 0, 0, None).unwrap(), 0);
+    assert!(close_by_pid(&desktop, 100));
+    assert!(!close_by_pid(&desktop, 200));
+
+    let invalid = 1usize as HWND;
+    assert_eq!(desktop.send_message(invalid, WM_USER, 0, 0, None).unwrap_err().raw_code(), 1400);
+
+    assert_eq!(desktop.sent(), vec![
+        SentMessage { window: notepad, msg_type: WM_USER, w_param: 2, l_param: 40 },
+        SentMessage { window: notepad, msg_type: WM_USER + 1, w_param: 0, l_param: 0 },
+        SentMessage { window: notepad, msg_type: WM_SYSCOMMAND, w_param: 0xF060, l_param: 0 },
+    ]);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_user32_backend() {
+    use windows_win::backend::User32;
+    use windows_win::raw::window::{Builder, destroy};
+
+    let window = Builder::new().class_name("STATIC").window_name("BackendTest").create().expect("To create window");
+
+    assert_eq!(User32.get_by_title("BackendTest", None).unwrap(), vec![window]);
+    assert_eq!(User32.class(window).unwrap(), "Static");
+    assert_eq!(User32.thread_process_id(window).0, std::process::id());
+    assert!(User32.set_text(window, "BackendTest2"));
+    assert_eq!(User32.text(window).unwrap(), "BackendTest2");
+
+    assert!(destroy(window));
+}
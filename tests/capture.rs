@@ -0,0 +1,113 @@
+use windows_win::capture::Image;
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+fn read_i32(data: &[u8], offset: usize) -> i32 {
+    read_u32(data, offset) as i32
+}
+
+#[test]
+fn should_set_and_get_pixels() {
+    let mut image = Image::new(3, 2);
+    assert_eq!(image.stride(), 12);
+    assert_eq!(image.pixels().len(), 24);
+    assert_eq!(image.pixel(2, 1), Some([0, 0, 0, 0]));
+
+    assert!(image.set_pixel(2, 1, [1, 2, 3, 4]));
+    assert!(!image.set_pixel(3, 1, [1, 2, 3, 4]));
+    assert_eq!(image.pixel(2, 1), Some([1, 2, 3, 4]));
+    assert_eq!(image.pixel(0, 2), None);
+    assert_eq!(&image.row(1)[8..], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn should_validate_raw_pixels() {
+    assert!(Image::from_bgra(2, 2, 7, vec![0; 16]).is_none());
+    assert!(Image::from_bgra(2, 2, 8, vec![0; 15]).is_none());
+
+    //Padded rows
+    let mut pixels = vec![0; 20];
+    pixels[10..14].copy_from_slice(&[5, 6, 7, 8]);
+    let image = Image::from_bgra(2, 2, 10, pixels).expect("Valid image");
+    assert_eq!(image.pixel(0, 1), Some([5, 6, 7, 8]));
+    assert_eq!(image.row(1).len(), 8);
+}
+
+#[test]
+fn should_crop_image() {
+    let mut image = Image::new(4, 4);
+    image.set_pixel(2, 3, [9, 9, 9, 9]);
+
+    let part = image.crop(1, 2, 3, 2).expect("Crop in bounds");
+    assert_eq!(part.width(), 3);
+    assert_eq!(part.height(), 2);
+    assert_eq!(part.pixel(1, 1), Some([9, 9, 9, 9]));
+
+    assert!(image.crop(2, 2, 3, 1).is_none());
+    assert!(image.crop(0, 0, 4, 4).is_some());
+}
+
+#[test]
+fn should_encode_bmp() {
+    let mut image = Image::new(2, 3);
+    image.set_pixel(0, 0, [10, 20, 30, 255]);
+    image.set_pixel(1, 2, [40, 50, 60, 255]);
+
+    let bmp = image.to_bmp();
+    assert_eq!(bmp.len(), 54 + 2 * 3 * 4);
+    assert_eq!(&bmp[..2], b"BM");
+    assert_eq!(read_u32(&bmp, 2), bmp.len() as u32);
+    assert_eq!(read_u32(&bmp, 10), 54);
+    assert_eq!(read_u32(&bmp, 14), 40);
+    assert_eq!(read_i32(&bmp, 18), 2);
+    //Top-down
+    assert_eq!(read_i32(&bmp, 22), -3);
+    assert_eq!(u16::from_le_bytes([bmp[26], bmp[27]]), 1);
+    assert_eq!(u16::from_le_bytes([bmp[28], bmp[29]]), 32);
+    assert_eq!(read_u32(&bmp, 30), 0);
+    assert_eq!(read_u32(&bmp, 34), 24);
+
+    assert_eq!(&bmp[54..58], &[10, 20, 30, 255]);
+    assert_eq!(&bmp[bmp.len() - 4..], &[40, 50, 60, 255]);
+}
+
+#[test]
+fn should_skip_row_padding_in_bmp() {
+    let mut pixels = vec![0xAA; 12];
+    pixels[0..4].copy_from_slice(&[1, 2, 3, 4]);
+    pixels[6..10].copy_from_slice(&[5, 6, 7, 8]);
+    let image = Image::from_bgra(1, 2, 6, pixels).expect("Valid image");
+
+    let bmp = image.to_bmp();
+    assert_eq!(&bmp[54..], &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn should_save_bmp() {
+    let mut image = Image::new(1, 1);
+    image.set_pixel(0, 0, [1, 2, 3, 4]);
+
+    let path = std::env::temp_dir().join(format!("windows-win-capture-{}.bmp", std::process::id()));
+    image.save_bmp(&path).expect("To save image");
+    let data = std::fs::read(&path).expect("To read image");
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(data, image.to_bmp());
+}
+
+#[cfg(windows)]
+#[test]
+fn should_capture_primary_screen() {
+    let image = windows_win::capture::primary_screen().expect("To capture screen");
+    assert!(image.width() > 0);
+    assert!(image.height() > 0);
+    assert!(image.pixels().chunks(4).all(|pixel| pixel[3] == 0xFF));
+}
+
+#[cfg(windows)]
+#[test]
+fn should_fail_to_capture_invalid_window() {
+    assert!(windows_win::capture::window(std::ptr::null_mut()).is_err());
+}
@@ -0,0 +1,34 @@
+#![cfg(windows)]
+
+use windows_win::clipboard::{ClipboardListener, sequence_number};
+
+use clipboard_win::set_clipboard_string;
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+#[test]
+fn test_clipboard_listener_events() {
+    let listener = ClipboardListener::new().expect("To create listener");
+    let before = sequence_number();
+
+    assert!(set_clipboard_string("Test").is_ok());
+    let update = listener.events().next().expect("To get event").expect("To get update");
+    //Clipboard can be changed concurrently by other tests.
+    assert!(update.sequence >= before);
+    assert!(update.sequence <= sequence_number());
+}
+
+#[test]
+fn test_clipboard_listener_spawn() {
+    let (sender, receiver) = mpsc::channel();
+    let thread = ClipboardListener::spawn(move |update| {
+        let _ = sender.send(update);
+    }).expect("To spawn listener");
+
+    assert!(set_clipboard_string("Test spawn").is_ok());
+    let update = receiver.recv_timeout(Duration::from_secs(5)).expect("To get update");
+    assert!(update.sequence > 0);
+
+    thread.stop();
+}
@@ -0,0 +1,141 @@
+use windows_win::console::{CellRect, Color, ConsoleEvent, CtrlEvent, ScreenBufferInfo, TextAttributes};
+use windows_win::sys::*;
+
+#[test]
+fn should_compose_text_attributes() {
+    let attributes = TextAttributes::default();
+    assert_eq!(attributes.raw(), 0x07);
+    assert_eq!(attributes.get_foreground(), Color::Gray);
+    assert_eq!(attributes.get_background(), Color::Black);
+
+    let attributes = attributes.foreground(Color::Yellow).background(Color::DarkBlue).underline();
+    assert_eq!(attributes.raw(), FOREGROUND_RED | FOREGROUND_GREEN | FOREGROUND_INTENSITY | BACKGROUND_BLUE | COMMON_LVB_UNDERSCORE);
+    assert_eq!(attributes.get_foreground(), Color::Yellow);
+    assert_eq!(attributes.get_background(), Color::DarkBlue);
+    assert!(attributes.is_underline());
+    assert!(!attributes.is_reverse());
+
+    //Colors are replaced, not mixed.
+    let attributes = attributes.foreground(Color::Black).background(Color::White);
+    assert_eq!(attributes.raw(), 0xF0 | COMMON_LVB_UNDERSCORE);
+
+    for index in 0..16 {
+        assert_eq!(Color::from_index(index).expect("To get color").index(), index);
+    }
+    assert_eq!(Color::from_index(16), None);
+}
+
+#[test]
+fn should_decode_key_event() {
+    let record = INPUT_RECORD {
+        EventType: KEY_EVENT,
+        Event: INPUT_RECORD_u {
+            KeyEvent: KEY_EVENT_RECORD {
+                bKeyDown: 1,
+                wRepeatCount: 2,
+                wVirtualKeyCode: 0x41,
+                wVirtualScanCode: 0x1E,
+                UnicodeChar: 'A' as u16,
+                dwControlKeyState: SHIFT_PRESSED | LEFT_CTRL_PRESSED,
+            }
+        }
+    };
+
+    let event = match ConsoleEvent::from_raw(&record) {
+        Some(ConsoleEvent::Key(event)) => event,
+        event => panic!("Unexpected event {:?}", event),
+    };
+    assert!(event.is_down);
+    assert_eq!(event.repeat_count, 2);
+    assert_eq!(event.vk, 0x41);
+    assert_eq!(event.char(), Some('A'));
+    assert!(event.control_keys.is_shift());
+    assert!(event.control_keys.is_ctrl());
+    assert!(!event.control_keys.is_alt());
+}
+
+#[test]
+fn should_decode_mouse_and_resize_events() {
+    let record = INPUT_RECORD {
+        EventType: MOUSE_EVENT,
+        Event: INPUT_RECORD_u {
+            MouseEvent: MOUSE_EVENT_RECORD {
+                dwMousePosition: COORD { X: 10, Y: 3 },
+                //Delta of -120
+                dwButtonState: 0xFF88_0000 | FROM_LEFT_1ST_BUTTON_PRESSED,
+                dwControlKeyState: 0,
+                dwEventFlags: MOUSE_WHEELED,
+            }
+        }
+    };
+
+    let event = match ConsoleEvent::from_raw(&record) {
+        Some(ConsoleEvent::Mouse(event)) => event,
+        event => panic!("Unexpected event {:?}", event),
+    };
+    assert_eq!(event.position, (10, 3));
+    assert!(event.is_left());
+    assert!(!event.is_right());
+    assert!(!event.is_moved());
+    assert_eq!(event.wheel_delta(), Some(-120));
+    assert!(!event.is_horizontal_wheel());
+
+    let record = INPUT_RECORD {
+        EventType: WINDOW_BUFFER_SIZE_EVENT,
+        Event: INPUT_RECORD_u {
+            WindowBufferSizeEvent: WINDOW_BUFFER_SIZE_RECORD {
+                dwSize: COORD { X: 120, Y: 30 },
+            }
+        }
+    };
+    assert_eq!(ConsoleEvent::from_raw(&record), Some(ConsoleEvent::Resize(120, 30)));
+
+    let record = INPUT_RECORD {
+        EventType: 0x40,
+        Event: INPUT_RECORD_u {
+            FocusEvent: FOCUS_EVENT_RECORD {
+                bSetFocus: 1,
+            }
+        }
+    };
+    assert_eq!(ConsoleEvent::from_raw(&record), None);
+}
+
+#[test]
+fn should_convert_screen_buffer_info() {
+    let info = CONSOLE_SCREEN_BUFFER_INFO {
+        dwSize: COORD { X: 120, Y: 9001 },
+        dwCursorPosition: COORD { X: 0, Y: 42 },
+        wAttributes: 0x1F,
+        srWindow: SMALL_RECT { Left: 0, Top: 20, Right: 119, Bottom: 49 },
+        dwMaximumWindowSize: COORD { X: 120, Y: 60 },
+    };
+
+    let info = ScreenBufferInfo::from_raw(&info);
+    assert_eq!(info.size, (120, 9001));
+    assert_eq!(info.cursor, (0, 42));
+    assert_eq!(info.attributes.get_foreground(), Color::White);
+    assert_eq!(info.attributes.get_background(), Color::DarkBlue);
+    assert_eq!(info.window, CellRect { left: 0, top: 20, right: 119, bottom: 49 });
+    assert_eq!(info.window_width(), 120);
+    assert_eq!(info.window_height(), 30);
+}
+
+#[test]
+fn should_convert_ctrl_events() {
+    for event in [CtrlEvent::CtrlC, CtrlEvent::CtrlBreak, CtrlEvent::Close, CtrlEvent::Logoff, CtrlEvent::Shutdown].iter() {
+        assert_eq!(CtrlEvent::from_raw(event.raw()), Some(*event));
+    }
+    assert_eq!(CtrlEvent::from_raw(3), None);
+}
+
+#[cfg(windows)]
+#[test]
+fn should_set_and_remove_ctrl_handler() {
+    use windows_win::console::CtrlHandler;
+
+    let handler = CtrlHandler::new(|_| true).expect("To set handler");
+    assert_eq!(CtrlHandler::new(|_| true).err().expect("To fail second handler").raw_code(), ERROR_ALREADY_EXISTS as i32);
+    drop(handler);
+    drop(CtrlHandler::new(|_| false).expect("To set handler again"));
+}
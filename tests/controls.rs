@@ -0,0 +1,149 @@
+use windows_win::controls::{
+    Bitness,
+    encode_list_view_item,
+    encode_list_view_column,
+    decode_list_view_column_width,
+    encode_tree_view_item,
+    decode_tree_view_item_children,
+    decode_text,
+};
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    read_u32(data, offset) as u64 | (read_u32(data, offset + 4) as u64) << 32
+}
+
+#[test]
+fn test_bitness() {
+    assert_eq!(Bitness::current().pointer_size(), core::mem::size_of::<usize>());
+    assert_eq!(Bitness::Bits32.pointer_size(), 4);
+    assert_eq!(Bitness::Bits64.pointer_size(), 8);
+}
+
+#[test]
+fn test_encode_list_view_item() {
+    let item = encode_list_view_item(Bitness::Bits32, 2, 0x1234_5678, 256);
+    assert_eq!(item.len(), 60);
+    assert_eq!(read_u32(&item, 0), 0x0001);
+    assert_eq!(read_u32(&item, 8), 2);
+    assert_eq!(read_u32(&item, 20), 0x1234_5678);
+    assert_eq!(read_u32(&item, 24), 256);
+
+    let item = encode_list_view_item(Bitness::Bits64, 3, 0x1_2345_6789, 512);
+    assert_eq!(item.len(), 88);
+    assert_eq!(read_u32(&item, 0), 0x0001);
+    assert_eq!(read_u32(&item, 8), 3);
+    assert_eq!(read_u64(&item, 24), 0x1_2345_6789);
+    assert_eq!(read_u32(&item, 32), 512);
+}
+
+#[test]
+fn test_encode_list_view_column() {
+    let mut column = encode_list_view_column(Bitness::Bits32, 0x1000, 128);
+    assert_eq!(column.len(), 44);
+    assert_eq!(read_u32(&column, 0), 0x0006);
+    assert_eq!(read_u32(&column, 12), 0x1000);
+    assert_eq!(read_u32(&column, 16), 128);
+    column[8..12].copy_from_slice(&100i32.to_le_bytes());
+    assert_eq!(decode_list_view_column_width(Bitness::Bits32, &column), 100);
+
+    let mut column = encode_list_view_column(Bitness::Bits64, 0x2_0000_1000, 128);
+    assert_eq!(column.len(), 56);
+    assert_eq!(read_u64(&column, 16), 0x2_0000_1000);
+    assert_eq!(read_u32(&column, 24), 128);
+    column[8..12].copy_from_slice(&200i32.to_le_bytes());
+    assert_eq!(decode_list_view_column_width(Bitness::Bits64, &column), 200);
+}
+
+#[test]
+fn test_encode_tree_view_item() {
+    let mut item = encode_tree_view_item(Bitness::Bits32, 0xABCD, 0x1000, 64);
+    assert_eq!(item.len(), 40);
+    assert_eq!(read_u32(&item, 0), 0x0051);
+    assert_eq!(read_u32(&item, 4), 0xABCD);
+    assert_eq!(read_u32(&item, 16), 0x1000);
+    assert_eq!(read_u32(&item, 20), 64);
+    assert!(!decode_tree_view_item_children(Bitness::Bits32, &item));
+    item[32] = 1;
+    assert!(decode_tree_view_item_children(Bitness::Bits32, &item));
+
+    let mut item = encode_tree_view_item(Bitness::Bits64, 0x7FF0_0000_ABCD, 0x7FF0_0000_1000, 64);
+    assert_eq!(item.len(), 56);
+    assert_eq!(read_u64(&item, 8), 0x7FF0_0000_ABCD);
+    assert_eq!(read_u64(&item, 24), 0x7FF0_0000_1000);
+    assert_eq!(read_u32(&item, 32), 64);
+    assert!(!decode_tree_view_item_children(Bitness::Bits64, &item));
+    item[44] = 1;
+    assert!(decode_tree_view_item_children(Bitness::Bits64, &item));
+}
+
+#[test]
+fn test_decode_text() {
+    let mut data: Vec<u8> = "Item \u{1F600}".encode_utf16().flat_map(|ch| ch.to_le_bytes().to_vec()).collect();
+    assert_eq!(decode_text(&data), "Item \u{1F600}");
+
+    data.extend_from_slice(&[0, 0, b'X', 0]);
+    assert_eq!(decode_text(&data), "Item \u{1F600}");
+    assert_eq!(decode_text(&[]), "");
+}
+
+#[cfg(windows)]
+#[test]
+fn test_remote_buffer() {
+    use windows_win::raw::memory::RemoteBuffer;
+    use windows_win::raw::process::get_current_handle;
+
+    let buffer = RemoteBuffer::alloc(get_current_handle(), 16).expect("To allocate");
+    assert_eq!(buffer.size(), 16);
+    buffer.write(4, &[1, 2, 3]).expect("To write");
+
+    let mut data = [0u8; 8];
+    buffer.read(0, &mut data).expect("To read");
+    assert_eq!(data, [0, 0, 0, 0, 1, 2, 3, 0]);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_list_box_combo_box() {
+    use windows_win::controls::{ListBox, ComboBox};
+    use windows_win::raw::window::{Builder, send_message, destroy};
+
+    const LB_ADDSTRING: u32 = 0x0180;
+    const LB_SETCURSEL: u32 = 0x0186;
+    const CB_ADDSTRING: u32 = 0x0143;
+    const CB_SETCURSEL: u32 = 0x014E;
+
+    let add = |window, msg, text: &str| {
+        let text: Vec<u16> = text.encode_utf16().chain(Some(0)).collect();
+        send_message(window, msg, 0, text.as_ptr() as isize, None).expect("To add string");
+    };
+
+    //LBS_HASSTRINGS
+    let list = Builder::new().class_name("LISTBOX").style(0x0040).create().expect("To create list box");
+    let list_box = ListBox::new(list);
+    assert_eq!(list_box.items().expect("To get items"), Vec::<String>::new());
+    assert_eq!(list_box.selected_index().expect("To get selection"), None);
+    add(list, LB_ADDSTRING, "First");
+    add(list, LB_ADDSTRING, "Second");
+    send_message(list, LB_SETCURSEL, 1, 0, None).expect("To select");
+    assert_eq!(list_box.items().expect("To get items"), vec!["First", "Second"]);
+    assert_eq!(list_box.selected_index().expect("To get selection"), Some(1));
+    assert!(list_box.item_text(2).is_err());
+
+    //CBS_DROPDOWNLIST | CBS_HASSTRINGS
+    let combo = Builder::new().class_name("COMBOBOX").style(0x0003 | 0x0200).create().expect("To create combo box");
+    let combo_box = ComboBox::new(combo);
+    assert_eq!(combo_box.selected().expect("To get selection"), None);
+    add(combo, CB_ADDSTRING, "One");
+    add(combo, CB_ADDSTRING, "Two");
+    send_message(combo, CB_SETCURSEL, 0, 0, None).expect("To select");
+    assert_eq!(combo_box.len().expect("To get len"), 2);
+    assert_eq!(combo_box.items().expect("To get items"), vec!["One", "Two"]);
+    assert_eq!(combo_box.selected().expect("To get selection").as_deref(), Some("One"));
+
+    assert!(destroy(list));
+    assert!(destroy(combo));
+}
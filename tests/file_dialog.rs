@@ -0,0 +1,74 @@
+use windows_win::ui::file_dialog::{split_files, CommDlgError, Filter, ParseFilterError};
+
+fn to_wide(text: &str) -> Vec<u16> {
+    text.encode_utf16().collect()
+}
+
+#[test]
+fn should_parse_filters() {
+    let filters = Filter::parse("Logs (*.log)|*.log|Images | *.png; *.jpg ;").expect("To parse filters");
+    assert_eq!(filters, vec![
+        Filter::new("Logs (*.log)", &["*.log"]),
+        Filter::new("Images", &["*.png", "*.jpg"]),
+    ]);
+
+    assert!(Filter::parse("").expect("To parse empty filters").is_empty());
+    assert!(Filter::parse("  ").expect("To parse empty filters").is_empty());
+}
+
+#[test]
+fn should_not_parse_invalid_filters() {
+    assert_eq!(Filter::parse("Logs"), Err(ParseFilterError::MissingPatterns("Logs".to_owned())));
+    assert_eq!(Filter::parse("Logs|*.log|All|"), Err(ParseFilterError::MissingPatterns("All".to_owned())));
+    assert_eq!(Filter::parse("Logs|*.log| |*.*"), Err(ParseFilterError::EmptyName(1)));
+    assert_eq!(Filter::parse("|*.log"), Err(ParseFilterError::EmptyName(0)));
+}
+
+#[test]
+fn should_encode_filters() {
+    let filters = Filter::parse("Logs|*.log|Images|*.png;*.jpg").expect("To parse filters");
+    assert_eq!(Filter::encode(&filters), to_wide("Logs\0*.log\0Images\0*.png;*.jpg\0\0"));
+    assert_eq!(Filter::encode(&[]), vec![0, 0]);
+}
+
+#[test]
+fn should_display_filters_in_parsable_form() {
+    let filter = Filter::new("Images", &["*.png", "*.jpg"]);
+    assert_eq!(filter.to_string(), "Images|*.png;*.jpg");
+
+    let text = filter.to_string();
+    assert_eq!(Filter::parse(&text).expect("To parse filter"), vec![filter]);
+}
+
+fn to_buffer(parts: &[&str], len: usize) -> Vec<u16> {
+    let mut buffer: Vec<u16> = parts.join("\0").encode_utf16().collect();
+    buffer.resize(len, 0);
+    buffer
+}
+
+#[test]
+fn should_split_single_file() {
+    //Leftover of longer initial name follows selected path.
+    let buffer = to_buffer(&["C:\\Temp\\a.txt", "initial name.log"], 32);
+    assert_eq!(split_files(&buffer, 8), vec![to_wide("C:\\Temp\\a.txt")]);
+
+    assert!(split_files(&[0; 16], 0).is_empty());
+}
+
+#[test]
+fn should_split_multiple_files() {
+    let buffer = to_buffer(&["C:\\Temp", "a.txt", "b.txt"], 32);
+    assert_eq!(split_files(&buffer, 8), vec![to_wide("C:\\Temp\\a.txt"), to_wide("C:\\Temp\\b.txt")]);
+
+    let buffer = to_buffer(&["C:\\", "a.txt"], 16);
+    assert_eq!(split_files(&buffer, 4), vec![to_wide("C:\\a.txt")]);
+}
+
+#[test]
+fn test_comm_dlg_error() {
+    let error = CommDlgError::BufferTooSmall.into_error_code();
+    assert_eq!(error.raw_code(), 0x3003);
+    assert_eq!(CommDlgError::BufferTooSmall, error);
+    assert_ne!(error, windows_win::ErrorCode::new_system(0x3003));
+    assert_eq!(error.to_string(), "CommDlgError(12291): Buffer is too small for selected files");
+}
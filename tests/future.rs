@@ -0,0 +1,78 @@
+#![cfg(windows)]
+
+use windows_win::sys::{AddClipboardFormatListener, WM_GETTEXTLENGTH, WM_USER};
+use windows_win::raw::window::Builder;
+use windows_win::future::{Messages, send_message};
+use windows_win::Window;
+
+use clipboard_win::set_clipboard_string;
+
+use std::future::Future;
+use std::sync::{Arc, mpsc};
+use std::task::{Context, Poll, Wake};
+use std::thread;
+
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = Box::pin(fut);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut ctx = Context::from_waker(&waker);
+
+    loop {
+        match fut.as_mut().poll(&mut ctx) {
+            Poll::Ready(result) => break result,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn test_messages_thread_message() {
+    let mut messages = Messages::spawn(|| Ok(())).expect("To spawn message loop");
+    messages.thread().post(WM_USER + 1, 2, 3).expect("To post message");
+
+    let msg = block_on(messages.recv()).expect("To get message");
+    assert_eq!(msg.id(), WM_USER + 1);
+    assert_eq!(msg.w_param(), 2);
+    assert_eq!(msg.l_param(), 3);
+    assert!(msg.window().is_null());
+
+    messages.thread().post(windows_win::sys::WM_QUIT, 0, 0).expect("To post quit");
+    assert!(block_on(messages.recv()).is_none());
+}
+
+#[test]
+fn test_messages_clipboard_update() {
+    let mut messages = Messages::spawn(|| {
+        let window = Window::from_builder(Builder::new().class_name("BUTTON").parent_message())?;
+        unsafe { AddClipboardFormatListener(window.inner()); }
+        Ok(window)
+    }).expect("To spawn message loop");
+
+    assert!(set_clipboard_string("Test").is_ok());
+    let msg = block_on(messages.recv()).expect("To get message");
+    assert_eq!(msg.id(), 797); //Clipboard update
+}
+
+#[test]
+fn test_send_message_future() {
+    let (sender, receiver) = mpsc::channel();
+    let messages = Messages::spawn(move || {
+        let window = Window::from_builder(Builder::new().class_name("BUTTON").window_name("Test").parent_message())?;
+        sender.send(window.inner() as usize).unwrap();
+        Ok(window)
+    }).expect("To spawn message loop");
+    let window = receiver.recv().unwrap();
+
+    let result = block_on(send_message(window as _, WM_GETTEXTLENGTH, 0, 0, 1000)).expect("To send message");
+    assert_eq!(result, 4);
+
+    drop(messages);
+}
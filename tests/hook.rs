@@ -0,0 +1,125 @@
+use windows_win::hook::{KeyboardEvent, MouseEvent, MouseEventKind};
+use windows_win::input::MouseButton;
+use windows_win::sys::{KBDLLHOOKSTRUCT, MSLLHOOKSTRUCT, POINT, WM_KEYUP, WM_SYSKEYDOWN, WM_XBUTTONDOWN, WM_MOUSEWHEEL, WM_LBUTTONUP, WM_USER, VK_F1};
+
+#[test]
+fn test_keyboard_event_from_raw() {
+    let raw = KBDLLHOOKSTRUCT {
+        vkCode: VK_F1 as _,
+        scanCode: 0x3B,
+        flags: 0x80 | 0x10,
+        time: 42,
+        dwExtraInfo: 0,
+    };
+
+    let event = KeyboardEvent::from_raw(WM_KEYUP as _, &raw);
+    assert_eq!(event.vk, VK_F1 as _);
+    assert_eq!(event.scan_code, 0x3B);
+    assert_eq!(event.time, 42);
+    assert!(event.is_up());
+    assert!(!event.is_down());
+    assert!(event.is_injected());
+    assert!(!event.is_system());
+    assert!(!event.is_extended());
+
+    let raw = KBDLLHOOKSTRUCT { flags: 0x20 | 0x01, ..raw };
+    let event = KeyboardEvent::from_raw(WM_SYSKEYDOWN as _, &raw);
+    assert!(event.is_down());
+    assert!(event.is_system());
+    assert!(event.is_alt_down());
+    assert!(event.is_extended());
+    assert!(!event.is_injected());
+}
+
+#[test]
+fn test_mouse_event_from_raw() {
+    let raw = MSLLHOOKSTRUCT {
+        pt: POINT { x: -10, y: 20 },
+        mouseData: 0x0002 << 16,
+        flags: 0x01,
+        time: 1,
+        dwExtraInfo: 0,
+    };
+
+    let event = MouseEvent::from_raw(WM_XBUTTONDOWN as _, &raw);
+    assert_eq!(event.kind, MouseEventKind::ButtonDown(MouseButton::X2));
+    assert_eq!((event.x, event.y), (-10, 20));
+    assert!(event.is_injected());
+
+    let raw = MSLLHOOKSTRUCT { mouseData: 0xFF88 << 16, flags: 0, ..raw };
+    let event = MouseEvent::from_raw(WM_MOUSEWHEEL as _, &raw);
+    assert_eq!(event.kind, MouseEventKind::Wheel(-120));
+    assert!(!event.is_injected());
+
+    assert_eq!(MouseEvent::from_raw(WM_LBUTTONUP as _, &raw).kind, MouseEventKind::ButtonUp(MouseButton::Left));
+    assert_eq!(MouseEvent::from_raw(WM_USER as _, &raw).kind, MouseEventKind::Other(WM_USER));
+}
+
+#[cfg(windows)]
+#[test]
+fn test_keyboard_hook_injected() {
+    use windows_win::hook::{KeyboardHook, HookAction};
+    use windows_win::input::InputBuilder;
+    use windows_win::sys::VK_F24;
+
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let (sender, receiver) = mpsc::channel();
+    let thread = KeyboardHook::spawn(move |event| {
+        if event.vk == VK_F24 as _ && event.is_injected() {
+            let _ = sender.send(*event);
+            return HookAction::Block;
+        }
+        HookAction::Pass
+    }).expect("To install hook");
+
+    InputBuilder::new().key_press(VK_F24).send().expect("To send input");
+
+    let event = receiver.recv_timeout(Duration::from_secs(5)).expect("To get key down");
+    assert!(event.is_down());
+    let event = receiver.recv_timeout(Duration::from_secs(5)).expect("To get key up");
+    assert!(event.is_up());
+
+    thread.stop();
+}
+
+#[cfg(windows)]
+#[test]
+fn test_keyboard_hook_drop_within_callback() {
+    use windows_win::hook::{KeyboardHook, HookAction};
+    use windows_win::input::InputBuilder;
+    use windows_win::message_loop::MessageThread;
+    use windows_win::sys::{VK_F24, MSG};
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let (sender, receiver) = mpsc::channel();
+    let thread = MessageThread::spawn(move || {
+        let hook = Rc::new(RefCell::new(None));
+        let inner = hook.clone();
+        let cb_sender = sender.clone();
+        *hook.borrow_mut() = Some(KeyboardHook::new(move |event| {
+            if event.vk == VK_F24 as _ && inner.borrow_mut().take().is_some() {
+                let _ = cb_sender.send(true);
+            }
+            HookAction::Pass
+        })?);
+
+        Ok(move |msg: &MSG| if msg.message == WM_USER {
+            //Hook removed by callback must not prevent new one.
+            let _ = sender.send(KeyboardHook::new(|_| HookAction::Pass).is_ok());
+        })
+    }).expect("To install hook");
+
+    InputBuilder::new().key_press(VK_F24).send().expect("To send input");
+    assert!(receiver.recv_timeout(Duration::from_secs(5)).expect("To drop hook"));
+
+    thread.post(WM_USER, 0, 0).expect("To post message");
+    assert!(receiver.recv_timeout(Duration::from_secs(5)).expect("To install hook again"));
+
+    thread.stop();
+}
@@ -0,0 +1,105 @@
+use windows_win::hotkey::{Shortcut, Modifiers, ParseError, HotkeyEvent, parse_key, key_name};
+use windows_win::sys::{MSG, POINT, WM_HOTKEY, WM_USER, VK_F1, VK_F24, VK_RETURN, VK_NUMPAD0, VK_OEM_PLUS, MOD_CONTROL, MOD_SHIFT};
+
+use core::ptr;
+
+fn msg(message: u32, w_param: usize, l_param: isize) -> MSG {
+    MSG {
+        hwnd: ptr::null_mut(),
+        message,
+        wParam: w_param,
+        lParam: l_param,
+        time: 0,
+        pt: POINT { x: 0, y: 0 },
+    }
+}
+
+#[test]
+fn test_parse_key() {
+    assert_eq!(parse_key("a"), Some(0x41));
+    assert_eq!(parse_key("Z"), Some(0x5A));
+    assert_eq!(parse_key("0"), Some(0x30));
+    assert_eq!(parse_key("F1"), Some(VK_F1));
+    assert_eq!(parse_key("f24"), Some(VK_F24));
+    assert_eq!(parse_key("F0"), None);
+    assert_eq!(parse_key("F25"), None);
+    assert_eq!(parse_key("Numpad7"), Some(VK_NUMPAD0 + 7));
+    assert_eq!(parse_key("Numpad10"), None);
+    assert_eq!(parse_key("enter"), Some(VK_RETURN));
+    assert_eq!(parse_key("Return"), Some(VK_RETURN));
+    assert_eq!(parse_key("Plus"), Some(VK_OEM_PLUS));
+    assert_eq!(parse_key("$"), None);
+    assert_eq!(parse_key("Föö"), None);
+    assert_eq!(parse_key(""), None);
+}
+
+#[test]
+fn test_key_name() {
+    assert_eq!(key_name(0x41).unwrap(), "A");
+    assert_eq!(key_name(VK_F1 + 11).unwrap(), "F12");
+    assert_eq!(key_name(VK_NUMPAD0 + 3).unwrap(), "Numpad3");
+    assert_eq!(key_name(VK_RETURN).unwrap(), "Enter");
+    assert!(key_name(0xFF).is_none());
+
+    for vk in 0..=0xFF {
+        if let Some(name) = key_name(vk) {
+            assert_eq!(parse_key(&name), Some(vk));
+        }
+    }
+}
+
+#[test]
+fn test_parse_shortcut() {
+    let shortcut: Shortcut = "Ctrl+Shift+F12".parse().unwrap();
+    assert_eq!(shortcut.modifiers, Modifiers::new().ctrl().shift());
+    assert_eq!(shortcut.modifiers.raw(), MOD_CONTROL | MOD_SHIFT);
+    assert_eq!(shortcut.vk, VK_F1 + 11);
+    assert_eq!(shortcut.to_string(), "Ctrl+Shift+F12");
+
+    let shortcut: Shortcut = " alt + win + control + x ".parse().unwrap();
+    assert!(shortcut.modifiers.is_alt());
+    assert!(shortcut.modifiers.is_win());
+    assert!(shortcut.modifiers.is_ctrl());
+    assert!(!shortcut.modifiers.is_shift());
+    assert_eq!(shortcut.vk, 0x58);
+    assert_eq!(shortcut.to_string(), "Ctrl+Alt+Win+X");
+
+    let shortcut: Shortcut = "Esc".parse().unwrap();
+    assert_eq!(shortcut.modifiers, Modifiers::default());
+    assert!(shortcut.no_repeat().modifiers.is_no_repeat());
+    assert_eq!(shortcut.no_repeat().to_string(), "Esc");
+}
+
+#[test]
+fn test_parse_shortcut_errors() {
+    assert_eq!("".parse::<Shortcut>(), Err(ParseError::Empty));
+    assert_eq!("Ctrl++".parse::<Shortcut>(), Err(ParseError::Empty));
+    assert_eq!("Ctrl+Shift".parse::<Shortcut>(), Err(ParseError::MissingKey));
+    assert_eq!("Ctrl+A+B".parse::<Shortcut>(), Err(ParseError::MultipleKeys));
+    assert_eq!("Ctrl+Foo".parse::<Shortcut>(), Err(ParseError::UnknownKey("Foo".to_owned())));
+}
+
+#[test]
+fn test_hotkey_event_from_msg() {
+    let event = HotkeyEvent::from_msg(&msg(WM_HOTKEY, 5, ((VK_F1 as isize) << 16) | (MOD_CONTROL as isize))).unwrap();
+    assert_eq!(event.id, 5);
+    assert_eq!(event.shortcut, Shortcut::new(Modifiers::new().ctrl(), VK_F1));
+
+    assert!(HotkeyEvent::from_msg(&msg(WM_USER, 5, 0)).is_none());
+}
+
+#[cfg(windows)]
+#[test]
+fn test_hotkey_register() {
+    use windows_win::hotkey::Hotkey;
+
+    //Unlikely to be used by anything else
+    let shortcut: Shortcut = "Ctrl+Shift+Alt+F24".parse().unwrap();
+    let hotkey = Hotkey::register(shortcut.no_repeat(), None).expect("To register hotkey");
+    assert_eq!(hotkey.shortcut(), shortcut.no_repeat());
+    assert!(Hotkey::register(shortcut, None).is_err());
+    drop(hotkey);
+
+    let hotkey = Hotkey::register(shortcut, None).expect("To register hotkey again");
+    assert!(hotkey.is_event(&HotkeyEvent { id: hotkey.id(), shortcut }));
+}
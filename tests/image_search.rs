@@ -0,0 +1,189 @@
+use windows_win::capture::Image;
+use windows_win::image_search::{Match, Region, Search};
+
+fn filled(width: u32, height: u32, pixel: [u8; 4]) -> Image {
+    let mut image = Image::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            image.set_pixel(x, y, pixel);
+        }
+    }
+    image
+}
+
+fn draw(image: &mut Image, template: &Image, x: u32, y: u32) {
+    for row in 0..template.height() {
+        for col in 0..template.width() {
+            image.set_pixel(x + col, y + row, template.pixel(col, row).unwrap());
+        }
+    }
+}
+
+fn button() -> Image {
+    let mut template = filled(3, 2, [200, 100, 50, 255]);
+    template.set_pixel(1, 0, [0, 0, 255, 255]);
+    template
+}
+
+#[test]
+fn should_find_exact_match() {
+    let template = button();
+    let mut image = filled(10, 8, [10, 10, 10, 255]);
+    draw(&mut image, &template, 4, 5);
+
+    let found = Search::new(&template).find(&image).expect("To find template");
+    assert_eq!((found.x, found.y), (4, 5));
+    assert_eq!((found.width, found.height), (3, 2));
+    assert_eq!(found.score, 0.0);
+    assert_eq!(found.center(), (5, 6));
+}
+
+#[test]
+fn should_ignore_alpha() {
+    let template = button();
+    let mut image = filled(6, 6, [0, 0, 0, 0]);
+    draw(&mut image, &template, 1, 1);
+    image.set_pixel(2, 1, [0, 0, 255, 0]);
+
+    assert!(Search::new(&template).find(&image).is_some());
+}
+
+#[test]
+fn should_respect_tolerance() {
+    let template = button();
+    let mut image = filled(8, 8, [10, 10, 10, 255]);
+    draw(&mut image, &template, 2, 2);
+    image.set_pixel(3, 3, [205, 100, 50, 255]);
+
+    assert!(Search::new(&template).find(&image).is_none());
+
+    let found = Search::new(&template).tolerance(5).find(&image).expect("To find within tolerance");
+    assert_eq!((found.x, found.y), (2, 2));
+    assert!(found.score > 0.0);
+    assert!(Search::new(&template).tolerance(4).find(&image).is_none());
+}
+
+#[test]
+fn should_score_by_squared_differences() {
+    let template = filled(2, 1, [0, 0, 0, 255]);
+    let image = filled(2, 1, [255, 255, 255, 255]);
+
+    let search = Search::new(&template).tolerance(255);
+    assert_eq!(search.score_at(&image, 0, 0), Some(1.0));
+    assert_eq!(search.score_at(&image, 1, 0), None);
+
+    let mut image = filled(2, 1, [0, 0, 0, 255]);
+    image.set_pixel(0, 0, [255, 0, 0, 255]);
+    //One channel out of six differs completely.
+    let score = search.score_at(&image, 0, 0).unwrap();
+    assert!((score - 1.0 / 6.0).abs() < 1e-9);
+
+    assert_eq!(search.max_score(0.1).score_at(&image, 0, 0), None);
+}
+
+#[test]
+fn should_pick_best_similar_match() {
+    let template = filled(2, 2, [100, 100, 100, 255]);
+    let mut image = filled(10, 4, [0, 0, 0, 255]);
+    draw(&mut image, &filled(2, 2, [90, 90, 90, 255]), 1, 1);
+    draw(&mut image, &filled(2, 2, [98, 98, 98, 255]), 6, 1);
+
+    let found = Search::new(&template).tolerance(255).max_score(0.01).find(&image).expect("To find similar");
+    assert_eq!((found.x, found.y), (6, 1));
+
+    let found = Search::new(&template).tolerance(255).max_score(0.01).region(Region::new(0, 0, 5, 4)).find(&image).expect("To find in region");
+    assert_eq!((found.x, found.y), (1, 1));
+}
+
+#[test]
+fn should_restrict_search_to_region() {
+    let template = button();
+    let mut image = filled(12, 6, [10, 10, 10, 255]);
+    draw(&mut image, &template, 1, 1);
+    draw(&mut image, &template, 8, 3);
+
+    let found = Search::new(&template).region(Region::new(5, 0, 100, 100)).find(&image).expect("To find in region");
+    assert_eq!((found.x, found.y), (8, 3));
+
+    //Template must be within region entirely.
+    assert!(Search::new(&template).region(Region::new(5, 0, 5, 6)).find(&image).is_none());
+    assert!(Search::new(&template).region(Region::new(20, 0, 5, 6)).find(&image).is_none());
+}
+
+#[test]
+fn should_find_all_without_overlaps() {
+    let template = filled(2, 2, [50, 50, 50, 255]);
+    let mut image = filled(12, 4, [0, 0, 0, 255]);
+    //Wider area yields several overlapping positions.
+    draw(&mut image, &filled(3, 2, [50, 50, 50, 255]), 1, 1);
+    draw(&mut image, &template, 8, 0);
+
+    let found = Search::new(&template).find_all(&image);
+    assert_eq!(found.len(), 2);
+    //Equal scores are in order of position.
+    assert_eq!((found[0].x, found[0].y), (8, 0));
+    assert_eq!((found[1].x, found[1].y), (1, 1));
+
+    let found = Search::new(&template).limit(1).find_all(&image);
+    assert_eq!(found.len(), 1);
+}
+
+#[test]
+fn should_order_all_by_score() {
+    let template = filled(2, 2, [100, 100, 100, 255]);
+    let mut image = filled(12, 4, [0, 0, 0, 255]);
+    draw(&mut image, &filled(2, 2, [95, 95, 95, 255]), 0, 0);
+    draw(&mut image, &template, 6, 2);
+
+    let found = Search::new(&template).tolerance(10).find_all(&image);
+    assert_eq!(found.len(), 2);
+    assert_eq!((found[0].x, found[0].y), (6, 2));
+    assert_eq!(found[0].score, 0.0);
+    assert_eq!((found[1].x, found[1].y), (0, 0));
+}
+
+#[test]
+fn should_handle_oversized_template() {
+    let template = filled(5, 5, [0, 0, 0, 255]);
+    let image = filled(4, 4, [0, 0, 0, 255]);
+
+    assert!(Search::new(&template).find(&image).is_none());
+    assert!(Search::new(&template).find_all(&image).is_empty());
+    assert_eq!(Search::new(&template).score_at(&image, 0, 0), None);
+}
+
+#[test]
+fn should_map_match_to_screen() {
+    let found = Match {
+        x: 10,
+        y: 20,
+        width: 4,
+        height: 6,
+        score: 0.0,
+    };
+
+    let found = found.offset(-100, 50);
+    assert_eq!((found.x, found.y), (-90, 70));
+    assert_eq!(found.center(), (-88, 73));
+}
+
+#[cfg(windows)]
+#[test]
+fn test_match_window() {
+    use windows_win::Window;
+    use windows_win::raw::window::{Builder, get_class};
+
+    //WS_POPUP | WS_VISIBLE
+    const STYLE: u32 = 0x80000000 | 0x10000000;
+    //WS_EX_TOPMOST | WS_EX_TOOLWINDOW
+    const EX_STYLE: u32 = 0x00000008 | 0x00000080;
+
+    let window = Window::from_builder(Builder::new().class_name("STATIC").style(STYLE as _).ex_style(EX_STYLE as _).position(0, 0).size(64, 64)).expect("To create window");
+
+    let found = Match { x: 0, y: 0, width: 64, height: 64, score: 0.0 };
+    let under = found.window().expect("To find window under match");
+    //Dropping result must not destroy window
+    assert_eq!(found.window(), Some(under));
+    assert!(get_class(under).is_ok());
+    assert!(get_class(window.inner()).is_ok());
+}
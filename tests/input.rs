@@ -0,0 +1,112 @@
+use windows_win::input::{InputBuilder, Input, KeyboardInput, MouseInput, MouseButton, VirtualScreen, MAX_ABSOLUTE, is_extended_key};
+use windows_win::hotkey::Shortcut;
+use windows_win::sys::{DWORD, VK_CONTROL, VK_SHIFT, VK_LEFT, VK_RETURN, VK_F1};
+
+const KEYUP: DWORD = 0x0002;
+const EXTENDED: DWORD = 0x0001;
+const UNICODE: DWORD = 0x0004;
+
+fn key(vk: u32, flags: DWORD) -> Input {
+    Input::Keyboard(KeyboardInput { vk: vk as u16, scan: 0, flags })
+}
+
+fn unicode(unit: u16, flags: DWORD) -> Input {
+    Input::Keyboard(KeyboardInput { vk: 0, scan: unit, flags: UNICODE | flags })
+}
+
+fn mouse(dx: i32, dy: i32, data: i32, flags: DWORD) -> Input {
+    Input::Mouse(MouseInput { dx, dy, data, flags })
+}
+
+#[test]
+fn test_key_events() {
+    let mut builder = InputBuilder::new();
+    builder.key_press(VK_RETURN).key_down(VK_LEFT).key_up(VK_LEFT);
+
+    assert!(is_extended_key(VK_LEFT));
+    assert!(!is_extended_key(VK_RETURN));
+    assert_eq!(builder.inputs(), &[
+        key(VK_RETURN, 0),
+        key(VK_RETURN, KEYUP),
+        key(VK_LEFT, EXTENDED),
+        key(VK_LEFT, EXTENDED | KEYUP),
+    ]);
+}
+
+#[test]
+fn test_text_events() {
+    let mut builder = InputBuilder::new();
+    builder.text("a😀");
+
+    assert_eq!(builder.into_inputs(), vec![
+        unicode(0x61, 0),
+        unicode(0x61, KEYUP),
+        unicode(0xD83D, 0),
+        unicode(0xD83D, KEYUP),
+        unicode(0xDE00, 0),
+        unicode(0xDE00, KEYUP),
+    ]);
+
+    assert!(InputBuilder::new().text("").inputs().is_empty());
+}
+
+#[test]
+fn test_shortcut_events() {
+    let shortcut: Shortcut = "Ctrl+Shift+F1".parse().unwrap();
+    let mut builder = InputBuilder::new();
+    builder.shortcut(&shortcut);
+
+    assert_eq!(builder.inputs(), &[
+        key(VK_CONTROL, 0),
+        key(VK_SHIFT, 0),
+        key(VK_F1, 0),
+        key(VK_F1, KEYUP),
+        key(VK_SHIFT, KEYUP),
+        key(VK_CONTROL, KEYUP),
+    ]);
+}
+
+#[test]
+fn test_normalize() {
+    let screen = VirtualScreen { x: -1920, y: 0, width: 3840, height: 1080 };
+
+    assert_eq!(screen.normalize(-1920, 0), (0, 0));
+    assert_eq!(screen.normalize(1919, 1079), (MAX_ABSOLUTE, MAX_ABSOLUTE));
+    assert_eq!(screen.normalize(-5000, 5000), (0, MAX_ABSOLUTE));
+    //Midpoint is rounded to nearest
+    assert_eq!(screen.normalize(0, 540), (32776, 32798));
+
+    let screen = VirtualScreen { x: 0, y: 0, width: 1, height: 0 };
+    assert_eq!(screen.normalize(10, 10), (0, 0));
+}
+
+#[test]
+fn test_mouse_events() {
+    let screen = VirtualScreen { x: 0, y: 0, width: 1001, height: 1001 };
+    let mut builder = InputBuilder::new();
+    builder.mouse_move_to(500, 1000, &screen)
+           .mouse_move_by(-5, 5)
+           .click(MouseButton::Left)
+           .double_click(MouseButton::X2)
+           .wheel(-120)
+           .hwheel(240);
+
+    assert_eq!(builder.inputs(), &[
+        mouse(32768, 65535, 0, 0x0001 | 0x8000 | 0x4000),
+        mouse(-5, 5, 0, 0x0001),
+        mouse(0, 0, 0, 0x0002),
+        mouse(0, 0, 0, 0x0004),
+        mouse(0, 0, 2, 0x0080),
+        mouse(0, 0, 2, 0x0100),
+        mouse(0, 0, 2, 0x0080),
+        mouse(0, 0, 2, 0x0100),
+        mouse(0, 0, -120, 0x0800),
+        mouse(0, 0, 240, 0x1000),
+    ]);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_send_empty() {
+    assert!(windows_win::input::send(&[]).is_ok());
+}
@@ -0,0 +1,25 @@
+#![cfg(windows)]
+
+use windows_win::Window;
+use windows_win::sys::{RGB, WS_EX_LAYERED};
+use windows_win::raw::window::{Builder, get_opacity, get_ex_style, is_visible_and_not_cloaked};
+
+#[test]
+fn test_layered_window() {
+    let window = Builder::new().class_name("STATIC").window_name("LayeredTest").create().expect("To create window");
+    let window = Window::from_hwnd(window);
+    assert_eq!(get_opacity(window.inner()).expect("To get opacity"), None);
+
+    assert!(window.set_opacity(128).is_ok());
+    assert_ne!(get_ex_style(window.inner()) & WS_EX_LAYERED, 0);
+    assert_eq!(get_opacity(window.inner()).expect("To get opacity"), Some(128));
+
+    assert_eq!(RGB(0x12, 0x34, 0x56), 0x563412);
+    assert!(window.set_color_key(RGB(255, 0, 255)).is_ok());
+
+    //Never shown
+    assert!(!is_visible_and_not_cloaked(window.inner()));
+    assert!(!window.is_cloaked());
+
+    window.destroy();
+}
@@ -0,0 +1,116 @@
+use windows_win::menu::{Menu, MenuItem, MenuState};
+use windows_win::sys::{WM_COMMAND, WM_SYSCOMMAND};
+
+fn item(id: u32, text: &str) -> MenuItem {
+    MenuItem {
+        id,
+        text: text.to_owned(),
+        window: 0x100,
+        ..MenuItem::default()
+    }
+}
+
+fn sample_menu() -> Menu {
+    let separator = MenuItem {
+        is_separator: true,
+        ..MenuItem::default()
+    };
+
+    Menu {
+        items: vec![
+            MenuItem {
+                submenu: Some(Menu {
+                    items: vec![
+                        item(1, "&New\tCtrl+N"),
+                        item(2, "&Save\tCtrl+S"),
+                        item(3, "Save &As..."),
+                        separator.clone(),
+                        MenuItem {
+                            submenu: Some(Menu {
+                                items: vec![item(10, "&1 notes.txt"), item(11, "Tom && Jerry")],
+                            }),
+                            ..item(0, "&Recent")
+                        },
+                    ]
+                }),
+                ..item(0, "&File")
+            },
+            MenuItem {
+                state: MenuState::from_raw(0x0003),
+                ..item(20, "&Help")
+            },
+        ]
+    }
+}
+
+#[test]
+fn test_menu_find() {
+    let menu = sample_menu();
+
+    assert_eq!(menu.find("File/Save As...").map(|item| item.id), Some(3));
+    assert_eq!(menu.find("file / save").map(|item| item.id), Some(2));
+    assert_eq!(menu.find("File/Recent/Tom & Jerry").map(|item| item.id), Some(11));
+    assert_eq!(menu.find("Help").map(|item| item.id), Some(20));
+    assert!(menu.find("File").unwrap().submenu.is_some());
+    assert!(menu.find("File/Open").is_none());
+    assert!(menu.find("Help/About").is_none());
+    assert!(menu.find("").is_none());
+
+    assert_eq!(menu.find_by_id(10).map(|item| item.label()), Some("1 notes.txt".to_owned()));
+    assert!(menu.find_by_id(0).is_none());
+    assert!(menu.find_by_id(99).is_none());
+}
+
+#[test]
+fn test_menu_item_label_command() {
+    assert_eq!(item(1, "&New\tCtrl+N").label(), "New");
+    assert_eq!(item(1, "Tom && Jerry").label(), "Tom & Jerry");
+    assert_eq!(item(1, "Trailing&").label(), "Trailing");
+
+    assert_eq!(item(2, "&Save").command(), (WM_COMMAND, 2, 0));
+    let close = MenuItem {
+        is_system: true,
+        ..item(0xF060, "&Close\tAlt+F4")
+    };
+    assert_eq!(close.command(), (WM_SYSCOMMAND, 0xF060, 0));
+}
+
+#[test]
+fn test_menu_state() {
+    let state = MenuState::from_raw(0x0008 | 0x1000);
+    assert!(state.is_checked());
+    assert!(state.is_default());
+    assert!(state.is_enabled());
+    assert!(!state.is_highlighted());
+
+    let state = MenuState::from_raw(0x0001);
+    assert!(state.is_grayed());
+    assert!(!state.is_disabled());
+    assert!(!state.is_enabled());
+
+    let state = MenuState::from_raw(0x0002 | 0x0080);
+    assert!(state.is_disabled());
+    assert!(state.is_highlighted());
+    assert!(!state.is_enabled());
+    assert_eq!(state.raw(), 0x0082);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_window_system_menu() {
+    use windows_win::Window;
+    use windows_win::raw::window::Builder;
+
+    //WS_OVERLAPPEDWINDOW
+    let window = Window::from_builder(Builder::new().class_name("STATIC").style(0x00CF0000)).expect("To create window");
+    assert!(window.menu().expect("To get menu").is_none());
+
+    let menu = window.system_menu().expect("To get system menu").expect("To have system menu");
+    let close = menu.find_by_id(0xF060).expect("To find close");
+    assert!(close.is_system);
+    assert_eq!(close.window, window.inner() as usize);
+    assert!(!close.label().is_empty());
+
+    let separator = menu.items.iter().find(|item| item.is_separator).expect("To have separator");
+    assert!(separator.invoke().is_err());
+}
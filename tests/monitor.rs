@@ -0,0 +1,125 @@
+use windows_win::monitor::{DpiAwareness, Scale};
+use windows_win::window_tree::Rect;
+use windows_win::sys::DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2;
+
+#[test]
+fn should_report_scale_factor() {
+    assert_eq!(Scale::default().dpi(), 96);
+    assert_eq!(Scale::new(0).dpi(), 96);
+
+    let scale = Scale::new(144);
+    assert_eq!(scale.factor(), 1.5);
+    assert_eq!(scale.percent(), 150);
+    assert_eq!(Scale::new(120).percent(), 125);
+    assert_eq!(Scale::new(192).percent(), 200);
+}
+
+#[test]
+fn should_scale_without_rounding() {
+    let scale = Scale::new(192);
+    assert_eq!(scale.to_physical(10), 20);
+    assert_eq!(scale.to_logical(20), 10);
+    assert_eq!(scale.to_physical(-10), -20);
+    assert_eq!(scale.to_logical(-20), -10);
+
+    let scale = Scale::default();
+    assert_eq!(scale.to_physical(13), 13);
+    assert_eq!(scale.to_logical(13), 13);
+}
+
+#[test]
+fn should_round_halves_away_from_zero() {
+    //1.5x
+    let scale = Scale::new(144);
+    assert_eq!(scale.to_physical(1), 2);
+    assert_eq!(scale.to_physical(3), 5);
+    assert_eq!(scale.to_physical(-1), -2);
+    assert_eq!(scale.to_physical(-3), -5);
+
+    //1 / 1.5 = 0.67
+    assert_eq!(scale.to_logical(1), 1);
+    assert_eq!(scale.to_logical(2), 1);
+    //4 / 1.5 = 2.67
+    assert_eq!(scale.to_logical(4), 3);
+    assert_eq!(scale.to_logical(-4), -3);
+}
+
+#[test]
+fn should_round_to_nearest() {
+    //1.25x
+    let scale = Scale::new(120);
+    //1.25
+    assert_eq!(scale.to_physical(1), 1);
+    //2.5
+    assert_eq!(scale.to_physical(2), 3);
+    //3.75
+    assert_eq!(scale.to_physical(3), 4);
+    //-2.5
+    assert_eq!(scale.to_physical(-2), -3);
+    //1.6
+    assert_eq!(scale.to_logical(2), 2);
+    //0.8
+    assert_eq!(scale.to_logical(1), 1);
+    //0.4
+    assert_eq!(Scale::new(240).to_logical(1), 0);
+}
+
+#[test]
+fn should_not_overflow() {
+    //Intermediate product doesn't fit into 32 bits.
+    let scale = Scale::new(192);
+    assert_eq!(scale.to_physical(1_000_000_000), 2_000_000_000);
+    assert_eq!(scale.to_logical(2_000_000_000), 1_000_000_000);
+}
+
+#[test]
+fn should_scale_points_and_rects() {
+    let scale = Scale::new(144);
+    assert_eq!(scale.point_to_physical((10, -10)), (15, -15));
+    assert_eq!(scale.point_to_logical((15, -15)), (10, -10));
+
+    let rect = Rect {
+        left: -1,
+        top: 1,
+        right: 3,
+        bottom: 5,
+    };
+    let physical = scale.rect_to_physical(rect);
+    assert_eq!(physical, Rect {
+        left: -2,
+        top: 2,
+        right: 5,
+        bottom: 8,
+    });
+    assert_eq!(scale.rect_to_logical(physical), Rect {
+        left: -1,
+        top: 1,
+        right: 3,
+        bottom: 5,
+    });
+}
+
+#[test]
+fn should_map_awareness_to_raw_context() {
+    assert_eq!(DpiAwareness::PerMonitorV2.raw(), DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2);
+    assert_eq!(DpiAwareness::Unaware.raw() as isize, -1);
+    assert_eq!(DpiAwareness::UnawareGdiScaled.raw() as isize, -5);
+}
+
+#[cfg(windows)]
+#[test]
+fn should_enumerate_monitors() {
+    use windows_win::monitor::Monitor;
+
+    let monitors = Monitor::all().expect("To enumerate monitors");
+    assert!(!monitors.is_empty());
+    assert_eq!(monitors.iter().filter(|monitor| monitor.is_primary).count(), 1);
+
+    let primary = Monitor::primary().expect("To get primary monitor");
+    assert!(primary.is_primary);
+    assert!(monitors.contains(&primary));
+    assert!(primary.rect.width() > 0);
+    assert!(primary.work_area.height() <= primary.rect.height());
+    assert!(primary.device.starts_with("\\\\.\\"));
+    assert!(primary.dpi().expect("To get DPI") > 0);
+}
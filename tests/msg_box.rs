@@ -0,0 +1,115 @@
+use windows_win::ui::msg_box::{Buttons, DefaultButton, Icon, Modality, MsgBoxResult, Style, StyleError};
+use windows_win::ui::msg_box::flags::{MB_HELP, MB_ICONERROR, MB_ICONINFORMATION, MB_SYSTEMMODAL, MB_TASKMODAL, MB_YESNOCANCEL};
+use windows_win::sys::{MB_DEFBUTTON3, MB_TOPMOST, IDYES, IDOK, IDCANCEL};
+
+#[test]
+fn should_compose_style_flags() {
+    assert_eq!(Style::default().raw(), 0);
+
+    let style = Style::new(Buttons::YesNoCancel).icon(Icon::Error)
+                                               .default_button(DefaultButton::Third)
+                                               .modality(Modality::Task)
+                                               .help();
+    assert_eq!(style.raw(), MB_YESNOCANCEL | MB_ICONERROR | MB_DEFBUTTON3 | MB_TASKMODAL | MB_HELP);
+    assert_eq!(style.button_count(), 4);
+    assert_eq!(style.validate(), Ok(()));
+
+    //Setting icon twice replaces it, instead of mixing flags.
+    let style = style.icon(Icon::Information).no_icon().icon(Icon::Information);
+    assert_eq!(style.raw() & 0xF0, MB_ICONINFORMATION);
+    assert_eq!(style.get_icon(), Some(Icon::Information));
+}
+
+#[test]
+fn should_parse_style_flags() {
+    let style = Style::from_raw(MB_YESNOCANCEL | MB_ICONERROR | MB_SYSTEMMODAL | MB_TOPMOST).expect("To parse flags");
+    assert_eq!(style.get_buttons(), Buttons::YesNoCancel);
+    assert_eq!(style.get_icon(), Some(Icon::Error));
+    assert_eq!(style.get_default_button(), DefaultButton::First);
+    assert_eq!(style.get_modality(), Modality::System);
+    assert!(!style.is_help());
+    //Unrelated flags are not part of style.
+    assert_eq!(style.raw(), MB_YESNOCANCEL | MB_ICONERROR | MB_SYSTEMMODAL);
+}
+
+#[test]
+fn should_reject_invalid_flags() {
+    assert_eq!(Style::from_raw(7), Err(StyleError::InvalidButtons(7)));
+    //Error and information icons combined.
+    assert_eq!(Style::from_raw(MB_ICONERROR | MB_ICONINFORMATION), Err(StyleError::InvalidIcon(0x50)));
+    assert_eq!(Style::from_raw(0x400), Err(StyleError::InvalidDefaultButton(0x400)));
+    assert_eq!(Style::from_raw(MB_SYSTEMMODAL | MB_TASKMODAL), Err(StyleError::InvalidModality(0x3000)));
+
+    let style = Style::new(Buttons::YesNo).default_button(DefaultButton::Third);
+    assert_eq!(style.validate(), Err(StyleError::MissingDefaultButton {
+        default: DefaultButton::Third,
+        count: 2,
+    }));
+    assert_eq!(style.help().validate(), Ok(()));
+}
+
+#[test]
+fn should_map_only_possible_results() {
+    assert_eq!(Buttons::YesNo.results(), &[MsgBoxResult::Yes, MsgBoxResult::No]);
+    assert_eq!(Buttons::YesNo.result(IDYES), MsgBoxResult::Yes);
+    assert_eq!(Buttons::YesNo.result(IDOK), MsgBoxResult::Ext(IDOK));
+    assert_eq!(Buttons::OkCancel.result(IDCANCEL), MsgBoxResult::Cancel);
+    assert_eq!(Buttons::Ok.result(32000), MsgBoxResult::Timeout);
+
+    for raw in 0..7 {
+        let buttons = Buttons::from_raw(raw).expect("To parse buttons");
+        assert_eq!(buttons.raw(), raw);
+        for result in buttons.results() {
+            assert_eq!(buttons.result((*result).into()), *result);
+        }
+    }
+}
+
+#[cfg(windows)]
+#[test]
+fn test_msg_box_timeout() {
+    use windows_win::ui::MessageBox;
+    use windows_win::ui::msg_box::flags::MB_RETRYCANCEL;
+
+    let result = MessageBox::info("Timeout test").flags(MB_RETRYCANCEL)
+                                                 .label(MsgBoxResult::Retry, "Retry &upload")
+                                                 .label(MsgBoxResult::Cancel, "&Skip")
+                                                 .show_with_timeout(std::time::Duration::from_millis(100));
+    assert_eq!(result.expect("To show message box"), MsgBoxResult::Timeout);
+    assert_eq!(MsgBoxResult::from(std::os::raw::c_int::from(MsgBoxResult::Timeout)), MsgBoxResult::Timeout);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_msg_box_typed_style() {
+    use windows_win::ui::MessageBox;
+
+    let mut msg_box = MessageBox::error("Typed style test");
+    msg_box.buttons(Buttons::YesNo).icon(Icon::Question);
+    let style = msg_box.get_style().expect("To get style");
+    assert_eq!(style.get_buttons(), Buttons::YesNo);
+    assert_eq!(style.get_icon(), Some(Icon::Question));
+
+    //Invalid style is rejected before message box is shown.
+    msg_box.default_button(DefaultButton::Fourth);
+    assert!(msg_box.get_style().is_err());
+    assert_eq!(msg_box.show().expect_err("To reject style").raw_code(), 87);
+
+    //Raw flags are passed as they are.
+    let result = MessageBox::info("Raw style test").flags(DefaultButton::Fourth.raw())
+                                                  .show_with_timeout(std::time::Duration::from_millis(100));
+    assert_eq!(result.expect("To show message box"), MsgBoxResult::Timeout);
+
+    //Flags, which are not part of style, keep it typed.
+    let result = MessageBox::info("Mixed style test").buttons(Buttons::YesNo)
+                                                    .default_button(DefaultButton::Fourth)
+                                                    .flags(MB_TOPMOST)
+                                                    .show_with_timeout(std::time::Duration::from_millis(100));
+    assert_eq!(result.expect_err("To reject style").raw_code(), 87);
+
+    //Raw style flags, mixed with typed ones, are passed as they are.
+    let result = MessageBox::info("Mixed style test").buttons(Buttons::YesNo)
+                                                    .flags(DefaultButton::Fourth.raw())
+                                                    .show_with_timeout(std::time::Duration::from_millis(100));
+    assert_eq!(result.expect("To show message box"), MsgBoxResult::Timeout);
+}
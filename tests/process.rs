@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 #[test]
 fn test_is_eval() {
     //Let's hope we don't run in evaluated shell it :)
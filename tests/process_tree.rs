@@ -0,0 +1,44 @@
+use windows_win::process_tree::ProcessTree;
+
+#[test]
+fn test_process_tree_descendants() {
+    let tree = ProcessTree::new(vec![(0, 0), (4, 0), (10, 4), (11, 4), (20, 10), (21, 20), (30, 99)]);
+
+    assert_eq!(tree.children(4), &[10, 11]);
+    assert!(tree.children(21).is_empty());
+    assert!(tree.children(0).contains(&4));
+    assert!(!tree.children(0).contains(&0));
+
+    assert_eq!(tree.descendants(4), vec![10, 11, 20, 21]);
+    assert_eq!(tree.descendants(20), vec![21]);
+    assert_eq!(tree.descendants(99), vec![30]);
+    assert!(tree.descendants(30).is_empty());
+}
+
+#[test]
+fn test_process_tree_cycle() {
+    //Parent pids are re-used, which can create cycle.
+    let tree = ProcessTree::new(vec![(1, 3), (2, 1), (3, 2)]);
+
+    assert_eq!(tree.descendants(1), vec![2, 3]);
+    assert_eq!(tree.descendants(3), vec![1, 2]);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_process_list_descendants() {
+    use windows_win::raw::process::{list, descendants};
+    use std::process::{self, Command, Stdio};
+
+    let processes = list().expect("To list processes");
+    let current = processes.iter().find(|entry| entry.pid == process::id()).expect("To find current process");
+    assert!(current.exe.ends_with(".exe"));
+    assert!(current.threads > 0);
+
+    let mut child = Command::new("cmd").args(["/C", "ping -n 5 127.0.0.1"]).stdout(Stdio::null()).spawn().expect("To spawn cmd");
+    let result = descendants(process::id());
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert!(result.expect("To get descendants").contains(&child.id()));
+}
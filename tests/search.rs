@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 extern crate windows_win;
 
 use windows_win::{
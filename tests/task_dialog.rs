@@ -0,0 +1,76 @@
+#![cfg(windows)]
+
+use windows_win::ui::task_dialog::{TaskDialog, TaskDialogEvent};
+use windows_win::sys::{ACTCTXW, HANDLE, ULONG_PTR, INVALID_HANDLE_VALUE, CreateActCtxW, ActivateActCtx, DeactivateActCtx, ReleaseActCtx};
+
+use std::os::windows::ffi::OsStrExt;
+use std::{env, fs, mem, process};
+
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<assembly xmlns="urn:schemas-microsoft-com:asm.v1" manifestVersion="1.0">
+  <dependency>
+    <dependentAssembly>
+      <assemblyIdentity type="win32" name="Microsoft.Windows.Common-Controls" version="6.0.0.0" processorArchitecture="*" publicKeyToken="6595b64144ccf1df" language="*"/>
+    </dependentAssembly>
+  </dependency>
+</assembly>"#;
+
+///Activates Common Controls v6 on current thread, as test executable has no manifest.
+struct CommonControls {
+    context: HANDLE,
+    cookie: ULONG_PTR,
+}
+
+impl CommonControls {
+    fn activate() -> Self {
+        let path = env::temp_dir().join(format!("windows-win-task-dialog-{}.manifest", process::id()));
+        fs::write(&path, MANIFEST).expect("To write manifest");
+        let source: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+
+        let mut config: ACTCTXW = unsafe { mem::zeroed() };
+        config.cbSize = mem::size_of::<ACTCTXW>() as _;
+        config.lpSource = source.as_ptr();
+
+        let context = unsafe { CreateActCtxW(&config) };
+        let _ = fs::remove_file(&path);
+        assert_ne!(context, INVALID_HANDLE_VALUE, "To create activation context");
+
+        let mut cookie = 0;
+        assert_ne!(unsafe { ActivateActCtx(context, &mut cookie) }, 0, "To activate context");
+
+        Self {
+            context,
+            cookie,
+        }
+    }
+}
+
+impl Drop for CommonControls {
+    fn drop(&mut self) {
+        unsafe {
+            DeactivateActCtx(0, self.cookie);
+            ReleaseActCtx(self.context);
+        }
+    }
+}
+
+#[test]
+fn test_task_dialog() {
+    let _controls = CommonControls::activate();
+
+    let result = TaskDialog::new("Task dialog test").button(100, "Custom")
+                                                    .radio_button(200, "First")
+                                                    .radio_button(201, "Second")
+                                                    .default_radio_button(201)
+                                                    .verification("Check", true)
+                                                    .show_with(|dialog, event| {
+                                                        if event == TaskDialogEvent::Created {
+                                                            dialog.click_button(100);
+                                                        }
+                                                        true
+                                                    }).expect("To show task dialog");
+
+    assert_eq!(result.button, 100);
+    assert_eq!(result.radio_button, Some(201));
+    assert!(result.verification_checked);
+}
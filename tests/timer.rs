@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 use windows_win::TimerBuilder;
 use windows_win::raw::timer;
 use windows_win::sys::{c_void, c_uchar};
@@ -72,3 +74,55 @@ fn test_timer_builder() {
     let timer = TimerBuilder::new().single(1).build().expect("To build timer");
     timer.delete(timer::Wait).expect("To delete timer");
 }
+
+#[test]
+fn test_timer_builder_closure() {
+    use std::sync::{mpsc, Arc};
+    use std::time::Duration;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    let count = Arc::new(atomic::AtomicUsize::new(0));
+    let captured = count.clone();
+    let (sender, receiver) = mpsc::channel();
+    let timer = TimerBuilder::new().callback(move || {
+        captured.fetch_add(1, atomic::Ordering::Relaxed);
+        let _ = sender.send(());
+    }).single(1).build().expect("To build timer");
+    receiver.recv_timeout(TIMEOUT).expect("To invoke callback");
+    timer.delete(timer::Wait).expect("To delete timer");
+    assert_eq!(count.load(atomic::Ordering::Relaxed), 1);
+    assert_eq!(Arc::strong_count(&count), 1);
+
+    let captured = count.clone();
+    let (sender, receiver) = mpsc::channel();
+    let timer = TimerBuilder::new().callback(move || {
+        let count = captured.fetch_add(1, atomic::Ordering::Relaxed);
+        let _ = sender.send(());
+        if count == 1 {
+            panic!("closure panic");
+        }
+    }).single(0).interval(30).build().expect("To build timer");
+    for _ in 0..3 {
+        receiver.recv_timeout(TIMEOUT).expect("To invoke callback after panic");
+    }
+    timer.delete(timer::Wait).expect("To delete timer");
+    assert!(count.load(atomic::Ordering::Relaxed) >= 4);
+    assert_eq!(Arc::strong_count(&count), 1);
+
+    //Closure is freed on separate thread, when timer is dropped.
+    struct DropSignal(mpsc::Sender<()>);
+    impl Drop for DropSignal {
+        fn drop(&mut self) {
+            let _ = self.0.send(());
+        }
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let signal = DropSignal(sender);
+    let timer = TimerBuilder::new().callback(move || {
+        let _ = &signal;
+    }).single(0).interval(30).build().expect("To build timer");
+    drop(timer);
+    receiver.recv_timeout(TIMEOUT).expect("To free closure");
+}
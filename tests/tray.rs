@@ -0,0 +1,24 @@
+#![cfg(windows)]
+
+use windows_win::ui::tray::{Icon, TrayIcon, TrayMenu};
+use windows_win::sys::ERROR_TIMEOUT;
+
+#[test]
+fn test_tray_icon() {
+    let mut menu = TrayMenu::new();
+    menu.disabled_item("Status").separator().item("Exit", || ());
+    assert_eq!(menu.len(), 3);
+
+    let icon = Icon::application().expect("To load icon");
+    let tray = match TrayIcon::new(icon, "Tray test") {
+        Ok(tray) => tray,
+        //Notification area is not available without Explorer, e.g. in service session.
+        Err(error) if error.raw_code() == ERROR_TIMEOUT as i32 => return,
+        Err(error) => panic!("To create tray icon: {}", error),
+    };
+
+    assert!(!tray.window().is_null());
+    tray.set_menu(menu);
+    tray.set_tooltip("Tray test updated").expect("To set tooltip");
+    tray.set_icon(Icon::application().expect("To load icon")).expect("To set icon");
+}
@@ -0,0 +1,16 @@
+use windows_win::utils::{from_hresult, HRESULT_CATEGORY};
+
+use core::ptr;
+
+#[test]
+fn test_from_hresult() {
+    //E_INVALIDARG
+    let error = from_hresult(0x80070057u32 as _);
+    assert_eq!(error, windows_win::ErrorCode::new_system(87));
+
+    //E_NOTIMPL
+    let error = from_hresult(0x80004001u32 as i32 as _);
+    assert_eq!(error.raw_code(), 0x80004001u32 as i32);
+    assert!(ptr::eq(error.category(), &HRESULT_CATEGORY));
+    assert!(error.to_string().starts_with("HRESULT("));
+}
@@ -0,0 +1,73 @@
+use windows_win::win_event::{WinEvent, WinEventKind, WinEventBuilder};
+use windows_win::sys::{EVENT_OBJECT_CREATE, EVENT_OBJECT_NAMECHANGE, EVENT_OBJECT_SHOW, EVENT_SYSTEM_FOREGROUND, OBJID_WINDOW, OBJID_CARET, CHILDID_SELF};
+
+use core::ptr;
+
+#[test]
+fn test_win_event_kind_raw() {
+    assert_eq!(WinEventKind::from_raw(EVENT_SYSTEM_FOREGROUND), WinEventKind::ForegroundChanged);
+    assert_eq!(WinEventKind::from_raw(EVENT_OBJECT_CREATE), WinEventKind::ObjectCreated);
+    assert_eq!(WinEventKind::from_raw(EVENT_OBJECT_NAMECHANGE), WinEventKind::NameChanged);
+    assert_eq!(WinEventKind::from_raw(EVENT_OBJECT_SHOW), WinEventKind::Other(EVENT_OBJECT_SHOW));
+
+    for kind in [WinEventKind::ForegroundChanged, WinEventKind::ObjectCreated, WinEventKind::ObjectDestroyed,
+                 WinEventKind::NameChanged, WinEventKind::LocationChanged, WinEventKind::Other(EVENT_OBJECT_SHOW)].iter() {
+        assert_eq!(WinEventKind::from_raw(kind.raw()), *kind);
+    }
+}
+
+#[test]
+fn test_win_event_is_window() {
+    let window = 1usize as _;
+    let event = WinEvent {
+        kind: WinEventKind::ObjectCreated,
+        window,
+        object_id: OBJID_WINDOW,
+        child_id: CHILDID_SELF,
+        thread_id: 1,
+        time: 0,
+    };
+    assert!(event.is_window());
+
+    assert!(!WinEvent { object_id: OBJID_CARET, ..event }.is_window());
+    assert!(!WinEvent { child_id: 1, ..event }.is_window());
+    assert!(!WinEvent { window: ptr::null_mut(), ..event }.is_window());
+}
+
+#[test]
+fn test_win_event_builder() {
+    let builder = WinEventBuilder::new().created().destroyed().created().foreground();
+    assert_eq!(builder.events(), &[WinEventKind::ObjectCreated, WinEventKind::ObjectDestroyed, WinEventKind::ForegroundChanged]);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_win_event_watcher_created() {
+    use windows_win::win_event::WinEventWatcher;
+    use windows_win::raw::window::{Builder, destroy};
+
+    use std::process;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    let (sender, receiver) = mpsc::channel();
+    let thread = WinEventWatcher::builder().created().process(process::id()).spawn(move |event| {
+        if event.is_window() {
+            let _ = sender.send(event.window as usize);
+        }
+    }).expect("To spawn watcher");
+
+    let window = Builder::new().class_name("STATIC").window_name("WinEventTest").create().expect("To create window");
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let timeout = deadline.checked_duration_since(Instant::now()).expect("To get created window");
+        let created = receiver.recv_timeout(timeout).expect("To get event");
+        if created == window as usize {
+            break;
+        }
+    }
+
+    assert!(destroy(window));
+    thread.stop();
+}
@@ -0,0 +1,106 @@
+use windows_win::window_tree::{WindowNode, Rect};
+
+fn sample_tree() -> WindowNode {
+    WindowNode {
+        hwnd: 0x10A2C,
+        class: "Notepad".to_owned(),
+        title: "Untitled - \"Notepad\"".to_owned(),
+        rect: Rect { left: -8, top: 0, right: 792, bottom: 600 },
+        style: 0x14CF0000,
+        pid: 100,
+        tid: 200,
+        visible: true,
+        children: vec![
+            WindowNode {
+                hwnd: 0x20B3,
+                class: "Edit".to_owned(),
+                rect: Rect { left: 0, top: 50, right: 784, bottom: 570 },
+                style: 0x50200104,
+                pid: 100,
+                tid: 200,
+                visible: true,
+                ..WindowNode::default()
+            },
+            WindowNode {
+                hwnd: 0x20B4,
+                class: "msctls_statusbar32".to_owned(),
+                pid: 100,
+                tid: 200,
+                children: vec![WindowNode {
+                    hwnd: 0x20B5,
+                    class: "Static".to_owned(),
+                    title: "Ln 1".to_owned(),
+                    ..WindowNode::default()
+                }],
+                ..WindowNode::default()
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_rect_size() {
+    let rect = Rect { left: -8, top: 10, right: 792, bottom: 610 };
+    assert_eq!(rect.width(), 800);
+    assert_eq!(rect.height(), 600);
+    assert_eq!(rect.to_string(), "(-8, 10)-(792, 610) 800x600");
+}
+
+#[test]
+fn test_window_node_display() {
+    let expected = "\
+0x00010a2c \"Untitled - \\\"Notepad\\\"\" Notepad (-8, 0)-(792, 600) 800x600 style=0x14cf0000 pid=100 tid=200
+    0x000020b3 \"\" Edit (0, 50)-(784, 570) 784x520 style=0x50200104 pid=100 tid=200
+    0x000020b4 \"\" msctls_statusbar32 (0, 0)-(0, 0) 0x0 style=0x00000000 pid=100 tid=200 hidden
+        0x000020b5 \"Ln 1\" Static (0, 0)-(0, 0) 0x0 style=0x00000000 pid=0 tid=0 hidden
+";
+
+    assert_eq!(sample_tree().to_string(), expected);
+}
+
+#[test]
+fn test_window_node_find() {
+    let tree = sample_tree();
+
+    assert_eq!(tree.len(), 4);
+    assert_eq!(tree.find(|node| node.class == "Static").map(|node| node.hwnd), Some(0x20B5));
+    assert_eq!(tree.find(|node| node.pid == 100).map(|node| node.hwnd), Some(0x10A2C));
+    assert!(tree.find(|node| node.class == "Button").is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_window_node_json() {
+    let tree = sample_tree();
+
+    let json = serde_json::to_value(&tree).expect("To serialize");
+    assert_eq!(json["class"], "Notepad");
+    assert_eq!(json["rect"]["left"], -8);
+    assert_eq!(json["children"][1]["children"][0]["title"], "Ln 1");
+    assert_eq!(json["children"][0]["visible"], true);
+
+    let json = serde_json::to_string(&tree).expect("To serialize");
+    let result: WindowNode = serde_json::from_str(&json).expect("To deserialize");
+    assert_eq!(result, tree);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_window_tree() {
+    use windows_win::Window;
+    use windows_win::raw::window::Builder;
+
+    let window = Window::from_builder(Builder::new().class_name("STATIC").window_name("TreeTest")).expect("To create window");
+    let child = Builder::new().class_name("BUTTON").window_name("Child").style(0x40000000).parent(window.inner()).create().expect("To create child");
+
+    let tree = window.tree().expect("To build tree");
+    assert_eq!(tree.hwnd, window.inner() as usize);
+    assert_eq!(tree.title, "TreeTest");
+    assert_eq!(tree.pid, std::process::id());
+    assert!(!tree.visible);
+    assert_eq!(tree.children.len(), 1);
+    assert_eq!(tree.children[0].hwnd, child as usize);
+    assert_eq!(tree.children[0].class, "Button");
+    assert_eq!(tree.children[0].title, "Child");
+    assert!(tree.to_string().contains("\"Child\" Button"));
+}
@@ -1,3 +1,5 @@
+#![cfg(windows)]
+
 use windows_win::sys::{AddClipboardFormatListener, SetLastErrorEx};
 
 use clipboard_win::set_clipboard_string;
@@ -8,9 +10,19 @@ use windows_win::raw::window::{
     get_by_class,
     get_by_title,
     get_by_pid,
+    wait_by,
+    wait_by_pid,
+    get_all_by_pid,
+    ProcessWindows,
     is_visible,
     get_text,
     send_get_text,
+    get_text_with,
+    get_class,
+    TextOptions,
+    SendMessageFlags,
+    SendError,
+    send_message_timeout,
     send_set_text,
     send_sys_command,
     Builder,
@@ -21,20 +33,17 @@ use windows_win::raw::process::{
     open,
     close,
     get_exe_path,
+    wait_for_input_idle,
 };
 
 fn start_prog(name: &str) -> std::process::Child {
     let res = std::process::Command::new(name).spawn().unwrap();
-    //Give a bit of time for window to appear
-    sleep(100);
+    let window = wait_by_pid(res.id(), Some(5000)).expect("To wait for window");
+    assert!(window.is_some());
 
     res
 }
 
-fn sleep(ms: u64) {
-    std::thread::sleep(std::time::Duration::from_millis(ms))
-}
-
 #[test]
 fn test_get_windows_by_class() {
     let result = get_by_class("IME", None);
@@ -48,6 +57,7 @@ fn test_interact_notepad() {
     let mut notepad = start_prog("notepad");
 
     test_open_close(notepad.id());
+    test_wait_for_input_idle(notepad.id());
     test_query_process_exe(notepad.id());
     test_get_windows_by_title(notepad.id());
     test_get_window_by_pid_after_error(notepad.id());
@@ -73,6 +83,15 @@ fn test_query_process_exe(notepad_id: u32) {
     assert!(result.is_ok());
 }
 
+fn test_wait_for_input_idle(notepad_id: u32) {
+    let notepad = open(notepad_id, 0x0400).expect("To open notepad");
+
+    let result = wait_for_input_idle(notepad, Some(5000));
+    assert!(result.expect("To wait for input idle"));
+
+    assert!(close(notepad).is_ok());
+}
+
 fn test_open_close(notepad_id: u32) {
     let result = open(notepad_id, 0x0038);
     assert!(result.is_ok());
@@ -174,6 +193,115 @@ fn test_window_create_dummy() {
     assert!(destroy(window));
 }
 
+#[test]
+fn test_wait_by_timeout() {
+    let result = wait_by(|_| false, Some(50));
+    assert!(result.expect("To wait").is_none());
+}
+
+#[test]
+fn test_wait_by_created_window() {
+    let title = "WaitByTest";
+    let (sender, receiver) = std::sync::mpsc::channel::<()>();
+    let thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let window = Builder::new().class_name("STATIC").window_name(title).create().expect("To create window");
+        //Window is destroyed with thread, so keep it until waiting is over.
+        let _ = receiver.recv();
+        assert!(destroy(window));
+        window as usize
+    });
+
+    let result = wait_by(|handle| get_text(handle).map(|text| text == title).unwrap_or(false), Some(5000));
+    let _ = sender.send(());
+    let window = thread.join().unwrap();
+    assert_eq!(result.expect("To wait").map(|window| window as usize), Some(window));
+}
+
+#[test]
+fn test_get_all_by_pid() {
+    let pid = std::process::id();
+    let first = Builder::new().class_name("STATIC").create().expect("To create window");
+    let second = Builder::new().class_name("STATIC").create().expect("To create window");
+    let child = Builder::new().class_name("BUTTON").style(0x40000000).parent(first).create().expect("To create child window");
+
+    let windows = get_all_by_pid(pid).expect("To get windows");
+    assert!(windows.contains(&first));
+    assert!(windows.contains(&second));
+    assert!(!windows.contains(&child));
+
+    let windows = ProcessWindows::new(pid).children().descendants().get().expect("To get windows");
+    assert!(windows.contains(&first));
+    assert!(windows.contains(&second));
+    let first_idx = windows.iter().position(|window| *window == first).unwrap();
+    assert_eq!(windows[first_idx + 1], child);
+
+    assert!(destroy(first));
+    assert!(destroy(second));
+}
+
+#[test]
+fn test_window_text_with() {
+    let long_title: String = "Title".repeat(300);
+    let window = Window::from_builder(Builder::new().class_name("BUTTON").window_name(&long_title)).expect("To create window");
+
+    let result = window.text_with(TextOptions::default()).expect("To get text");
+    assert_eq!(result.as_ref(), Some(&long_title));
+    let result = get_text(window.inner()).expect("To get title");
+    assert_eq!(result, long_title);
+    assert_eq!(get_class(window.inner()).expect("To get class"), "Button");
+
+    let result = get_text_with(window.inner(), &TextOptions { timeout: None, max_len: Some(5) }).expect("To get text");
+    assert_eq!(result.as_deref(), Some("Title"));
+
+    assert!(window.send_set_text(""));
+    assert!(window.text_with(TextOptions::default()).expect("To get text").is_none());
+    assert_eq!(get_text(window.inner()).expect_err("To fail on empty title").raw_code(), 0);
+}
+
+#[test]
+fn test_window_text_with_hung() {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    let (sender, receiver) = mpsc::channel();
+    let thread = thread::spawn(move || {
+        let window = Window::from_builder(Builder::new().class_name("BUTTON").window_name("Hung")).expect("To create window");
+        sender.send(window.inner() as usize).expect("To send window");
+        //Doesn't process messages meanwhile
+        thread::sleep(Duration::from_millis(1000));
+    });
+
+    let window = receiver.recv().expect("To get window") as windows_win::sys::HWND;
+    let result = get_text_with(window, &TextOptions { timeout: Some(100), max_len: None });
+    assert_eq!(result, Err(SendError::Timeout));
+
+    thread.join().expect("To finish thread");
+}
+
+#[test]
+fn test_send_message_timeout() {
+    assert_eq!(SendMessageFlags::new().raw(), 0x0000);
+    assert_eq!(SendMessageFlags::new().block().abort_if_hung().raw(), 0x0003);
+    assert_eq!(SendMessageFlags::default().no_timeout_if_not_hung().error_on_exit().raw(), 0x0028);
+
+    let window = Window::from_builder(Builder::new().class_name("BUTTON").window_name("Timeout")).expect("To create window");
+    assert!(!window.is_hung());
+
+    let result = window.send_message_timeout(0x000E, 0, 0, 1000, SendMessageFlags::new().abort_if_hung());
+    assert_eq!(result, Ok(7));
+
+    let invalid = 1usize as windows_win::sys::HWND;
+    match send_message_timeout(invalid, 0x000E, 0, 0, 1000, SendMessageFlags::new()) {
+        Err(SendError::Os(error)) => assert_eq!(error.raw_code(), 1400),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    let error: windows_win::ErrorCode = SendError::Timeout.into();
+    assert_eq!(error.raw_code(), 1460);
+}
+
 #[test]
 fn check_enum_by_with_last_error_will_not_fail() {
     unsafe {